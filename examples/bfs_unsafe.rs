@@ -64,7 +64,6 @@ pub fn main() {
         let mut dists = vec![-1; tree.num_nodes()];
 
         let visited = (0..tree.num_nodes())
-            .into_iter()
             .map(|i| AtomicBool::new(i == 0))
             .collect::<Vec<_>>();
         let mut current_frontier = vec![0];
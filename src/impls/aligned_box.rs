@@ -0,0 +1,179 @@
+use std::{
+    alloc::{alloc, dealloc, handle_alloc_error, Layout},
+    cell::UnsafeCell,
+    fmt::Debug,
+    mem::{align_of, ManuallyDrop},
+    ops::Deref,
+    ptr::NonNull,
+};
+
+/// Owned slice whose allocation is aligned to at least `align` bytes.
+///
+/// Unlike [`Box<[T]>`](`Box`), which only guarantees an alignment of `align_of::<T>()`,
+/// this allows the base pointer to satisfy the stricter alignment requirements of SIMD
+/// instructions. The memory is deallocated with the same layout it was allocated with.
+pub(crate) struct AlignedBox<T> {
+    ptr: NonNull<T>,
+    len: usize,
+    align: usize,
+}
+
+// Safety: the allocation is uniquely owned, just like a Box.
+unsafe impl<T: Send> Send for AlignedBox<T> {}
+unsafe impl<T: Sync> Sync for AlignedBox<T> {}
+
+impl<T> AlignedBox<T> {
+    /// Creates a new slice of `len` elements aligned to at least `align` bytes, each initialized
+    /// to the return value of `closure` called with the index of the element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power of two or if the size of the slice overflows `isize`.
+    pub(crate) fn new_with(len: usize, align: usize, mut closure: impl FnMut(usize) -> T) -> Self {
+        assert!(
+            align.is_power_of_two(),
+            "alignment should be a power of two. Got an alignment of {align}"
+        );
+        let align = align.max(align_of::<T>());
+        let layout = Self::layout(len, align);
+
+        let ptr = if layout.size() == 0 {
+            // Safety: align is a power of two, so it is not zero
+            unsafe { NonNull::new_unchecked(align as *mut T) }
+        } else {
+            let raw = unsafe {
+                // Safety: layout has a non-zero size
+                alloc(layout)
+            };
+            match NonNull::new(raw as *mut T) {
+                Some(ptr) => ptr,
+                None => handle_alloc_error(layout),
+            }
+        };
+
+        let mut guard = InitGuard {
+            ptr,
+            initialized: 0,
+            layout,
+        };
+        for i in 0..len {
+            let value = closure(i);
+            unsafe {
+                // Safety: i is in bounds of the allocation and the element is uninitialized
+                ptr.as_ptr().add(i).write(value);
+            }
+            guard.initialized += 1;
+        }
+        std::mem::forget(guard);
+
+        Self { ptr, len, align }
+    }
+
+    /// Returns the alignment of the base pointer of the slice.
+    #[inline]
+    pub(crate) fn align(&self) -> usize {
+        self.align
+    }
+
+    /// Consumes the slice, moving its elements into a [`Box<[T]>`](`Box`) with the default alignment.
+    pub(crate) fn into_boxed_slice(self) -> Box<[T]> {
+        let this = ManuallyDrop::new(self);
+        let mut boxed = Box::new_uninit_slice(this.len);
+        unsafe {
+            // Safety: both allocations hold len elements and can't overlap
+            std::ptr::copy_nonoverlapping(
+                this.ptr.as_ptr(),
+                boxed.as_mut_ptr() as *mut T,
+                this.len,
+            );
+            // Safety: the elements were moved out, so only the allocation is freed
+            this.deallocate();
+            // Safety: all elements were just initialized
+            boxed.assume_init()
+        }
+    }
+
+    /// Returns the layout of a slice of `len` elements aligned to `align` bytes.
+    #[inline]
+    fn layout(len: usize, align: usize) -> Layout {
+        Layout::array::<T>(len)
+            .and_then(|layout| layout.align_to(align))
+            .expect("the size of the slice should not overflow isize")
+    }
+
+    /// Frees the allocation without dropping the elements.
+    ///
+    /// # Safety
+    ///
+    /// The allocation must not be used after calling this method.
+    #[inline]
+    unsafe fn deallocate(&self) {
+        let layout = Self::layout(self.len, self.align);
+        if layout.size() != 0 {
+            unsafe {
+                // Safety: ptr was allocated with the same layout
+                dealloc(self.ptr.as_ptr() as *mut u8, layout)
+            }
+        }
+    }
+}
+
+/// Drops the already initialized elements and frees the allocation if
+/// the initialization of an [`AlignedBox`] panics.
+struct InitGuard<T> {
+    ptr: NonNull<T>,
+    initialized: usize,
+    layout: Layout,
+}
+
+impl<T> Drop for InitGuard<T> {
+    fn drop(&mut self) {
+        unsafe {
+            // Safety: the first initialized elements are initialized and are never used again
+            std::ptr::drop_in_place(std::ptr::slice_from_raw_parts_mut(
+                self.ptr.as_ptr(),
+                self.initialized,
+            ));
+            if self.layout.size() != 0 {
+                // Safety: ptr was allocated with the same layout
+                dealloc(self.ptr.as_ptr() as *mut u8, self.layout)
+            }
+        }
+    }
+}
+
+impl<T> Drop for AlignedBox<T> {
+    fn drop(&mut self) {
+        unsafe {
+            // Safety: the first len elements are initialized and are never used again
+            std::ptr::drop_in_place(std::ptr::slice_from_raw_parts_mut(
+                self.ptr.as_ptr(),
+                self.len,
+            ));
+            // Safety: self is being dropped
+            self.deallocate();
+        }
+    }
+}
+
+impl<T> Deref for AlignedBox<T> {
+    type Target = UnsafeCell<[T]>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        let slice = std::ptr::slice_from_raw_parts_mut(self.ptr.as_ptr(), self.len);
+        unsafe {
+            // Safety: the first len elements are initialized and UnsafeCell is repr(transparent)
+            &*(slice as *const UnsafeCell<[T]>)
+        }
+    }
+}
+
+impl<T> Debug for AlignedBox<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AlignedBox")
+            .field("len", &self.len)
+            .field("align", &self.align)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,53 @@
+use std::{alloc::Allocator, cell::UnsafeCell, fmt::Debug, ops::Deref};
+
+/// Owned slice whose allocation is managed by a custom [`Allocator`].
+///
+/// Unlike [`AlignedBox`](`super::AlignedBox`), converting it back into a
+/// [`Box<[T], A>`](`Box`) keeps both the allocation and the allocator.
+pub(crate) struct AllocatorBox<T, A: Allocator>(Box<UnsafeCell<[T]>, A>);
+
+impl<T, A: Allocator> AllocatorBox<T, A> {
+    /// Creates a new slice of `len` elements allocated with `alloc`, each initialized
+    /// to the return value of `closure` called with the index of the element.
+    pub(crate) fn new_with_in(len: usize, alloc: A, mut closure: impl FnMut(usize) -> T) -> Self {
+        let mut boxed = Box::new_uninit_slice_in(len, alloc);
+        for (i, element) in boxed.iter_mut().enumerate() {
+            element.write(closure(i));
+        }
+        let boxed = unsafe {
+            // Safety: all elements were just initialized
+            boxed.assume_init()
+        };
+        let (ptr, alloc) = Box::into_raw_with_allocator(boxed);
+        Self(unsafe {
+            // Safety: ptr was allocated by alloc and UnsafeCell is repr(transparent)
+            Box::from_raw_in(ptr as *mut UnsafeCell<[T]>, alloc)
+        })
+    }
+
+    /// Consumes the slice, returning its elements in the same allocation.
+    pub(crate) fn into_boxed_slice_in(self) -> Box<[T], A> {
+        let (ptr, alloc) = Box::into_raw_with_allocator(self.0);
+        unsafe {
+            // Safety: ptr was allocated by alloc and UnsafeCell is repr(transparent)
+            Box::from_raw_in(ptr as *mut [T], alloc)
+        }
+    }
+}
+
+impl<T, A: Allocator> Deref for AllocatorBox<T, A> {
+    type Target = UnsafeCell<[T]>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, A: Allocator> Debug for AllocatorBox<T, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AllocatorBox")
+            .field("len", &self.0.get().len())
+            .finish_non_exhaustive()
+    }
+}
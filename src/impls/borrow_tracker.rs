@@ -0,0 +1,221 @@
+use std::{
+    fmt::Debug,
+    ops::{Deref, DerefMut},
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+/// Borrow state of an element that is mutably borrowed.
+const MUT_BORROWED: u8 = u8::MAX;
+
+/// Per-index borrow state of a collection, used to detect aliasing violations at runtime.
+///
+/// Each index holds the number of shared borrows of the element, or [`MUT_BORROWED`]
+/// if the element is mutably borrowed.
+pub(crate) struct BorrowTracker(Box<[AtomicU8]>);
+
+impl BorrowTracker {
+    /// Creates a new tracker for a collection of size `len` with no borrowed elements.
+    pub(crate) fn new(len: usize) -> Self {
+        Self((0..len).map(|_| AtomicU8::new(0)).collect())
+    }
+
+    /// Records a shared borrow of the element identified by `index`, returning
+    /// the handle that releases it on drop.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the element is mutably borrowed.
+    pub(crate) fn borrow(&self, index: usize) -> BorrowRelease<'_> {
+        let state = &self.0[index];
+        let result = state.fetch_update(Ordering::Acquire, Ordering::Relaxed, |borrows| {
+            if borrows < MUT_BORROWED - 1 {
+                Some(borrows + 1)
+            } else {
+                None
+            }
+        });
+        match result {
+            Ok(_) => BorrowRelease {
+                state,
+                mutable: false,
+            },
+            Err(MUT_BORROWED) => {
+                panic!("Index {index} is already mutably borrowed: aliasing rules violated")
+            }
+            Err(_) => panic!("Index {index} has too many shared borrows"),
+        }
+    }
+
+    /// Records a mutable borrow of the element identified by `index`, returning
+    /// the handle that releases it on drop.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the element is already borrowed.
+    pub(crate) fn borrow_mut(&self, index: usize) -> BorrowRelease<'_> {
+        let state = &self.0[index];
+        if state
+            .compare_exchange(0, MUT_BORROWED, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            panic!("Index {index} is already borrowed: aliasing rules violated")
+        }
+        BorrowRelease {
+            state,
+            mutable: true,
+        }
+    }
+}
+
+impl Debug for BorrowTracker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BorrowTracker").finish_non_exhaustive()
+    }
+}
+
+/// Releases a borrow recorded by a [`BorrowTracker`] on drop.
+pub(crate) struct BorrowRelease<'a> {
+    state: &'a AtomicU8,
+    mutable: bool,
+}
+
+impl Drop for BorrowRelease<'_> {
+    fn drop(&mut self) {
+        if self.mutable {
+            self.state.store(0, Ordering::Release);
+        } else {
+            self.state.fetch_sub(1, Ordering::Release);
+        }
+    }
+}
+
+/// A shared reference to an element of a collection whose borrow is tracked at runtime.
+///
+/// The borrow is released when the guard is dropped.
+pub struct SharedBorrowGuard<'a, T: ?Sized> {
+    value: &'a T,
+    _release: Option<BorrowRelease<'a>>,
+}
+
+impl<'a, T: ?Sized> SharedBorrowGuard<'a, T> {
+    /// Wraps a reference whose borrow is released by `release`.
+    #[inline]
+    pub(crate) fn new(value: &'a T, release: BorrowRelease<'a>) -> Self {
+        Self {
+            value,
+            _release: Some(release),
+        }
+    }
+
+    /// Wraps a reference whose borrow is not tracked.
+    #[inline]
+    pub(crate) fn untracked(value: &'a T) -> Self {
+        Self {
+            value,
+            _release: None,
+        }
+    }
+
+    /// Maps the guarded reference to a reference to a part of it, keeping the same borrow.
+    #[inline]
+    pub(crate) fn map<U: ?Sized>(self, f: impl FnOnce(&'a T) -> &'a U) -> SharedBorrowGuard<'a, U> {
+        SharedBorrowGuard {
+            value: f(self.value),
+            _release: self._release,
+        }
+    }
+}
+
+impl<T: ?Sized> Deref for SharedBorrowGuard<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        self.value
+    }
+}
+
+impl<T: ?Sized + PartialEq<U>, U: ?Sized> PartialEq<&U> for SharedBorrowGuard<'_, T> {
+    /// Compares the guarded element with `other`, as if the guard was a shared reference.
+    #[inline]
+    fn eq(&self, other: &&U) -> bool {
+        *self.value == **other
+    }
+}
+
+impl<T: ?Sized + PartialEq<U>, U: ?Sized> PartialEq<SharedBorrowGuard<'_, U>>
+    for SharedBorrowGuard<'_, T>
+{
+    #[inline]
+    fn eq(&self, other: &SharedBorrowGuard<'_, U>) -> bool {
+        *self.value == *other.value
+    }
+}
+
+impl<T: ?Sized + Debug> Debug for SharedBorrowGuard<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+/// A mutable reference to an element of a collection whose borrow is tracked at runtime.
+///
+/// The borrow is released when the guard is dropped.
+pub struct BorrowGuard<'a, T: ?Sized> {
+    value: &'a mut T,
+    _release: Option<BorrowRelease<'a>>,
+}
+
+impl<'a, T: ?Sized> BorrowGuard<'a, T> {
+    /// Wraps a mutable reference whose borrow is released by `release`.
+    #[inline]
+    pub(crate) fn new(value: &'a mut T, release: BorrowRelease<'a>) -> Self {
+        Self {
+            value,
+            _release: Some(release),
+        }
+    }
+
+    /// Wraps a mutable reference whose borrow is not tracked.
+    #[inline]
+    pub(crate) fn untracked(value: &'a mut T) -> Self {
+        Self {
+            value,
+            _release: None,
+        }
+    }
+
+    /// Maps the guarded reference to a reference to a part of it, keeping the same borrow.
+    #[inline]
+    pub(crate) fn map<U: ?Sized>(
+        self,
+        f: impl FnOnce(&'a mut T) -> &'a mut U,
+    ) -> BorrowGuard<'a, U> {
+        BorrowGuard {
+            value: f(self.value),
+            _release: self._release,
+        }
+    }
+}
+
+impl<T: ?Sized> Deref for BorrowGuard<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        self.value
+    }
+}
+
+impl<T: ?Sized> DerefMut for BorrowGuard<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        self.value
+    }
+}
+
+impl<T: ?Sized + Debug> Debug for BorrowGuard<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.value.fmt(f)
+    }
+}
@@ -0,0 +1,64 @@
+use crate::*;
+use std::{fmt::Debug, marker::PhantomData};
+
+/// Wrapper around a shared reference to a collection that reinterprets the
+/// pointers to its elements of type `T` as pointers to elements of type `U`.
+pub(crate) struct CastView<'a, C, T> {
+    parent: &'a C,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<C: TrustedSizedCollection, T> Debug for CastView<'_, C, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CastView")
+            .field("len", &self.parent.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, C: PointerIndex<T>, T> CastView<'a, C, T> {
+    /// Creates a view over `parent` with elements of type `U`.
+    ///
+    /// The caller must guarantee that `T` can be reinterpreted as `U`.
+    #[inline]
+    pub(crate) fn new<U>(parent: &'a C) -> Self {
+        debug_assert!(
+            size_of::<T>() == size_of::<U>() && align_of::<T>() >= align_of::<U>(),
+            "cannot reinterpret elements of size {} and alignment {} as elements of size {} and alignment {}",
+            size_of::<T>(),
+            align_of::<T>(),
+            size_of::<U>(),
+            align_of::<U>()
+        );
+
+        Self {
+            parent,
+            _marker: PhantomData,
+        }
+    }
+}
+
+unsafe impl<C: TrustedSizedCollection, T> TrustedSizedCollection for CastView<'_, C, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.parent.len()
+    }
+}
+
+unsafe impl<C: PointerIndex<T>, T, U> PointerIndex<U> for CastView<'_, C, T> {
+    #[inline]
+    unsafe fn get_ptr_unchecked(&self, index: usize) -> *const U {
+        unsafe {
+            // Safety: the caller guarantees index is valid
+            self.parent.get_ptr_unchecked(index) as *const U
+        }
+    }
+
+    #[inline]
+    unsafe fn get_mut_ptr_unchecked(&self, index: usize) -> *mut U {
+        unsafe {
+            // Safety: the caller guarantees index is valid
+            self.parent.get_mut_ptr_unchecked(index) as *mut U
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::*;
+use std::{marker::PhantomData, ptr};
+
+/// A cursor over the chunks of a collection that yields pointers to consecutive chunks
+/// by advancing a running pointer.
+///
+/// This avoids recomputing the address of each chunk from its index in tight sequential loops.
+/// See [`ContiguousPointerChunkIndex::sequential_chunk_writer`].
+///
+/// # Examples
+///
+/// ```
+/// # use par_slice::*;
+/// let collection = vec![0; 6].into_pointer_par_chunk_index(2);
+/// let mut writer = collection.sequential_chunk_writer();
+///
+/// let mut i = 0;
+/// while writer.remaining() > 0 {
+///     // Each chunk is yielded once and no other reference to it exists
+///     unsafe { (*writer.next_chunk()).fill(i) };
+///     i += 1;
+/// }
+///
+/// assert_eq!(collection.into(), vec![0, 0, 1, 1, 2, 2]);
+/// ```
+#[derive(Debug)]
+pub struct ChunkWriter<'a, T> {
+    next: *mut T,
+    chunk: usize,
+    num_chunks: usize,
+    chunk_size: usize,
+    _marker: PhantomData<&'a ()>,
+}
+
+impl<'a, T> ChunkWriter<'a, T> {
+    /// Creates a writer over the chunks of `collection`, starting from the first one.
+    #[inline]
+    pub(crate) fn new<C: ContiguousPointerChunkIndex<T> + ?Sized>(collection: &'a C) -> Self {
+        Self {
+            next: collection.full_mut_ptr(),
+            chunk: 0,
+            num_chunks: collection.num_chunks(),
+            chunk_size: collection.chunk_size(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of chunks that have not been yielded yet.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.num_chunks - self.chunk
+    }
+
+    /// Returns a mutable pointer to the next chunk of the collection and advances the writer.
+    ///
+    /// The `i`-th call returns the same chunk as `collection.get_mut_ptr(i)`.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method when [`remaining`](`Self::remaining`) is `0` is [undefined behavior].
+    /// The usual aliasing and data race rules apply when dereferencing the returned pointer.
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    #[must_use]
+    #[inline]
+    pub unsafe fn next_chunk(&mut self) -> *mut [T] {
+        #[cfg(feature = "checked")]
+        assert_in_bounds(self.num_chunks, self.chunk);
+        debug_assert!(self.chunk < self.num_chunks);
+
+        let chunk = ptr::slice_from_raw_parts_mut(self.next, self.chunk_size);
+        self.next = unsafe {
+            // Safety: the caller guarantees that this chunk is in bounds, so the pointer
+            // to the next one is at most one past the end of the collection
+            self.next.add(self.chunk_size)
+        };
+        self.chunk += 1;
+        chunk
+    }
+}
@@ -24,6 +24,45 @@ unsafe impl<T> TrustedSizedCollection for [T] {
     }
 }
 
+/// Allows generic code bounded on [`TrustedSizedCollection`] to hold slices by reference.
+///
+/// # Examples
+///
+/// ```
+/// # use par_slice::*;
+/// fn len_of<C: TrustedSizedCollection>(collection: C) -> usize {
+///     collection.len()
+/// }
+///
+/// let v = [1, 2, 3];
+/// let slice: &[i32] = &v;
+/// assert_eq!(len_of(slice), 3);
+/// assert_eq!(TrustedSizedCollection::len(&slice), 3);
+/// ```
+unsafe impl<T> TrustedSizedCollection for &[T] {
+    #[inline]
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        <[T]>::is_empty(self)
+    }
+}
+
+unsafe impl<T> TrustedSizedCollection for &mut [T] {
+    #[inline]
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        <[T]>::is_empty(self)
+    }
+}
+
 unsafe impl<T, const N: usize> TrustedSizedCollection for [T; N] {
     #[inline]
     fn len(&self) -> usize {
@@ -17,6 +17,48 @@ pub(crate) fn new_boxed_slice_with<T>(len: usize, mut closure: impl FnMut(usize)
     unsafe { boxed.assume_init() }
 }
 
+/// Creates a new boxed slice with the items yielded by `iter`, allocating
+/// exactly `iter.len()` elements.
+///
+/// # Panics
+///
+/// Panics if `iter` yields fewer items than its reported length.
+pub(crate) fn new_boxed_slice_from_iter_exact<T>(
+    mut iter: impl ExactSizeIterator<Item = T>,
+) -> Box<[T]> {
+    let len = iter.len();
+    let boxed = new_boxed_slice_from_iter_prefix(len, &mut iter);
+    debug_assert!(
+        iter.next().is_none(),
+        "iterator should yield {len} items. Got more items"
+    );
+    boxed
+}
+
+/// Creates a new boxed slice with the first `len` items yielded by `iter`.
+///
+/// # Panics
+///
+/// Panics if `iter` yields fewer than `len` items.
+pub(crate) fn new_boxed_slice_from_iter_prefix<T>(
+    len: usize,
+    iter: &mut impl Iterator<Item = T>,
+) -> Box<[T]> {
+    let mut boxed = Box::new_uninit_slice(len);
+    for (i, elem) in boxed.iter_mut().enumerate() {
+        match iter.next() {
+            Some(item) => {
+                elem.write(item);
+            }
+            None => panic!("iterator should yield {len} items. Got {i} items"),
+        }
+    }
+    unsafe {
+        // Safety: all len elements were just initialized
+        boxed.assume_init()
+    }
+}
+
 /// Creates a new boxed slice of `len` elements, each initialized to `value`.
 pub(crate) fn new_boxed_slice_with_value<T: Clone>(len: usize, value: T) -> Box<[T]> {
     let mut boxed = Box::new_uninit_slice(len);
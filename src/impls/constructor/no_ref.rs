@@ -1,4 +1,5 @@
 use crate::*;
+use std::mem::MaybeUninit;
 
 /// Utility struct for contructors for slices that allow unsynchronized access
 /// to their elements through [`UnsafeNoRefIndex`] and [`UnsafeNoRefChunkIndex`].
@@ -23,9 +24,7 @@ impl NoRefParSlice {
     /// ```
     #[allow(clippy::new_ret_no_self)]
     #[inline]
-    pub fn new<T: Default + Send + Sync>(
-        len: usize,
-    ) -> impl UnsafeNoRefIndex<T> + ParCollection<T, Box<[T]>> {
+    pub fn new<T: Default + Send + Sync>(len: usize) -> OwnedNoRefParIndex<T> {
         new_boxed_slice(len).into_par_index_no_ref()
     }
 
@@ -46,10 +45,7 @@ impl NoRefParSlice {
     /// assert_eq!(data_race_slice.into().as_ref(), &[42, 69, 69, 69]);
     /// ```
     #[inline]
-    pub fn with_value<T: Clone + Send + Sync>(
-        value: T,
-        len: usize,
-    ) -> impl UnsafeNoRefIndex<T> + ParCollection<T, Box<[T]>> {
+    pub fn with_value<T: Clone + Send + Sync>(value: T, len: usize) -> OwnedNoRefParIndex<T> {
         new_boxed_slice_with_value(len, value).into_par_index_no_ref()
     }
 
@@ -74,10 +70,36 @@ impl NoRefParSlice {
     pub fn with_closure<T: Send + Sync>(
         closure: impl FnMut(usize) -> T,
         len: usize,
-    ) -> impl UnsafeNoRefIndex<T> + ParCollection<T, Box<[T]>> {
+    ) -> OwnedNoRefParIndex<T> {
         new_boxed_slice_with(len, closure).into_par_index_no_ref()
     }
 
+    /// Constructs a new slice with `len` uninitialized elements that allows
+    /// unsynchronized access to its elements through [`UnsafeNoRefIndex`] and that
+    /// can be converted into a boxed slice.
+    ///
+    /// Once every element has been initialized, the slice can be turned into one
+    /// of initialized elements with [`SerialAccess::assume_init`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use par_slice::*;
+    /// # use std::mem::MaybeUninit;
+    /// let data_race_slice = NoRefParSlice::new_uninit(4);
+    ///
+    /// for i in 0..4 {
+    ///     unsafe { data_race_slice.set_value(i, MaybeUninit::new(i)) };
+    /// }
+    ///
+    /// // Every element was initialized
+    /// let data_race_slice = unsafe { data_race_slice.assume_init() };
+    /// assert_eq!(data_race_slice.into().as_ref(), &[0, 1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn new_uninit<T: Send + Sync>(len: usize) -> OwnedNoRefParIndex<MaybeUninit<T>> {
+        Box::new_uninit_slice(len).into_par_index_no_ref()
+    }
+
     /// Constructs a new slice with `len` elements, each initialized
     /// to [`T::default`](`Default::default`), that allows unsynchronized
     /// access to chunks of `chunk_size` of its elements through
@@ -98,7 +120,7 @@ impl NoRefParSlice {
     pub fn new_chunks<T: Default + Send + Sync>(
         len: usize,
         chunk_size: usize,
-    ) -> impl UnsafeNoRefChunkIndex<T> + ParCollection<[T], Box<[T]>> {
+    ) -> OwnedNoRefChunkParIndex<T> {
         assert_chunk_size(len, chunk_size);
         new_boxed_slice(len).into_par_chunk_index_no_ref(chunk_size)
     }
@@ -124,7 +146,7 @@ impl NoRefParSlice {
         value: T,
         len: usize,
         chunk_size: usize,
-    ) -> impl UnsafeNoRefChunkIndex<T> + ParCollection<[T], Box<[T]>> {
+    ) -> OwnedNoRefChunkParIndex<T> {
         assert_chunk_size(len, chunk_size);
         new_boxed_slice_with_value(len, value).into_par_chunk_index_no_ref(chunk_size)
     }
@@ -151,8 +173,92 @@ impl NoRefParSlice {
         closure: impl FnMut(usize) -> T,
         len: usize,
         chunk_size: usize,
-    ) -> impl UnsafeNoRefChunkIndex<T> + ParCollection<[T], Box<[T]>> {
+    ) -> OwnedNoRefChunkParIndex<T> {
         assert_chunk_size(len, chunk_size);
         new_boxed_slice_with(len, closure).into_par_chunk_index_no_ref(chunk_size)
     }
+
+    /// Constructs a new slice with `len` elements, each initialized
+    /// to [`T::default`](`Default::default`), that allows unsynchronized
+    /// access to chunks of `chunk_size` of its elements through
+    /// [`UnsafeNoRefChunkIndex`] and that can be converted into a boxed slice, or a [`ChunkSizeError`]
+    /// if `len` is not divisible by `chunk_size`.
+    ///
+    /// This is the non-panicking version of [`new_chunks`](`Self::new_chunks`).
+    ///
+    /// # Examples
+    /// ```
+    /// # use par_slice::*;
+    /// assert!(NoRefParSlice::try_new_chunks::<u32>(4, 3).is_err());
+    ///
+    /// let no_ref_slice = NoRefParSlice::try_new_chunks(4, 2).unwrap();
+    /// unsafe { no_ref_slice.set_values(0, &[42, 42]) };
+    ///
+    /// assert_eq!(no_ref_slice.into().as_ref(), &[42, 42, 0, 0]);
+    /// ```
+    #[inline]
+    pub fn try_new_chunks<T: Default + Send + Sync>(
+        len: usize,
+        chunk_size: usize,
+    ) -> Result<OwnedNoRefChunkParIndex<T>, ChunkSizeError> {
+        ChunkSizeError::check(len, chunk_size)?;
+        Ok(new_boxed_slice(len).into_par_chunk_index_no_ref(chunk_size))
+    }
+
+    /// Constructs a new slice with `len` elements, each initialized
+    /// to `value`, that allows unsynchronized
+    /// access to chunks of `chunk_size` of its elements through
+    /// [`UnsafeNoRefChunkIndex`] and that can be converted into a boxed slice, or a [`ChunkSizeError`]
+    /// if `len` is not divisible by `chunk_size`.
+    ///
+    /// This is the non-panicking version of [`chunks_with_value`](`Self::chunks_with_value`).
+    ///
+    /// # Examples
+    /// ```
+    /// # use par_slice::*;
+    /// assert!(NoRefParSlice::try_chunks_with_value(0, 4, 3).is_err());
+    ///
+    /// let no_ref_slice = NoRefParSlice::try_chunks_with_value(0, 4, 2).unwrap();
+    /// unsafe { no_ref_slice.set_values(0, &[42, 42]) };
+    ///
+    /// assert_eq!(no_ref_slice.into().as_ref(), &[42, 42, 0, 0]);
+    /// ```
+    #[inline]
+    pub fn try_chunks_with_value<T: Clone + Send + Sync>(
+        value: T,
+        len: usize,
+        chunk_size: usize,
+    ) -> Result<OwnedNoRefChunkParIndex<T>, ChunkSizeError> {
+        ChunkSizeError::check(len, chunk_size)?;
+        Ok(new_boxed_slice_with_value(len, value).into_par_chunk_index_no_ref(chunk_size))
+    }
+
+    /// Constructs a new slice with `len` elements, each initialized
+    /// to the return value of `closure` called with the index of the element
+    /// to generate as an [`usize`], that allows unsynchronized
+    /// access to chunks of `chunk_size` of its elements through
+    /// [`UnsafeNoRefChunkIndex`] and that can be converted into a boxed slice, or a [`ChunkSizeError`]
+    /// if `len` is not divisible by `chunk_size`.
+    ///
+    /// This is the non-panicking version of [`chunks_with_closure`](`Self::chunks_with_closure`).
+    ///
+    /// # Examples
+    /// ```
+    /// # use par_slice::*;
+    /// assert!(NoRefParSlice::try_chunks_with_closure(|i| i, 4, 3).is_err());
+    ///
+    /// let no_ref_slice = NoRefParSlice::try_chunks_with_closure(|i| i, 4, 2).unwrap();
+    /// unsafe { no_ref_slice.set_values(0, &[42, 42]) };
+    ///
+    /// assert_eq!(no_ref_slice.into().as_ref(), &[42, 42, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn try_chunks_with_closure<T: Send + Sync>(
+        closure: impl FnMut(usize) -> T,
+        len: usize,
+        chunk_size: usize,
+    ) -> Result<OwnedNoRefChunkParIndex<T>, ChunkSizeError> {
+        ChunkSizeError::check(len, chunk_size)?;
+        Ok(new_boxed_slice_with(len, closure).into_par_chunk_index_no_ref(chunk_size))
+    }
 }
@@ -23,9 +23,7 @@ impl PointerParSlice {
     /// assert_eq!(pointer_slice.into().as_ref(), &[42, 0, 0, 0]);
     /// ```
     #[inline]
-    pub fn new<T: Default + Send + Sync>(
-        len: usize,
-    ) -> impl PointerIndex<T> + ParCollection<T, Box<[T]>> {
+    pub fn new<T: Default + Send + Sync>(len: usize) -> OwnedPointerParIndex<T> {
         new_boxed_slice(len).into_pointer_par_index()
     }
 
@@ -46,10 +44,7 @@ impl PointerParSlice {
     /// assert_eq!(pointer_slice.into().as_ref(), &[42, 69, 69, 69]);
     /// ```
     #[inline]
-    pub fn with_value<T: Clone + Send + Sync>(
-        value: T,
-        len: usize,
-    ) -> impl PointerIndex<T> + ParCollection<T, Box<[T]>> {
+    pub fn with_value<T: Clone + Send + Sync>(value: T, len: usize) -> OwnedPointerParIndex<T> {
         new_boxed_slice_with_value(len, value).into_pointer_par_index()
     }
 
@@ -74,7 +69,7 @@ impl PointerParSlice {
     pub fn with_closure<T: Send + Sync>(
         closure: impl FnMut(usize) -> T,
         len: usize,
-    ) -> impl PointerIndex<T> + ParCollection<T, Box<[T]>> {
+    ) -> OwnedPointerParIndex<T> {
         new_boxed_slice_with(len, closure).into_pointer_par_index()
     }
 
@@ -98,7 +93,7 @@ impl PointerParSlice {
     pub fn new_chunks<T: Default + Send + Sync>(
         len: usize,
         chunk_size: usize,
-    ) -> impl PointerChunkIndex<T> + ParCollection<[T], Box<[T]>> {
+    ) -> OwnedPointerChunkParIndex<T> {
         assert_chunk_size(len, chunk_size);
         new_boxed_slice(len).into_pointer_par_chunk_index(chunk_size)
     }
@@ -124,7 +119,7 @@ impl PointerParSlice {
         value: T,
         len: usize,
         chunk_size: usize,
-    ) -> impl PointerChunkIndex<T> + ParCollection<[T], Box<[T]>> {
+    ) -> OwnedPointerChunkParIndex<T> {
         assert_chunk_size(len, chunk_size);
         new_boxed_slice_with_value(len, value).into_pointer_par_chunk_index(chunk_size)
     }
@@ -151,8 +146,92 @@ impl PointerParSlice {
         closure: impl FnMut(usize) -> T,
         len: usize,
         chunk_size: usize,
-    ) -> impl PointerChunkIndex<T> + ParCollection<[T], Box<[T]>> {
+    ) -> OwnedPointerChunkParIndex<T> {
         assert_chunk_size(len, chunk_size);
         new_boxed_slice_with(len, closure).into_pointer_par_chunk_index(chunk_size)
     }
+
+    /// Constructs a new slice with `len` elements, each initialized
+    /// to [`T::default`](`Default::default`), that allows unsynchronized
+    /// access to chunks of `chunk_size` of its elements through
+    /// [`PointerChunkIndex`] and that can be converted into a boxed slice, or a [`ChunkSizeError`]
+    /// if `len` is not divisible by `chunk_size`.
+    ///
+    /// This is the non-panicking version of [`new_chunks`](`Self::new_chunks`).
+    ///
+    /// # Examples
+    /// ```
+    /// # use par_slice::*;
+    /// assert!(PointerParSlice::try_new_chunks::<u32>(4, 3).is_err());
+    ///
+    /// let pointer_slice = PointerParSlice::try_new_chunks(4, 2).unwrap();
+    /// unsafe { (*pointer_slice.get_mut_ptr(0))[0] = 42 };
+    ///
+    /// assert_eq!(pointer_slice.into().as_ref(), &[42, 0, 0, 0]);
+    /// ```
+    #[inline]
+    pub fn try_new_chunks<T: Default + Send + Sync>(
+        len: usize,
+        chunk_size: usize,
+    ) -> Result<OwnedPointerChunkParIndex<T>, ChunkSizeError> {
+        ChunkSizeError::check(len, chunk_size)?;
+        Ok(new_boxed_slice(len).into_pointer_par_chunk_index(chunk_size))
+    }
+
+    /// Constructs a new slice with `len` elements, each initialized
+    /// to `value`, that allows unsynchronized
+    /// access to chunks of `chunk_size` of its elements through
+    /// [`PointerChunkIndex`] and that can be converted into a boxed slice, or a [`ChunkSizeError`]
+    /// if `len` is not divisible by `chunk_size`.
+    ///
+    /// This is the non-panicking version of [`chunks_with_value`](`Self::chunks_with_value`).
+    ///
+    /// # Examples
+    /// ```
+    /// # use par_slice::*;
+    /// assert!(PointerParSlice::try_chunks_with_value(0, 4, 3).is_err());
+    ///
+    /// let pointer_slice = PointerParSlice::try_chunks_with_value(0, 4, 2).unwrap();
+    /// unsafe { (*pointer_slice.get_mut_ptr(0))[0] = 42 };
+    ///
+    /// assert_eq!(pointer_slice.into().as_ref(), &[42, 0, 0, 0]);
+    /// ```
+    #[inline]
+    pub fn try_chunks_with_value<T: Clone + Send + Sync>(
+        value: T,
+        len: usize,
+        chunk_size: usize,
+    ) -> Result<OwnedPointerChunkParIndex<T>, ChunkSizeError> {
+        ChunkSizeError::check(len, chunk_size)?;
+        Ok(new_boxed_slice_with_value(len, value).into_pointer_par_chunk_index(chunk_size))
+    }
+
+    /// Constructs a new slice with `len` elements, each initialized
+    /// to the return value of `closure` called with the index of the element
+    /// to generate as an [`usize`], that allows unsynchronized
+    /// access to chunks of `chunk_size` of its elements through
+    /// [`PointerChunkIndex`] and that can be converted into a boxed slice, or a [`ChunkSizeError`]
+    /// if `len` is not divisible by `chunk_size`.
+    ///
+    /// This is the non-panicking version of [`chunks_with_closure`](`Self::chunks_with_closure`).
+    ///
+    /// # Examples
+    /// ```
+    /// # use par_slice::*;
+    /// assert!(PointerParSlice::try_chunks_with_closure(|i| i, 4, 3).is_err());
+    ///
+    /// let pointer_slice = PointerParSlice::try_chunks_with_closure(|i| i, 4, 2).unwrap();
+    /// unsafe { (*pointer_slice.get_mut_ptr(0))[0] = 42 };
+    ///
+    /// assert_eq!(pointer_slice.into().as_ref(), &[42, 1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn try_chunks_with_closure<T: Send + Sync>(
+        closure: impl FnMut(usize) -> T,
+        len: usize,
+        chunk_size: usize,
+    ) -> Result<OwnedPointerChunkParIndex<T>, ChunkSizeError> {
+        ChunkSizeError::check(len, chunk_size)?;
+        Ok(new_boxed_slice_with(len, closure).into_pointer_par_chunk_index(chunk_size))
+    }
 }
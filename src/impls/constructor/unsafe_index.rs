@@ -1,4 +1,12 @@
 use crate::*;
+#[cfg(feature = "allocator_api")]
+use std::alloc::Allocator;
+#[cfg(feature = "bytemuck")]
+use std::{
+    alloc::{alloc_zeroed, handle_alloc_error, Layout},
+    ptr,
+};
+use std::{collections::BTreeMap, mem::MaybeUninit};
 
 /// Utility struct for contructors for slices that allow unsynchronized access
 /// to their elements through [`UnsafeIndex`] and [`UnsafeChunkIndex`].
@@ -23,9 +31,7 @@ impl ParSlice {
     /// ```
     #[allow(clippy::new_ret_no_self)]
     #[inline]
-    pub fn new<T: Default + Send + Sync>(
-        len: usize,
-    ) -> impl UnsafeIndex<T> + ParCollection<T, Box<[T]>> {
+    pub fn new<T: Default + Send + Sync>(len: usize) -> OwnedParIndex<T> {
         new_boxed_slice(len).into_par_index()
     }
 
@@ -46,10 +52,7 @@ impl ParSlice {
     /// assert_eq!(unsafe_slice.into().as_ref(), &[42, 69, 69, 69]);
     /// ```
     #[inline]
-    pub fn with_value<T: Clone + Send + Sync>(
-        value: T,
-        len: usize,
-    ) -> impl UnsafeIndex<T> + ParCollection<T, Box<[T]>> {
+    pub fn with_value<T: Clone + Send + Sync>(value: T, len: usize) -> OwnedParIndex<T> {
         new_boxed_slice_with_value(len, value).into_par_index()
     }
 
@@ -74,10 +77,432 @@ impl ParSlice {
     pub fn with_closure<T: Send + Sync>(
         closure: impl FnMut(usize) -> T,
         len: usize,
-    ) -> impl UnsafeIndex<T> + ParCollection<T, Box<[T]>> {
+    ) -> OwnedParIndex<T> {
         new_boxed_slice_with(len, closure).into_par_index()
     }
 
+    /// Constructs a new slice with the items yielded by `iter`, that allows unsynchronized
+    /// access to its elements through [`UnsafeIndex`] and that can be
+    /// converted into a boxed slice.
+    ///
+    /// Exactly `iter.len()` elements are allocated, without collecting the items
+    /// into an intermediate [`Vec`] first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `iter` yields fewer items than `iter.len()`. In debug builds, it also panics
+    /// if `iter` yields more items than `iter.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use par_slice::*;
+    /// let unsafe_slice = ParSlice::from_iter_exact((0..4).map(|x| x * 2));
+    ///
+    /// unsafe {
+    ///     *unsafe_slice.get_mut(0) = 42;
+    /// }
+    ///
+    /// assert_eq!(unsafe_slice.into().as_ref(), &[42, 2, 4, 6]);
+    /// ```
+    #[inline]
+    pub fn from_iter_exact<T: Send + Sync>(
+        iter: impl ExactSizeIterator<Item = T>,
+    ) -> OwnedParIndex<T> {
+        new_boxed_slice_from_iter_exact(iter).into_par_index()
+    }
+
+    /// Constructs a new slice with the `len` items yielded by `iter`, that allows unsynchronized
+    /// access to its elements through [`UnsafeIndex`] and that can be converted into a boxed slice.
+    ///
+    /// Unlike [`from_iter_exact`](`Self::from_iter_exact`), `iter` may be any [`Iterator`]:
+    /// its length is validated against `len` instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `iter` yields fewer or more than `len` items.
+    ///
+    /// # Examples
+    /// ```
+    /// # use par_slice::*;
+    /// let unsafe_slice = ParSlice::from_exact_len(3, (0..).step_by(2).take_while(|&x| x < 6));
+    ///
+    /// unsafe {
+    ///     *unsafe_slice.get_mut(0) = 42;
+    /// }
+    ///
+    /// assert_eq!(unsafe_slice.into().as_ref(), &[42, 2, 4]);
+    /// ```
+    #[inline]
+    pub fn from_exact_len<T: Send + Sync>(
+        len: usize,
+        mut iter: impl Iterator<Item = T>,
+    ) -> OwnedParIndex<T> {
+        let boxed = new_boxed_slice_from_iter_prefix(len, &mut iter);
+        assert!(
+            iter.next().is_none(),
+            "iterator should yield {len} items. Got more items"
+        );
+        boxed.into_par_index()
+    }
+
+    /// Constructs a new slice with `len` elements, initialized in parallel using [`rayon`]'s
+    /// global thread pool, that allows unsynchronized access to its elements through
+    /// [`UnsafeIndex`] and that can be converted into a boxed slice.
+    ///
+    /// The element with index `i` is initialized to `f(state, i)`, where `state` is created with
+    /// `make_state` once per [`rayon`] job and is then reused for all the indices of that job.
+    /// This is useful for initializers that need per-thread scratch space (*e.g.* a random number generator).
+    ///
+    /// This method is only available with the `rayon` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// # use par_slice::*;
+    /// let unsafe_slice = ParSlice::par_with_state(4, || 10, |offset, i| *offset + i);
+    ///
+    /// unsafe {
+    ///     *unsafe_slice.get_mut(0) = 42;
+    /// }
+    ///
+    /// assert_eq!(unsafe_slice.into().as_ref(), &[42, 11, 12, 13]);
+    /// ```
+    #[cfg(feature = "rayon")]
+    #[inline]
+    pub fn par_with_state<T: Send + Sync, S>(
+        len: usize,
+        make_state: impl Fn() -> S + Sync,
+        f: impl Fn(&mut S, usize) -> T + Sync,
+    ) -> OwnedParIndex<T> {
+        par_new_boxed_slice_with_state(len, make_state, f).into_par_index()
+    }
+
+    /// Constructs a new slice with the elements of all `parts` in order, that allows unsynchronized
+    /// access to its elements through [`UnsafeIndex`] and that can be converted into a boxed slice.
+    ///
+    /// This is useful to join per-thread partial results into a single collection for the next phase.
+    /// The elements are moved (not cloned) into a single allocation of the total length.
+    ///
+    /// # Examples
+    /// ```
+    /// # use par_slice::*;
+    /// let parts: Vec<Box<[i32]>> = vec![Box::new([1, 2]), Box::new([3, 4, 5])];
+    /// let unsafe_slice = ParSlice::concat(parts);
+    ///
+    /// assert_eq!(unsafe { *unsafe_slice.get(3) }, 4);
+    /// assert_eq!(unsafe_slice.into().as_ref(), &[1, 2, 3, 4, 5]);
+    /// ```
+    #[inline]
+    pub fn concat<T: Send + Sync>(parts: Vec<Box<[T]>>) -> OwnedParIndex<T> {
+        let mut concat = Vec::with_capacity(parts.iter().map(|part| part.len()).sum());
+        for part in parts {
+            concat.extend(part.into_vec());
+        }
+        concat.into_boxed_slice().into_par_index()
+    }
+
+    /// Constructs a new slice with `pattern` repeated `times` times, that allows unsynchronized
+    /// access to its elements through [`UnsafeIndex`] and that can be converted into a boxed slice.
+    ///
+    /// Exactly `pattern.len() * times` elements are allocated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pattern.len() * times` overflows.
+    ///
+    /// # Examples
+    /// ```
+    /// # use par_slice::*;
+    /// let unsafe_slice = ParSlice::repeat_pattern(&[1, 2], 3);
+    ///
+    /// unsafe {
+    ///     *unsafe_slice.get_mut(0) = 42;
+    /// }
+    ///
+    /// assert_eq!(unsafe_slice.into().as_ref(), &[42, 2, 1, 2, 1, 2]);
+    /// ```
+    #[inline]
+    pub fn repeat_pattern<T: Clone + Send + Sync>(pattern: &[T], times: usize) -> OwnedParIndex<T> {
+        let len = pattern
+            .len()
+            .checked_mul(times)
+            .expect("the length of the slice should not overflow usize");
+        new_boxed_slice_with(len, |i| pattern[i % pattern.len()].clone()).into_par_index()
+    }
+
+    /// Splits `map` into the vector of its keys and a slice of its values, both in key order,
+    /// where the slice allows unsynchronized access to its elements through [`UnsafeIndex`] and
+    /// can be converted into a boxed slice.
+    ///
+    /// Index `i` of the slice holds the value associated with the `i`-th key of the returned vector,
+    /// so the caller can map keys to indexes (*e.g.* with [`slice::binary_search`]) and back.
+    ///
+    /// # Examples
+    /// ```
+    /// # use par_slice::*;
+    /// # use std::collections::BTreeMap;
+    /// let map = BTreeMap::from([(30, 'c'), (10, 'a'), (20, 'b')]);
+    /// let (keys, unsafe_slice) = ParSlice::values_into_par_index(map);
+    ///
+    /// let index = keys.binary_search(&20).unwrap();
+    /// unsafe {
+    ///     *unsafe_slice.get_mut(index) = 'z';
+    /// }
+    ///
+    /// assert_eq!(keys, vec![10, 20, 30]);
+    /// assert_eq!(unsafe_slice.into().as_ref(), &['a', 'z', 'c']);
+    /// ```
+    #[inline]
+    pub fn values_into_par_index<K: Ord, V: Send + Sync>(
+        map: BTreeMap<K, V>,
+    ) -> (Vec<K>, OwnedParIndex<V>) {
+        let (keys, values): (Vec<K>, Vec<V>) = map.into_iter().unzip();
+        (keys, values.into_boxed_slice().into_par_index())
+    }
+
+    /// Constructs a new slice with `len` elements, each initialized
+    /// to all zero bytes, that allows unsynchronized access to its elements
+    /// through [`UnsafeIndex`] and that can be converted into a boxed slice.
+    ///
+    /// The memory is requested already zeroed from the allocator (see [`std::alloc::alloc_zeroed`]),
+    /// which is much faster than writing the elements one by one for large slices.
+    ///
+    /// # Examples
+    /// ```
+    /// # use par_slice::*;
+    /// let unsafe_slice = ParSlice::new_zeroed::<u32>(4);
+    ///
+    /// unsafe {
+    ///     *unsafe_slice.get_mut(0) = 42;
+    /// }
+    ///
+    /// assert_eq!(unsafe_slice.into().as_ref(), &[42, 0, 0, 0]);
+    /// ```
+    #[cfg(feature = "bytemuck")]
+    #[inline]
+    pub fn new_zeroed<T: bytemuck::Zeroable + Send + Sync>(len: usize) -> OwnedParIndex<T> {
+        let layout =
+            Layout::array::<T>(len).expect("the size of the slice should not overflow isize");
+        if layout.size() == 0 {
+            return new_boxed_slice_with(len, |_| T::zeroed()).into_par_index();
+        }
+
+        let ptr = unsafe {
+            // Safety: layout has a non-zero size
+            alloc_zeroed(layout)
+        };
+        if ptr.is_null() {
+            handle_alloc_error(layout);
+        }
+        let zeroed = unsafe {
+            // Safety: ptr was allocated by the global allocator with the layout of len elements
+            // of T and T is Zeroable, so the all zero bit pattern is a valid T
+            Box::from_raw(ptr::slice_from_raw_parts_mut(ptr as *mut T, len))
+        };
+        zeroed.into_par_index()
+    }
+
+    /// Constructs a new slice with `len` elements, each initialized
+    /// to [`T::default`](`Default::default`), whose first element is aligned to
+    /// the larger of `ALIGN` and `align_of::<T>()` bytes, that allows unsynchronized
+    /// access to its elements through [`UnsafeIndex`] and that can be
+    /// converted into a boxed slice.
+    ///
+    /// This is useful for SIMD workloads that require a stricter alignment than the
+    /// one of `T`. Converting the slice into a boxed slice moves its elements into a new
+    /// allocation with the default alignment.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ALIGN` is not a power of two.
+    ///
+    /// # Examples
+    /// ```
+    /// # use par_slice::*;
+    /// let unsafe_slice = ParSlice::new_aligned::<64, u32>(4);
+    ///
+    /// assert_eq!(unsafe_slice.base_align(), 64);
+    /// assert_eq!(&*unsafe { unsafe_slice.get(0) } as *const u32 as usize % 64, 0);
+    ///
+    /// unsafe {
+    ///     *unsafe_slice.get_mut(0) = 42;
+    /// }
+    ///
+    /// assert_eq!(unsafe_slice.into().as_ref(), &[42, 0, 0, 0]);
+    /// ```
+    #[inline]
+    pub fn new_aligned<const ALIGN: usize, T: Default + Send + Sync>(
+        len: usize,
+    ) -> AlignedParIndex<T> {
+        let aligned = AlignedBox::new_with(len, ALIGN, |_| T::default());
+        AlignedParIndex::from_inner(UnsafeCellSlice::new_aligned(aligned))
+    }
+
+    /// Constructs a new slice with `len` elements, each initialized
+    /// to `value`, whose first element is aligned to the larger of `ALIGN`
+    /// and `align_of::<T>()` bytes, that allows unsynchronized
+    /// access to its elements through [`UnsafeIndex`] and that can be
+    /// converted into a boxed slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ALIGN` is not a power of two.
+    ///
+    /// # Examples
+    /// ```
+    /// # use par_slice::*;
+    /// let unsafe_slice = ParSlice::with_value_aligned::<32, _>(69_u8, 4);
+    ///
+    /// assert_eq!(unsafe_slice.base_align(), 32);
+    ///
+    /// unsafe {
+    ///     *unsafe_slice.get_mut(0) = 42;
+    /// }
+    ///
+    /// assert_eq!(unsafe_slice.into().as_ref(), &[42, 69, 69, 69]);
+    /// ```
+    #[inline]
+    pub fn with_value_aligned<const ALIGN: usize, T: Clone + Send + Sync>(
+        value: T,
+        len: usize,
+    ) -> AlignedParIndex<T> {
+        let aligned = AlignedBox::new_with(len, ALIGN, |_| value.clone());
+        AlignedParIndex::from_inner(UnsafeCellSlice::new_aligned(aligned))
+    }
+
+    /// Constructs a new slice with `len` elements, each initialized
+    /// to the return value of `closure` called with the index of the element
+    /// to generate as an [`usize`], whose first element is aligned to the larger
+    /// of `ALIGN` and `align_of::<T>()` bytes, that allows unsynchronized
+    /// access to its elements through [`UnsafeIndex`] and that can be
+    /// converted into a boxed slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ALIGN` is not a power of two.
+    ///
+    /// # Examples
+    /// ```
+    /// # use par_slice::*;
+    /// let unsafe_slice = ParSlice::with_closure_aligned::<64, _>(|i| i as f32, 4);
+    ///
+    /// assert_eq!(unsafe_slice.base_align(), 64);
+    ///
+    /// unsafe {
+    ///     *unsafe_slice.get_mut(0) = 42.0;
+    /// }
+    ///
+    /// assert_eq!(unsafe_slice.into().as_ref(), &[42.0, 1.0, 2.0, 3.0]);
+    /// ```
+    #[inline]
+    pub fn with_closure_aligned<const ALIGN: usize, T: Send + Sync>(
+        closure: impl FnMut(usize) -> T,
+        len: usize,
+    ) -> AlignedParIndex<T> {
+        let aligned = AlignedBox::new_with(len, ALIGN, closure);
+        AlignedParIndex::from_inner(UnsafeCellSlice::new_aligned(aligned))
+    }
+
+    /// Constructs a new slice with `len` elements, each initialized
+    /// to [`T::default`](`Default::default`), allocated with `alloc`,
+    /// that allows unsynchronized access to its elements through [`UnsafeIndex`]
+    /// and that can be converted into a vector in the same allocator.
+    ///
+    /// Converting the slice into a [`Vec<T, A>`](`Vec`) keeps its allocation, so that
+    /// [`Vec::into_boxed_slice`] returns a [`Box<[T], A>`](`Box`) without reallocating, while
+    /// [`SerialAccess::into_boxed_slice`] moves its elements into the global allocator.
+    ///
+    /// The slice can't be converted directly into a [`Box<[T], A>`](`Box`): as [`Box`] is a
+    /// fundamental type, the orphan rules don't allow implementing [`From`] or [`Into`]
+    /// for a boxed slice with an arbitrary allocator `A`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(allocator_api)]
+    /// # use par_slice::*;
+    /// use std::alloc::Global;
+    ///
+    /// let unsafe_slice = ParSlice::new_in::<u32, _>(4, Global);
+    ///
+    /// unsafe {
+    ///     *unsafe_slice.get_mut(0) = 42;
+    /// }
+    ///
+    /// let vec: Vec<u32, Global> = unsafe_slice.into();
+    /// assert_eq!(vec, [42, 0, 0, 0]);
+    /// ```
+    #[cfg(feature = "allocator_api")]
+    #[inline]
+    pub fn new_in<T: Default + Send + Sync, A: Allocator + Send + Sync>(
+        len: usize,
+        alloc: A,
+    ) -> AllocatorParIndex<T, A> {
+        let boxed = AllocatorBox::new_with_in(len, alloc, |_| T::default());
+        AllocatorParIndex::from_inner(UnsafeCellSlice::new_allocator(boxed))
+    }
+
+    /// Constructs a new slice with `len` elements, each initialized
+    /// to `value`, allocated with `alloc`, that allows unsynchronized
+    /// access to its elements through [`UnsafeIndex`] and that can be
+    /// converted into a vector in the same allocator.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(allocator_api)]
+    /// # use par_slice::*;
+    /// use std::alloc::Global;
+    ///
+    /// let unsafe_slice = ParSlice::with_value_in(69, 4, Global);
+    ///
+    /// unsafe {
+    ///     *unsafe_slice.get_mut(0) = 42;
+    /// }
+    ///
+    /// let vec: Vec<i32, Global> = unsafe_slice.into();
+    /// assert_eq!(vec, [42, 69, 69, 69]);
+    /// ```
+    #[cfg(feature = "allocator_api")]
+    #[inline]
+    pub fn with_value_in<T: Clone + Send + Sync, A: Allocator + Send + Sync>(
+        value: T,
+        len: usize,
+        alloc: A,
+    ) -> AllocatorParIndex<T, A> {
+        let boxed = AllocatorBox::new_with_in(len, alloc, |_| value.clone());
+        AllocatorParIndex::from_inner(UnsafeCellSlice::new_allocator(boxed))
+    }
+
+    /// Constructs a new slice with `len` elements, each initialized
+    /// to the return value of `closure` called with the index of the element
+    /// to generate as an [`usize`], allocated with `alloc`, that allows unsynchronized
+    /// access to its elements through [`UnsafeIndex`] and that can be
+    /// converted into a vector in the same allocator.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(allocator_api)]
+    /// # use par_slice::*;
+    /// use std::alloc::Global;
+    ///
+    /// let unsafe_slice = ParSlice::with_closure_in(|i| i, 4, Global);
+    ///
+    /// unsafe {
+    ///     *unsafe_slice.get_mut(0) = 42;
+    /// }
+    ///
+    /// let vec: Vec<usize, Global> = unsafe_slice.into();
+    /// assert_eq!(vec, [42, 1, 2, 3]);
+    /// ```
+    #[cfg(feature = "allocator_api")]
+    #[inline]
+    pub fn with_closure_in<T: Send + Sync, A: Allocator + Send + Sync>(
+        closure: impl FnMut(usize) -> T,
+        len: usize,
+        alloc: A,
+    ) -> AllocatorParIndex<T, A> {
+        let boxed = AllocatorBox::new_with_in(len, alloc, closure);
+        AllocatorParIndex::from_inner(UnsafeCellSlice::new_allocator(boxed))
+    }
+
     /// Constructs a new slice with `len` elements, each initialized
     /// to [`T::default`](`Default::default`), that allows unsynchronized
     /// access to chunks of `chunk_size` of its elements through
@@ -98,7 +523,7 @@ impl ParSlice {
     pub fn new_chunks<T: Default + Send + Sync>(
         len: usize,
         chunk_size: usize,
-    ) -> impl UnsafeChunkIndex<T> + ParCollection<[T], Box<[T]>> {
+    ) -> OwnedChunkParIndex<T> {
         assert_chunk_size(len, chunk_size);
         new_boxed_slice(len).into_par_chunk_index(chunk_size)
     }
@@ -124,7 +549,7 @@ impl ParSlice {
         value: T,
         len: usize,
         chunk_size: usize,
-    ) -> impl UnsafeChunkIndex<T> + ParCollection<[T], Box<[T]>> {
+    ) -> OwnedChunkParIndex<T> {
         assert_chunk_size(len, chunk_size);
         new_boxed_slice_with_value(len, value).into_par_chunk_index(chunk_size)
     }
@@ -151,8 +576,166 @@ impl ParSlice {
         closure: impl FnMut(usize) -> T,
         len: usize,
         chunk_size: usize,
-    ) -> impl UnsafeChunkIndex<T> + ParCollection<[T], Box<[T]>> {
+    ) -> OwnedChunkParIndex<T> {
         assert_chunk_size(len, chunk_size);
         new_boxed_slice_with(len, closure).into_par_chunk_index(chunk_size)
     }
+
+    /// Constructs a new slice with a chunk for each of the `rows`, that allows unsynchronized
+    /// access to its chunks through [`UnsafeChunkIndex`] and that can be converted into a boxed slice.
+    ///
+    /// Rows of different lengths are padded with clones of `pad` to the length of the longest row,
+    /// which is the chunk size of the slice (or `1` if every row is empty).
+    /// The original length of each row is returned together with the slice, so that padding
+    /// can be skipped when reading the chunks.
+    ///
+    /// # Examples
+    /// ```
+    /// # use par_slice::*;
+    /// let (unsafe_slice, row_lens) = ParSlice::padded_chunks(vec![vec![1], vec![2, 3, 4]], 0);
+    ///
+    /// assert_eq!(unsafe_slice.chunk_size(), 3);
+    /// assert_eq!(row_lens, vec![1, 3]);
+    /// assert_eq!(unsafe { &unsafe_slice.get(0)[..row_lens[0]] }, &[1]);
+    ///
+    /// assert_eq!(unsafe_slice.into().as_ref(), &[1, 0, 0, 2, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn padded_chunks<T: Clone + Send + Sync>(
+        rows: Vec<Vec<T>>,
+        pad: T,
+    ) -> (OwnedChunkParIndex<T>, Vec<usize>) {
+        let row_lens: Vec<usize> = rows.iter().map(Vec::len).collect();
+        let chunk_size = row_lens.iter().copied().max().unwrap_or(0).max(1);
+
+        let mut padded = Vec::with_capacity(rows.len() * chunk_size);
+        for row in rows {
+            let len = row.len();
+            padded.extend(row);
+            padded.extend(std::iter::repeat_n(pad.clone(), chunk_size - len));
+        }
+
+        (
+            padded.into_boxed_slice().into_par_chunk_index(chunk_size),
+            row_lens,
+        )
+    }
+
+    /// Constructs a new slice with `len` elements, each initialized
+    /// to [`T::default`](`Default::default`), that allows unsynchronized
+    /// access to chunks of `chunk_size` of its elements through
+    /// [`UnsafeChunkIndex`] and that can be converted into a boxed slice, or a [`ChunkSizeError`]
+    /// if `len` is not divisible by `chunk_size`.
+    ///
+    /// This is the non-panicking version of [`new_chunks`](`Self::new_chunks`).
+    ///
+    /// # Examples
+    /// ```
+    /// # use par_slice::*;
+    /// assert!(ParSlice::try_new_chunks::<u32>(4, 3).is_err());
+    ///
+    /// let unsafe_slice = ParSlice::try_new_chunks(4, 2).unwrap();
+    /// unsafe { unsafe_slice.get_mut(0)[0] = 42 };
+    ///
+    /// assert_eq!(unsafe_slice.into().as_ref(), &[42, 0, 0, 0]);
+    /// ```
+    #[inline]
+    pub fn try_new_chunks<T: Default + Send + Sync>(
+        len: usize,
+        chunk_size: usize,
+    ) -> Result<OwnedChunkParIndex<T>, ChunkSizeError> {
+        ChunkSizeError::check(len, chunk_size)?;
+        Ok(new_boxed_slice(len).into_par_chunk_index(chunk_size))
+    }
+
+    /// Constructs a new slice with `len` elements, each initialized
+    /// to `value`, that allows unsynchronized
+    /// access to chunks of `chunk_size` of its elements through
+    /// [`UnsafeChunkIndex`] and that can be converted into a boxed slice, or a [`ChunkSizeError`]
+    /// if `len` is not divisible by `chunk_size`.
+    ///
+    /// This is the non-panicking version of [`chunks_with_value`](`Self::chunks_with_value`).
+    ///
+    /// # Examples
+    /// ```
+    /// # use par_slice::*;
+    /// assert!(ParSlice::try_chunks_with_value(0, 4, 3).is_err());
+    ///
+    /// let unsafe_slice = ParSlice::try_chunks_with_value(0, 4, 2).unwrap();
+    /// unsafe { unsafe_slice.get_mut(0)[0] = 42 };
+    ///
+    /// assert_eq!(unsafe_slice.into().as_ref(), &[42, 0, 0, 0]);
+    /// ```
+    #[inline]
+    pub fn try_chunks_with_value<T: Clone + Send + Sync>(
+        value: T,
+        len: usize,
+        chunk_size: usize,
+    ) -> Result<OwnedChunkParIndex<T>, ChunkSizeError> {
+        ChunkSizeError::check(len, chunk_size)?;
+        Ok(new_boxed_slice_with_value(len, value).into_par_chunk_index(chunk_size))
+    }
+
+    /// Constructs a new slice with `len` elements, each initialized
+    /// to the return value of `closure` called with the index of the element
+    /// to generate as an [`usize`], that allows unsynchronized
+    /// access to chunks of `chunk_size` of its elements through
+    /// [`UnsafeChunkIndex`] and that can be converted into a boxed slice, or a [`ChunkSizeError`]
+    /// if `len` is not divisible by `chunk_size`.
+    ///
+    /// This is the non-panicking version of [`chunks_with_closure`](`Self::chunks_with_closure`).
+    ///
+    /// # Examples
+    /// ```
+    /// # use par_slice::*;
+    /// assert!(ParSlice::try_chunks_with_closure(|i| i, 4, 3).is_err());
+    ///
+    /// let unsafe_slice = ParSlice::try_chunks_with_closure(|i| i, 4, 2).unwrap();
+    /// unsafe { unsafe_slice.get_mut(0)[0] = 42 };
+    ///
+    /// assert_eq!(unsafe_slice.into().as_ref(), &[42, 1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn try_chunks_with_closure<T: Send + Sync>(
+        closure: impl FnMut(usize) -> T,
+        len: usize,
+        chunk_size: usize,
+    ) -> Result<OwnedChunkParIndex<T>, ChunkSizeError> {
+        ChunkSizeError::check(len, chunk_size)?;
+        Ok(new_boxed_slice_with(len, closure).into_par_chunk_index(chunk_size))
+    }
+
+    /// Constructs a new slice with `len` uninitialized elements that allows
+    /// unsynchronized access to chunks of `chunk_size` of its elements through
+    /// [`UnsafeChunkIndex`] and that can be converted into a boxed slice.
+    ///
+    /// Once every element has been initialized, the slice can be turned into one
+    /// of initialized elements with [`SerialAccess::assume_init_chunks`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is not divisible by `chunk_size`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use par_slice::*;
+    /// # use std::mem::MaybeUninit;
+    /// let unsafe_slice = ParSlice::new_uninit_chunks(4, 2);
+    ///
+    /// for i in 0..2 {
+    ///     unsafe { unsafe_slice.get_mut(i) }.fill(MaybeUninit::new(i));
+    /// }
+    ///
+    /// // Every element was initialized
+    /// let unsafe_slice = unsafe { unsafe_slice.assume_init_chunks() };
+    /// assert_eq!(unsafe_slice.into().as_ref(), &[0, 0, 1, 1]);
+    /// ```
+    #[inline]
+    pub fn new_uninit_chunks<T: Send + Sync>(
+        len: usize,
+        chunk_size: usize,
+    ) -> OwnedChunkParIndex<MaybeUninit<T>> {
+        assert_chunk_size(len, chunk_size);
+        Box::new_uninit_slice(len).into_par_chunk_index(chunk_size)
+    }
 }
@@ -1,130 +1,326 @@
 use crate::*;
+use std::sync::Arc;
 
+// The implementations below return the named collection types instead of the opaque
+// types of the traits, so that the returned collections can be named (*e.g.* in a struct field)
+// when the type of the input collection is known.
+
+#[allow(refining_impl_trait)]
 unsafe impl<T: Send + Sync> ParIndexView<T> for [T] {
     #[inline]
-    fn as_pointer_par_index(&mut self) -> impl PointerIndex<T> + ParView<T> {
-        UnsafeCellSlice::new_borrowed(self)
+    fn as_pointer_par_index(&mut self) -> PointerView<'_, T> {
+        PointerView::new(self)
+    }
+
+    #[inline]
+    fn as_par_index_no_ref(&mut self) -> NoRefView<'_, T> {
+        NoRefView::new(self)
     }
 
     #[inline]
-    fn as_par_index_no_ref(&mut self) -> impl UnsafeNoRefIndex<T> + ParView<T> {
-        UnsafeCellSlice::new_borrowed(self)
+    fn as_par_index(&mut self) -> UnsafeView<'_, T> {
+        UnsafeView::new(self)
     }
 
     #[inline]
-    fn as_par_index(&mut self) -> impl UnsafeIndex<T> + ParView<T> {
-        UnsafeCellSlice::new_borrowed(self)
+    fn as_pointer_par_chunk_index(&mut self, chunk_size: usize) -> PointerChunkView<'_, T> {
+        PointerChunkView::new(self, chunk_size)
     }
 
     #[inline]
-    fn as_pointer_par_chunk_index(
+    fn as_par_chunk_index_no_ref(&mut self, chunk_size: usize) -> NoRefChunkView<'_, T> {
+        NoRefChunkView::new(self, chunk_size)
+    }
+
+    #[inline]
+    fn as_par_chunk_index(&mut self, chunk_size: usize) -> UnsafeChunkView<'_, T> {
+        UnsafeChunkView::new(self, chunk_size)
+    }
+
+    #[inline]
+    fn try_as_par_chunk_index(
         &mut self,
         chunk_size: usize,
-    ) -> impl PointerChunkIndex<T> + ParView<[T]> {
-        assert_chunk_size(self.len(), chunk_size);
-        UnsafeCellChunkSlice::new_borrowed(self, chunk_size)
+    ) -> Result<UnsafeChunkView<'_, T>, ChunkSizeError> {
+        ChunkSizeError::check(self.len(), chunk_size)?;
+        Ok(self.as_par_chunk_index(chunk_size))
     }
 
     #[inline]
-    fn as_par_chunk_index_no_ref(
+    fn as_rchunk_index(&mut self, chunk_size: usize) -> RevChunkView<'_, T> {
+        RevChunkView::new(self, chunk_size)
+    }
+
+    #[inline]
+    fn as_par_chunk_index_remainder(
         &mut self,
         chunk_size: usize,
-    ) -> impl UnsafeNoRefChunkIndex<T> + ParView<[T]> {
-        assert_chunk_size(self.len(), chunk_size);
-        UnsafeCellChunkSlice::new_borrowed(self, chunk_size)
+    ) -> (UnsafeChunkView<'_, T>, &mut [T]) {
+        assert!(chunk_size > 0, "chunk_size should be greater than 0");
+        let mid = self.len() - self.len() % chunk_size;
+        let (chunks, remainder) = self.split_at_mut(mid);
+        (chunks.as_par_chunk_index(chunk_size), remainder)
+    }
+}
+
+#[allow(refining_impl_trait)]
+unsafe impl<T: Send + Sync, const N: usize> ParIndexView<T> for [T; N] {
+    #[inline]
+    fn as_pointer_par_index(&mut self) -> PointerView<'_, T> {
+        self.as_mut_slice().as_pointer_par_index()
+    }
+
+    #[inline]
+    fn as_par_index_no_ref(&mut self) -> NoRefView<'_, T> {
+        self.as_mut_slice().as_par_index_no_ref()
+    }
+
+    #[inline]
+    fn as_par_index(&mut self) -> UnsafeView<'_, T> {
+        self.as_mut_slice().as_par_index()
+    }
+
+    #[inline]
+    fn as_pointer_par_chunk_index(&mut self, chunk_size: usize) -> PointerChunkView<'_, T> {
+        self.as_mut_slice().as_pointer_par_chunk_index(chunk_size)
+    }
+
+    #[inline]
+    fn as_par_chunk_index_no_ref(&mut self, chunk_size: usize) -> NoRefChunkView<'_, T> {
+        self.as_mut_slice().as_par_chunk_index_no_ref(chunk_size)
     }
 
     #[inline]
-    fn as_par_chunk_index(&mut self, chunk_size: usize) -> impl UnsafeChunkIndex<T> + ParView<[T]> {
-        assert_chunk_size(self.len(), chunk_size);
-        UnsafeCellChunkSlice::new_borrowed(self, chunk_size)
+    fn as_par_chunk_index(&mut self, chunk_size: usize) -> UnsafeChunkView<'_, T> {
+        self.as_mut_slice().as_par_chunk_index(chunk_size)
+    }
+
+    #[inline]
+    fn try_as_par_chunk_index(
+        &mut self,
+        chunk_size: usize,
+    ) -> Result<UnsafeChunkView<'_, T>, ChunkSizeError> {
+        self.as_mut_slice().try_as_par_chunk_index(chunk_size)
+    }
+
+    #[inline]
+    fn as_rchunk_index(&mut self, chunk_size: usize) -> RevChunkView<'_, T> {
+        self.as_mut_slice().as_rchunk_index(chunk_size)
+    }
+
+    #[inline]
+    fn as_par_chunk_index_remainder(
+        &mut self,
+        chunk_size: usize,
+    ) -> (UnsafeChunkView<'_, T>, &mut [T]) {
+        self.as_mut_slice().as_par_chunk_index_remainder(chunk_size)
     }
 }
 
-unsafe impl<T: Send + Sync> IntoParIndex<T> for Box<[T]> {
+#[allow(refining_impl_trait)]
+unsafe impl<T: Send + Sync, const N: usize> ParIndexView<T> for &mut [T; N] {
     #[inline]
-    fn into_pointer_par_index(self) -> impl PointerIndex<T> + ParCollection<T, Self> {
-        UnsafeCellSlice::new_owned(self)
+    fn as_pointer_par_index(&mut self) -> PointerView<'_, T> {
+        self.as_mut_slice().as_pointer_par_index()
     }
 
     #[inline]
-    fn into_par_index_no_ref(self) -> impl UnsafeNoRefIndex<T> + ParCollection<T, Self> {
-        UnsafeCellSlice::new_owned(self)
+    fn as_par_index_no_ref(&mut self) -> NoRefView<'_, T> {
+        self.as_mut_slice().as_par_index_no_ref()
     }
 
     #[inline]
-    fn into_par_index(self) -> impl UnsafeIndex<T> + ParCollection<T, Self> {
-        UnsafeCellSlice::new_owned(self)
+    fn as_par_index(&mut self) -> UnsafeView<'_, T> {
+        self.as_mut_slice().as_par_index()
     }
 
     #[inline]
-    fn into_pointer_par_chunk_index(
-        self,
+    fn as_pointer_par_chunk_index(&mut self, chunk_size: usize) -> PointerChunkView<'_, T> {
+        self.as_mut_slice().as_pointer_par_chunk_index(chunk_size)
+    }
+
+    #[inline]
+    fn as_par_chunk_index_no_ref(&mut self, chunk_size: usize) -> NoRefChunkView<'_, T> {
+        self.as_mut_slice().as_par_chunk_index_no_ref(chunk_size)
+    }
+
+    #[inline]
+    fn as_par_chunk_index(&mut self, chunk_size: usize) -> UnsafeChunkView<'_, T> {
+        self.as_mut_slice().as_par_chunk_index(chunk_size)
+    }
+
+    #[inline]
+    fn try_as_par_chunk_index(
+        &mut self,
         chunk_size: usize,
-    ) -> impl PointerChunkIndex<T> + ParCollection<[T], Self> {
-        assert_chunk_size(self.len(), chunk_size);
-        UnsafeCellChunkSlice::new_owned(self, chunk_size)
+    ) -> Result<UnsafeChunkView<'_, T>, ChunkSizeError> {
+        self.as_mut_slice().try_as_par_chunk_index(chunk_size)
     }
 
     #[inline]
-    fn into_par_chunk_index_no_ref(
-        self,
+    fn as_rchunk_index(&mut self, chunk_size: usize) -> RevChunkView<'_, T> {
+        self.as_mut_slice().as_rchunk_index(chunk_size)
+    }
+
+    #[inline]
+    fn as_par_chunk_index_remainder(
+        &mut self,
         chunk_size: usize,
-    ) -> impl UnsafeNoRefChunkIndex<T> + ParCollection<[T], Self> {
-        assert_chunk_size(self.len(), chunk_size);
-        UnsafeCellChunkSlice::new_owned(self, chunk_size)
+    ) -> (UnsafeChunkView<'_, T>, &mut [T]) {
+        self.as_mut_slice().as_par_chunk_index_remainder(chunk_size)
+    }
+}
+
+#[allow(refining_impl_trait)]
+unsafe impl<T: Send + Sync> IntoParIndex<T> for Box<[T]> {
+    #[inline]
+    fn into_pointer_par_index(self) -> OwnedPointerParIndex<T> {
+        OwnedPointerParIndex::new(self)
+    }
+
+    #[inline]
+    fn into_par_index_no_ref(self) -> OwnedNoRefParIndex<T> {
+        OwnedNoRefParIndex::new(self)
+    }
+
+    #[inline]
+    fn into_par_index(self) -> OwnedParIndex<T> {
+        OwnedParIndex::new(self)
+    }
+
+    #[inline]
+    fn into_pointer_par_chunk_index(self, chunk_size: usize) -> OwnedPointerChunkParIndex<T> {
+        OwnedPointerChunkParIndex::new(self, chunk_size)
+    }
+
+    #[inline]
+    fn into_par_chunk_index_no_ref(self, chunk_size: usize) -> OwnedNoRefChunkParIndex<T> {
+        OwnedNoRefChunkParIndex::new(self, chunk_size)
+    }
+
+    #[inline]
+    fn into_par_chunk_index(self, chunk_size: usize) -> OwnedChunkParIndex<T> {
+        OwnedChunkParIndex::new(self, chunk_size)
     }
 
     #[inline]
-    fn into_par_chunk_index(
+    fn try_into_par_chunk_index(
         self,
         chunk_size: usize,
-    ) -> impl UnsafeChunkIndex<T> + ParCollection<[T], Self> {
-        assert_chunk_size(self.len(), chunk_size);
-        UnsafeCellChunkSlice::new_owned(self, chunk_size)
+    ) -> Result<OwnedChunkParIndex<T>, ChunkSizeError> {
+        ChunkSizeError::check(self.len(), chunk_size)?;
+        Ok(self.into_par_chunk_index(chunk_size))
     }
 }
 
+#[allow(refining_impl_trait)]
 unsafe impl<T: Send + Sync> IntoParIndex<T> for Vec<T> {
     #[inline]
-    fn into_pointer_par_index(self) -> impl PointerIndex<T> + ParCollection<T, Self> {
-        UnsafeCellSlice::new_owned(self.into_boxed_slice())
+    fn into_pointer_par_index(self) -> VecPointerParIndex<T> {
+        VecPointerParIndex::new(self)
+    }
+
+    #[inline]
+    fn into_par_index_no_ref(self) -> VecNoRefParIndex<T> {
+        VecNoRefParIndex::new(self)
+    }
+
+    #[inline]
+    fn into_par_index(self) -> VecParIndex<T> {
+        VecParIndex::new(self)
     }
 
     #[inline]
-    fn into_par_index_no_ref(self) -> impl UnsafeNoRefIndex<T> + ParCollection<T, Self> {
-        UnsafeCellSlice::new_owned(self.into_boxed_slice())
+    fn into_pointer_par_chunk_index(self, chunk_size: usize) -> VecPointerChunkParIndex<T> {
+        VecPointerChunkParIndex::new(self, chunk_size)
     }
 
     #[inline]
-    fn into_par_index(self) -> impl UnsafeIndex<T> + ParCollection<T, Self> {
-        UnsafeCellSlice::new_owned(self.into_boxed_slice())
+    fn into_par_chunk_index_no_ref(self, chunk_size: usize) -> VecNoRefChunkParIndex<T> {
+        VecNoRefChunkParIndex::new(self, chunk_size)
     }
 
     #[inline]
-    fn into_pointer_par_chunk_index(
+    fn into_par_chunk_index(self, chunk_size: usize) -> VecChunkParIndex<T> {
+        VecChunkParIndex::new(self, chunk_size)
+    }
+
+    #[inline]
+    fn try_into_par_chunk_index(
         self,
         chunk_size: usize,
-    ) -> impl PointerChunkIndex<T> + ParCollection<[T], Self> {
-        assert_chunk_size(self.len(), chunk_size);
-        UnsafeCellChunkSlice::new_owned(self.into_boxed_slice(), chunk_size)
+    ) -> Result<VecChunkParIndex<T>, ChunkSizeError> {
+        ChunkSizeError::check(self.len(), chunk_size)?;
+        Ok(self.into_par_chunk_index(chunk_size))
+    }
+}
+
+#[allow(refining_impl_trait)]
+unsafe impl<T: Send + Sync, C: AsMut<[T]> + ?Sized> AsParIndexExt<T> for C {
+    #[inline]
+    fn as_par_index_via_asmut<'a>(&'a mut self) -> UnsafeView<'a, T>
+    where
+        T: 'a,
+    {
+        self.as_mut().as_par_index()
     }
+}
 
+unsafe impl<T: Send + Sync> IntoSharedParIndex<T> for Arc<[T]> {
     #[inline]
-    fn into_par_chunk_index_no_ref(
+    fn into_shared_pointer_par_index(
         self,
-        chunk_size: usize,
-    ) -> impl UnsafeNoRefChunkIndex<T> + ParCollection<[T], Self> {
-        assert_chunk_size(self.len(), chunk_size);
-        UnsafeCellChunkSlice::new_owned(self.into_boxed_slice(), chunk_size)
+    ) -> impl SharedPointerIndex<T> + ParCollection<T, Self> + Clone {
+        SharedSlice::new(self)
+    }
+}
+
+/// The elements are always moved to the heap for the parallel phase, as
+/// inline storage can't be shared between threads without being moved.
+/// Converting back into a [`SmallVec`](`smallvec::SmallVec`) moves them back
+/// to inline storage if they fit.
+#[cfg(feature = "smallvec")]
+#[allow(refining_impl_trait)]
+unsafe impl<A: smallvec::Array> IntoParIndex<A::Item> for smallvec::SmallVec<A>
+where
+    A::Item: Send + Sync,
+{
+    #[inline]
+    fn into_pointer_par_index(self) -> SmallVecPointerParIndex<A> {
+        SmallVecPointerParIndex::new(self)
+    }
+
+    #[inline]
+    fn into_par_index_no_ref(self) -> SmallVecNoRefParIndex<A> {
+        SmallVecNoRefParIndex::new(self)
+    }
+
+    #[inline]
+    fn into_par_index(self) -> SmallVecParIndex<A> {
+        SmallVecParIndex::new(self)
+    }
+
+    #[inline]
+    fn into_pointer_par_chunk_index(self, chunk_size: usize) -> SmallVecPointerChunkParIndex<A> {
+        SmallVecPointerChunkParIndex::new(self, chunk_size)
+    }
+
+    #[inline]
+    fn into_par_chunk_index_no_ref(self, chunk_size: usize) -> SmallVecNoRefChunkParIndex<A> {
+        SmallVecNoRefChunkParIndex::new(self, chunk_size)
+    }
+
+    #[inline]
+    fn into_par_chunk_index(self, chunk_size: usize) -> SmallVecChunkParIndex<A> {
+        SmallVecChunkParIndex::new(self, chunk_size)
     }
 
     #[inline]
-    fn into_par_chunk_index(
+    fn try_into_par_chunk_index(
         self,
         chunk_size: usize,
-    ) -> impl UnsafeChunkIndex<T> + ParCollection<[T], Self> {
-        assert_chunk_size(self.len(), chunk_size);
-        UnsafeCellChunkSlice::new_owned(self.into_boxed_slice(), chunk_size)
+    ) -> Result<SmallVecChunkParIndex<A>, ChunkSizeError> {
+        ChunkSizeError::check(self.len(), chunk_size)?;
+        Ok(self.into_par_chunk_index(chunk_size))
     }
 }
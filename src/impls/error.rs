@@ -0,0 +1,44 @@
+use std::{error::Error, fmt::Display};
+
+/// The error returned when a collection can't be split exactly in chunks of the requested size.
+///
+/// # Examples
+///
+/// ```
+/// # use par_slice::*;
+/// let mut collection = vec![0; 5];
+/// let error = collection.try_as_par_chunk_index(2).err().unwrap();
+///
+/// assert_eq!(error, ChunkSizeError { len: 5, chunk_size: 2 });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChunkSizeError {
+    /// The size of the collection that could not be split in chunks.
+    pub len: usize,
+    /// The requested size of the chunks.
+    pub chunk_size: usize,
+}
+
+impl ChunkSizeError {
+    /// Returns an error if a collection of size `len` can't be split exactly in chunks of size `chunk_size`.
+    #[inline]
+    pub(crate) fn check(len: usize, chunk_size: usize) -> Result<(), Self> {
+        if chunk_size == 0 || len % chunk_size != 0 {
+            Err(Self { len, chunk_size })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Display for ChunkSizeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "chunk_size should be a divisor of len. Got a chunk_size of {} for a collection of len {}",
+            self.chunk_size, self.len
+        )
+    }
+}
+
+impl Error for ChunkSizeError {}
@@ -0,0 +1,200 @@
+use crate::*;
+use std::{fmt::Debug, marker::PhantomData};
+
+/// A view over the elements of a chunk collection, indexed one element at a time, that allows
+/// unsynchronized access to them through references.
+///
+/// Element `i` of the view is element `i % chunk_size` of chunk `i / chunk_size` of the parent
+/// collection.
+/// No element is copied: a reference obtained from the view counts as a reference to the chunk
+/// containing the element for Rust's *aliasing rules*. With the `track-borrows` feature, the
+/// borrows of the view are recorded by the parent collection as borrows of the same chunk.
+///
+/// # Examples
+///
+/// ```
+/// # use par_slice::*;
+/// let collection = vec![0, 1, 2, 3, 4, 5].into_par_chunk_index(2);
+/// let flat = collection.as_flat();
+///
+/// assert_eq!(flat.len(), 6);
+/// assert_eq!(unsafe { flat.get(3) }, &3);
+/// ```
+pub struct FlatView<'a, T> {
+    parent: *const (),
+    get_unchecked: unsafe fn(*const (), usize, usize) -> *const T,
+    get_mut_unchecked: unsafe fn(*const (), usize, usize) -> *mut T,
+    #[cfg(feature = "track-borrows")]
+    get: unsafe fn(*const (), usize, usize) -> SharedBorrowGuard<'a, T>,
+    #[cfg(feature = "track-borrows")]
+    get_mut: unsafe fn(*const (), usize, usize) -> BorrowGuard<'a, T>,
+    len: usize,
+    chunk_size: usize,
+    _marker: PhantomData<&'a ()>,
+}
+
+// Safety: views are only created from shared references to `Sync` collections.
+unsafe impl<T> Send for FlatView<'_, T> {}
+unsafe impl<T> Sync for FlatView<'_, T> {}
+
+impl<T> Clone for FlatView<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for FlatView<'_, T> {}
+
+impl<T> Debug for FlatView<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FlatView")
+            .field("len", &self.len)
+            .field("chunk_size", &self.chunk_size)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, T> FlatView<'a, T> {
+    /// Creates a view over the elements of `parent`.
+    #[inline]
+    pub(crate) fn new<C: UnsafeChunkIndex<T> + Sync>(parent: &'a C) -> Self {
+        /// Returns a pointer to an element of a chunk of a type-erased `C` through `get_unchecked`.
+        unsafe fn erased_get_unchecked<T, C: UnsafeChunkIndex<T>>(
+            parent: *const (),
+            chunk: usize,
+            offset: usize,
+        ) -> *const T {
+            unsafe {
+                // Safety: parent was created from a &C that outlives the view and
+                // the caller guarantees chunk and offset are valid
+                (*(parent as *const C))
+                    .get_unchecked(chunk)
+                    .get_unchecked(offset)
+            }
+        }
+
+        /// Calls `get_mut_at_unchecked` on a type-erased `C`.
+        unsafe fn erased_get_mut_unchecked<T, C: UnsafeChunkIndex<T>>(
+            parent: *const (),
+            chunk: usize,
+            offset: usize,
+        ) -> *mut T {
+            unsafe {
+                // Safety: parent was created from a &C that outlives the view and
+                // the caller guarantees chunk and offset are valid
+                (*(parent as *const C)).get_mut_at_unchecked(chunk, offset)
+            }
+        }
+
+        /// Borrows an element of a chunk of a type-erased `C` through `get`.
+        #[cfg(feature = "track-borrows")]
+        unsafe fn erased_get<'a, T, C: UnsafeChunkIndex<T> + 'a>(
+            parent: *const (),
+            chunk: usize,
+            offset: usize,
+        ) -> SharedBorrowGuard<'a, T> {
+            // Safety: parent was created from a &'a C
+            let parent = unsafe { &*(parent as *const C) };
+            unsafe { parent.get(chunk) }.map(|chunk| &chunk[offset])
+        }
+
+        /// Mutably borrows an element of a chunk of a type-erased `C` through `get_mut`.
+        #[cfg(feature = "track-borrows")]
+        unsafe fn erased_get_mut<'a, T, C: UnsafeChunkIndex<T> + 'a>(
+            parent: *const (),
+            chunk: usize,
+            offset: usize,
+        ) -> BorrowGuard<'a, T> {
+            // Safety: parent was created from a &'a C
+            let parent = unsafe { &*(parent as *const C) };
+            unsafe { parent.get_mut(chunk) }.map(|chunk| &mut chunk[offset])
+        }
+
+        Self {
+            parent: parent as *const C as *const (),
+            get_unchecked: erased_get_unchecked::<T, C>,
+            get_mut_unchecked: erased_get_mut_unchecked::<T, C>,
+            #[cfg(feature = "track-borrows")]
+            get: erased_get::<'a, T, C>,
+            #[cfg(feature = "track-borrows")]
+            get_mut: erased_get_mut::<'a, T, C>,
+            len: parent.num_elements(),
+            chunk_size: parent.chunk_size(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+unsafe impl<T> TrustedSizedCollection for FlatView<'_, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+unsafe impl<T> UnsafeIndex<T> for FlatView<'_, T> {
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> &T {
+        #[cfg(feature = "checked")]
+        assert_in_bounds(self.len(), index);
+        debug_assert!(index < self.len());
+
+        unsafe {
+            // Safety: the caller guarantees Rust's aliasing rules are respected and that
+            // index is valid, so its chunk and offset in the parent are valid as well
+            &*(self.get_unchecked)(
+                self.parent,
+                index / self.chunk_size,
+                index % self.chunk_size,
+            )
+        }
+    }
+
+    #[inline]
+    unsafe fn get_mut_unchecked(&self, index: usize) -> &mut T {
+        #[cfg(feature = "checked")]
+        assert_in_bounds(self.len(), index);
+        debug_assert!(index < self.len());
+
+        unsafe {
+            // Safety: the caller guarantees Rust's aliasing rules are respected and that
+            // index is valid, so its chunk and offset in the parent are valid as well
+            &mut *(self.get_mut_unchecked)(
+                self.parent,
+                index / self.chunk_size,
+                index % self.chunk_size,
+            )
+        }
+    }
+
+    #[cfg(feature = "track-borrows")]
+    #[inline]
+    unsafe fn get(&self, index: usize) -> SharedBorrowGuard<'_, T> {
+        assert_in_bounds(self.len(), index);
+        unsafe {
+            // Safety: we just checked that index is in bounds, so its chunk and offset
+            // in the parent are valid as well
+            (self.get)(
+                self.parent,
+                index / self.chunk_size,
+                index % self.chunk_size,
+            )
+        }
+    }
+
+    #[cfg(feature = "track-borrows")]
+    #[inline]
+    unsafe fn get_mut(&self, index: usize) -> BorrowGuard<'_, T> {
+        assert_in_bounds(self.len(), index);
+        unsafe {
+            // Safety: we just checked that index is in bounds, so its chunk and offset
+            // in the parent are valid as well
+            (self.get_mut)(
+                self.parent,
+                index / self.chunk_size,
+                index % self.chunk_size,
+            )
+        }
+    }
+}
@@ -53,6 +53,8 @@ impl<T: ?Sized, B: ParView<T>> IndexWrapper<(), T, B> {
     /// ```
     /// # use par_slice::*;
     /// let wrapped_vector = IndexWrapper::new::<u8>(vec![0, 1, 2].into_par_index());
+    ///
+    /// assert_eq!(unsafe { *wrapped_vector.get(2_u8) }, 2);
     /// ```
     #[inline]
     pub fn new<I: AsUsize>(collection: B) -> IndexWrapper<I, T, B> {
@@ -71,8 +73,9 @@ impl<I, T, B> IndexWrapper<I, T, B> {
     /// ```
     /// # use par_slice::*;
     /// let wrapped_vector = IndexWrapper::new::<usize>(vec![0, 1, 2].into_par_index());
+    /// unsafe { *wrapped_vector.get_mut(1) = 42 };
     ///
-    /// assert_eq!(wrapped_vector.into_inner().into(), vec![0, 1, 2]);
+    /// assert_eq!(wrapped_vector.into_inner().into(), vec![0, 42, 2]);
     /// ```
     #[inline]
     pub fn into_inner(self) -> B {
@@ -82,24 +85,28 @@ impl<I, T, B> IndexWrapper<I, T, B> {
 
 impl<I: AsUsize, T: ?Sized, B: PointerIndex<T>> IndexWrapper<I, T, B> {
     #[doc = wrapper_method_doc!(PointerIndex::get_ptr)]
+    #[must_use]
     #[inline]
     pub fn get_ptr(&self, index: I) -> *const T {
         self.inner.get_ptr(index.as_usize())
     }
 
     #[doc = wrapper_method_doc!(PointerIndex::get_ptr_unchecked)]
+    #[must_use]
     #[inline]
     pub unsafe fn get_ptr_unchecked(&self, index: I) -> *const T {
         unsafe { self.inner.get_ptr_unchecked(index.as_usize()) }
     }
 
     #[doc = wrapper_method_doc!(PointerIndex::get_mut_ptr)]
+    #[must_use]
     #[inline]
     pub fn get_mut_ptr(&self, index: I) -> *mut T {
         self.inner.get_mut_ptr(index.as_usize())
     }
 
     #[doc = wrapper_method_doc!(PointerIndex::get_mut_ptr_unchecked)]
+    #[must_use]
     #[inline]
     pub unsafe fn get_mut_ptr_unchecked(&self, index: I) -> *mut T {
         unsafe { self.inner.get_mut_ptr_unchecked(index.as_usize()) }
@@ -108,6 +115,7 @@ impl<I: AsUsize, T: ?Sized, B: PointerIndex<T>> IndexWrapper<I, T, B> {
 
 impl<I: AsUsize, T, B: UnsafeNoRefIndex<T>> IndexWrapper<I, T, B> {
     #[doc = wrapper_method_doc!(UnsafeNoRefIndex::get_value)]
+    #[must_use]
     #[inline]
     pub unsafe fn get_value(&self, index: I) -> T
     where
@@ -117,6 +125,7 @@ impl<I: AsUsize, T, B: UnsafeNoRefIndex<T>> IndexWrapper<I, T, B> {
     }
 
     #[doc = wrapper_method_doc!(UnsafeNoRefIndex::get_value_unchecked)]
+    #[must_use]
     #[inline]
     pub unsafe fn get_value_unchecked(&self, index: I) -> T
     where
@@ -125,6 +134,24 @@ impl<I: AsUsize, T, B: UnsafeNoRefIndex<T>> IndexWrapper<I, T, B> {
         unsafe { self.inner.get_value_unchecked(index.as_usize()) }
     }
 
+    #[doc = wrapper_method_doc!(UnsafeNoRefIndex::get_value_into, ", out")]
+    #[inline]
+    pub unsafe fn get_value_into(&self, index: I, out: *mut T)
+    where
+        T: Copy,
+    {
+        unsafe { self.inner.get_value_into(index.as_usize(), out) }
+    }
+
+    #[doc = wrapper_method_doc!(UnsafeNoRefIndex::get_value_into_unchecked, ", out")]
+    #[inline]
+    pub unsafe fn get_value_into_unchecked(&self, index: I, out: *mut T)
+    where
+        T: Copy,
+    {
+        unsafe { self.inner.get_value_into_unchecked(index.as_usize(), out) }
+    }
+
     #[doc = wrapper_method_doc!(UnsafeNoRefIndex::set_value, ", value")]
     #[inline]
     pub unsafe fn set_value(&self, index: I, value: T) {
@@ -186,25 +213,47 @@ impl<I: AsUsize, T, B: UnsafeNoRefChunkIndex<T>> IndexWrapper<I, T, B> {
 
 impl<I: AsUsize, T: ?Sized, B: UnsafeIndex<T>> IndexWrapper<I, T, B> {
     #[doc = wrapper_method_doc!(UnsafeIndex::get)]
+    #[must_use]
+    #[cfg(not(feature = "track-borrows"))]
     #[inline]
     pub unsafe fn get(&self, index: I) -> &T {
         unsafe { self.inner.get(index.as_usize()) }
     }
 
+    #[doc = wrapper_method_doc!(UnsafeIndex::get)]
+    #[must_use]
+    #[cfg(feature = "track-borrows")]
+    #[inline]
+    pub unsafe fn get(&self, index: I) -> SharedBorrowGuard<'_, T> {
+        unsafe { self.inner.get(index.as_usize()) }
+    }
+
     #[doc = wrapper_method_doc!(UnsafeIndex::get_unchecked)]
+    #[must_use]
     #[inline]
     pub unsafe fn get_unchecked(&self, index: I) -> &T {
         unsafe { self.inner.get_unchecked(index.as_usize()) }
     }
 
     #[doc = wrapper_method_doc!(UnsafeIndex::get_mut)]
+    #[must_use]
+    #[cfg(not(feature = "track-borrows"))]
     #[allow(clippy::mut_from_ref)]
     #[inline]
     pub unsafe fn get_mut(&self, index: I) -> &mut T {
         unsafe { self.inner.get_mut(index.as_usize()) }
     }
 
+    #[doc = wrapper_method_doc!(UnsafeIndex::get_mut)]
+    #[must_use]
+    #[cfg(feature = "track-borrows")]
+    #[inline]
+    pub unsafe fn get_mut(&self, index: I) -> BorrowGuard<'_, T> {
+        unsafe { self.inner.get_mut(index.as_usize()) }
+    }
+
     #[doc = wrapper_method_doc!(UnsafeIndex::get_mut_unchecked)]
+    #[must_use]
     #[allow(clippy::mut_from_ref)]
     #[inline]
     pub unsafe fn get_mut_unchecked(&self, index: I) -> &mut T {
@@ -259,6 +308,15 @@ unsafe impl<I, T: ?Sized, B: PointerIndex<T>> PointerIndex<T> for IndexWrapper<I
 
 unsafe impl<I, T, B: PointerChunkIndex<T>> PointerChunkIndex<T> for IndexWrapper<I, [T], B> {}
 
+unsafe impl<I, T, B: ContiguousPointerChunkIndex<T>> ContiguousPointerChunkIndex<T>
+    for IndexWrapper<I, [T], B>
+{
+    #[inline]
+    fn full_mut_ptr(&self) -> *mut T {
+        self.inner.full_mut_ptr()
+    }
+}
+
 unsafe impl<I, T: ?Sized, B: UnsafeNoRefIndex<T>> UnsafeNoRefIndex<T> for IndexWrapper<I, T, B> {
     #[inline]
     unsafe fn get_value_unchecked(&self, index: usize) -> T
@@ -309,6 +367,32 @@ unsafe impl<I, T: ?Sized, B: UnsafeIndex<T>> UnsafeIndex<T> for IndexWrapper<I,
     unsafe fn get_mut_unchecked(&self, index: usize) -> &mut T {
         unsafe { self.inner.get_mut_unchecked(index) }
     }
+
+    #[cfg(feature = "track-borrows")]
+    #[inline]
+    unsafe fn get(&self, index: usize) -> SharedBorrowGuard<'_, T> {
+        unsafe { self.inner.get(index) }
+    }
+
+    #[cfg(feature = "track-borrows")]
+    #[inline]
+    unsafe fn get_mut(&self, index: usize) -> BorrowGuard<'_, T> {
+        unsafe { self.inner.get_mut(index) }
+    }
+}
+
+unsafe impl<I, T, B: UnsafeChunkIndex<T>> UnsafeChunkIndex<T> for IndexWrapper<I, [T], B> {
+    #[inline]
+    unsafe fn get_mut_at_unchecked(&self, chunk: usize, offset: usize) -> &mut T {
+        unsafe { self.inner.get_mut_at_unchecked(chunk, offset) }
+    }
 }
 
-unsafe impl<I, T, B: UnsafeChunkIndex<T>> UnsafeChunkIndex<T> for IndexWrapper<I, [T], B> {}
+unsafe impl<I, T, B: ContiguousUnsafeChunkIndex<T>> ContiguousUnsafeChunkIndex<T>
+    for IndexWrapper<I, [T], B>
+{
+    #[inline]
+    unsafe fn get_mut_chunks_unchecked(&self, start: usize, count: usize) -> &mut [T] {
+        unsafe { self.inner.get_mut_chunks_unchecked(start, count) }
+    }
+}
@@ -29,6 +29,18 @@ unsafe impl AsUsize for u32 {
     }
 }
 
+/// Maps a [`char`] to its Unicode scalar value, which is in the range `0..=0x10FFFF`.
+///
+/// A collection indexed by [`char`] must therefore have a length of `0x110000` to cover every
+/// codepoint.
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+unsafe impl AsUsize for char {
+    #[inline]
+    fn as_usize(&self) -> usize {
+        *self as u32 as usize
+    }
+}
+
 #[cfg(target_pointer_width = "64")]
 unsafe impl AsUsize for u64 {
     #[inline]
@@ -36,3 +48,43 @@ unsafe impl AsUsize for u64 {
         *self as usize
     }
 }
+
+/// Implements [`AsUsize`] for a tuple struct wrapping a single field that already implements
+/// [`AsUsize`].
+///
+/// The generated implementation forwards to the wrapped field, so it is injective as long as
+/// the wrapped type's implementation is.
+/// The macro only accepts tuple structs with exactly one field of the given type, so that two
+/// different values of the tuple struct always map to two different [`usize`] values.
+///
+/// # Examples
+///
+/// ```
+/// # use par_slice::*;
+/// struct NodeId(u32);
+/// impl_as_usize!(NodeId => u32);
+///
+/// assert_eq!(NodeId(42).as_usize(), 42);
+/// ```
+///
+/// Tuple structs with more than one field are rejected:
+///
+/// ```compile_fail
+/// # use par_slice::*;
+/// struct Edge(u32, u32);
+/// impl_as_usize!(Edge => u32);
+/// ```
+#[macro_export]
+macro_rules! impl_as_usize {
+    ($name:ty => $inner:ty) => {
+        // Safety: the tuple struct has a single field and its conversion is injective.
+        unsafe impl $crate::AsUsize for $name {
+            #[inline]
+            fn as_usize(&self) -> usize {
+                let Self(inner) = self;
+                let inner: &$inner = inner;
+                $crate::AsUsize::as_usize(inner)
+            }
+        }
+    };
+}
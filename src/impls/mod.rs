@@ -1,3 +1,24 @@
+mod aligned_box;
+pub(crate) use aligned_box::*;
+
+#[cfg(feature = "allocator_api")]
+mod allocator_box;
+#[cfg(feature = "allocator_api")]
+pub(crate) use allocator_box::*;
+
+#[cfg(feature = "track-borrows")]
+mod borrow_tracker;
+#[cfg(feature = "track-borrows")]
+pub(crate) use borrow_tracker::BorrowTracker;
+#[cfg(feature = "track-borrows")]
+pub use borrow_tracker::{BorrowGuard, SharedBorrowGuard};
+
+mod cast_view;
+pub(crate) use cast_view::*;
+
+mod chunk_writer;
+pub use chunk_writer::*;
+
 mod collection;
 
 mod conversion;
@@ -5,13 +26,48 @@ mod conversion;
 mod constructor;
 pub use constructor::*;
 
+mod error;
+pub use error::*;
+
+mod flat_view;
+pub use flat_view::*;
+
 mod indexing;
 
 mod index_wrapper;
 pub use index_wrapper::*;
 
+mod parallel;
+pub(crate) use parallel::*;
+
+mod par_index_types;
+pub use par_index_types::*;
+
+mod parity_view;
+pub use parity_view::*;
+
+mod pointer_grid;
+pub use pointer_grid::*;
+
+#[cfg(feature = "rayon")]
+mod rayon;
+#[cfg(feature = "rayon")]
+pub(crate) use rayon::*;
+
+mod rev_chunk_slice;
+pub(crate) use rev_chunk_slice::*;
+
+mod shared_slice;
+pub(crate) use shared_slice::*;
+
+mod sub_view;
+pub use sub_view::*;
+
 mod unsafe_cell_chunk_slice;
 pub(crate) use unsafe_cell_chunk_slice::*;
 
 mod unsafe_cell_slice;
 pub(crate) use unsafe_cell_slice::*;
+
+mod zip_par_index;
+pub use zip_par_index::*;
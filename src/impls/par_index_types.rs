@@ -0,0 +1,925 @@
+use crate::*;
+use std::{
+    cell::UnsafeCell,
+    cmp::Ordering,
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    ops::{Index, IndexMut},
+};
+
+// Safety for all the trait implementations generated by the macros below:
+// the types are only wrappers around their `inner` field.
+
+macro_rules! forward_sized {
+    ([$($g:tt)*] $ty:ty) => {
+        unsafe impl<$($g)*> TrustedSizedCollection for $ty {
+            #[inline]
+            fn len(&self) -> usize {
+                self.inner.len()
+            }
+        }
+    };
+}
+
+macro_rules! forward_chunk_sized {
+    ([$($g:tt)*] $ty:ty) => {
+        unsafe impl<$($g)*> TrustedChunkSizedCollection for $ty {
+            #[inline]
+            fn chunk_size(&self) -> usize {
+                self.inner.chunk_size()
+            }
+
+            #[inline]
+            fn num_elements(&self) -> usize {
+                self.inner.num_elements()
+            }
+
+            #[inline]
+            fn num_chunks(&self) -> usize {
+                self.inner.num_chunks()
+            }
+        }
+    };
+}
+
+macro_rules! forward_pointer {
+    ([$($g:tt)*] $ty:ty, $elem:ty) => {
+        unsafe impl<$($g)*> PointerIndex<$elem> for $ty {
+            #[inline]
+            unsafe fn get_ptr_unchecked(&self, index: usize) -> *const $elem {
+                unsafe { self.inner.get_ptr_unchecked(index) }
+            }
+
+            #[inline]
+            unsafe fn get_mut_ptr_unchecked(&self, index: usize) -> *mut $elem {
+                unsafe { self.inner.get_mut_ptr_unchecked(index) }
+            }
+        }
+    };
+}
+
+macro_rules! forward_pointer_chunk {
+    ([$($g:tt)*] $ty:ty, $elem:ty) => {
+        forward_chunk_sized!([$($g)*] $ty);
+        forward_pointer!([$($g)*] $ty, [$elem]);
+
+        unsafe impl<$($g)*> PointerChunkIndex<$elem> for $ty {}
+
+        unsafe impl<$($g)*> ContiguousPointerChunkIndex<$elem> for $ty {
+            #[inline]
+            fn full_mut_ptr(&self) -> *mut $elem {
+                self.inner.full_mut_ptr()
+            }
+        }
+    };
+}
+
+macro_rules! forward_no_ref {
+    ([$($g:tt)*] $ty:ty, $elem:ty) => {
+        unsafe impl<$($g)*> UnsafeNoRefIndex<$elem> for $ty {
+            #[inline]
+            unsafe fn get_value_unchecked(&self, index: usize) -> $elem
+            where
+                $elem: Copy,
+            {
+                unsafe { self.inner.get_value_unchecked(index) }
+            }
+
+            #[inline]
+            unsafe fn set_value_unchecked(&self, index: usize, value: $elem) {
+                unsafe {
+                    self.inner.set_value_unchecked(index, value);
+                }
+            }
+        }
+    };
+}
+
+macro_rules! forward_no_ref_chunk {
+    ([$($g:tt)*] $ty:ty, $elem:ty) => {
+        forward_pointer_chunk!([$($g)*] $ty, $elem);
+
+        unsafe impl<$($g)*> UnsafeNoRefChunkIndex<$elem> for $ty {
+            #[inline]
+            unsafe fn get_values_unchecked<O: AsMut<[$elem]>>(&self, index: usize, out: O) -> O
+            where
+                $elem: Copy,
+            {
+                unsafe { self.inner.get_values_unchecked(index, out) }
+            }
+
+            #[inline]
+            unsafe fn set_values_unchecked(&self, index: usize, values: &[$elem])
+            where
+                $elem: Clone,
+            {
+                unsafe {
+                    self.inner.set_values_unchecked(index, values);
+                }
+            }
+        }
+    };
+}
+
+macro_rules! forward_unsafe {
+    ([$($g:tt)*] $ty:ty, $elem:ty) => {
+        unsafe impl<$($g)*> UnsafeIndex<$elem> for $ty {
+            #[inline]
+            unsafe fn get_unchecked(&self, index: usize) -> &$elem {
+                unsafe { self.inner.get_unchecked(index) }
+            }
+
+            #[inline]
+            unsafe fn get_mut_unchecked(&self, index: usize) -> &mut $elem {
+                unsafe { self.inner.get_mut_unchecked(index) }
+            }
+
+            #[cfg(feature = "track-borrows")]
+            #[inline]
+            unsafe fn get(&self, index: usize) -> SharedBorrowGuard<'_, $elem> {
+                unsafe { self.inner.get(index) }
+            }
+
+            #[cfg(feature = "track-borrows")]
+            #[inline]
+            unsafe fn get_mut(&self, index: usize) -> BorrowGuard<'_, $elem> {
+                unsafe { self.inner.get_mut(index) }
+            }
+        }
+    };
+}
+
+macro_rules! forward_unsafe_chunk {
+    ([$($g:tt)*] $ty:ty, $elem:ty) => {
+        forward_non_contiguous_unsafe_chunk!([$($g)*] $ty, $elem);
+
+        unsafe impl<$($g)*> ContiguousUnsafeChunkIndex<$elem> for $ty {
+            #[inline]
+            unsafe fn get_mut_chunks_unchecked(&self, start: usize, count: usize) -> &mut [$elem] {
+                unsafe { self.inner.get_mut_chunks_unchecked(start, count) }
+            }
+        }
+    };
+}
+
+/// Like `forward_unsafe_chunk`, but for collections whose consecutive chunks are not
+/// consecutive in memory.
+macro_rules! forward_non_contiguous_unsafe_chunk {
+    ([$($g:tt)*] $ty:ty, $elem:ty) => {
+        forward_chunk_sized!([$($g)*] $ty);
+        forward_unsafe!([$($g)*] $ty, [$elem]);
+
+        unsafe impl<$($g)*> UnsafeChunkIndex<$elem> for $ty {
+            #[inline]
+            unsafe fn get_mut_at_unchecked(&self, chunk: usize, offset: usize) -> &mut $elem {
+                unsafe { self.inner.get_mut_at_unchecked(chunk, offset) }
+            }
+        }
+    };
+}
+
+/// Declares a view over a borrowed slice wrapping `$inner`.
+macro_rules! view {
+    ($(#[$attr:meta])* $name:ident($inner:ty)) => {
+        $(#[$attr])*
+        pub struct $name<'a, T> {
+            inner: $inner,
+        }
+
+        impl<'a, T> $name<'a, T> {
+            #[inline]
+            pub(crate) fn from_inner(inner: $inner) -> Self {
+                Self { inner }
+            }
+        }
+
+        impl<T> Debug for $name<'_, T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_tuple(stringify!($name)).field(&self.inner).finish()
+            }
+        }
+
+        forward_sized!(['a, T] $name<'a, T>);
+    };
+}
+
+/// Implements the public constructor of a view of single elements.
+macro_rules! element_view_new {
+    ($name:ident) => {
+        impl<'a, T> $name<'a, T> {
+            /// Creates a view over `slice`.
+            #[inline]
+            pub fn new(slice: &'a mut [T]) -> Self {
+                Self::from_inner(UnsafeCellSlice::new_borrowed(slice))
+            }
+        }
+    };
+}
+
+/// Implements the public constructor of a view of chunks.
+macro_rules! chunk_view_new {
+    ($name:ident) => {
+        impl<'a, T> $name<'a, T> {
+            /// Creates a view over `slice` with chunks of `chunk_size` elements.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the length of `slice` is not divisible by `chunk_size`.
+            #[inline]
+            pub fn new(slice: &'a mut [T], chunk_size: usize) -> Self {
+                assert_chunk_size(slice.len(), chunk_size);
+                Self::from_inner(UnsafeCellChunkSlice::new_borrowed(slice, chunk_size))
+            }
+        }
+    };
+}
+
+/// Declares an owned collection wrapping `$inner` that converts back into `$storage`.
+macro_rules! owned {
+    (
+        $(#[$attr:meta])*
+        [$($g:tt)*] $name:ident<$($u:ident),*>($inner:ty) for $storage:ty, $elem:ty
+    ) => {
+        owned_base! {
+            $(#[$attr])*
+            [$($g)*] $name<$($u),*>($inner) for $storage, $elem
+        }
+
+        impl<$($g)*> Clone for $name<$($u),*>
+        where
+            $elem: Clone,
+        {
+            #[inline]
+            fn clone(&self) -> Self {
+                Self::from_inner(self.inner.clone())
+            }
+        }
+    };
+}
+
+/// Like `owned`, but for collections that can't be cloned.
+macro_rules! owned_base {
+    (
+        $(#[$attr:meta])*
+        [$($g:tt)*] $name:ident<$($u:ident),*>($inner:ty) for $storage:ty, $elem:ty
+    ) => {
+        $(#[$attr])*
+        pub struct $name<$($g)*> {
+            inner: $inner,
+        }
+
+        impl<$($g)*> $name<$($u),*> {
+            #[inline]
+            pub(crate) fn from_inner(inner: $inner) -> Self {
+                Self { inner }
+            }
+
+            /// Converts the collection back into its backing collection.
+            ///
+            /// This is the same as the [`From`] conversion, but it lets the compiler infer the
+            /// returned type (*e.g.* in `collection.into().as_ref()`).
+            #[inline]
+            pub fn into(self) -> $storage {
+                self.inner.into()
+            }
+        }
+
+        impl<$($g)*> Debug for $name<$($u),*> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_tuple(stringify!($name)).field(&self.inner).finish()
+            }
+        }
+
+        impl<$($g)*> From<$name<$($u),*>> for $storage {
+            #[inline]
+            fn from(value: $name<$($u),*>) -> Self {
+                value.inner.into()
+            }
+        }
+
+        impl<$($g)*> SerialAccess<$elem> for $name<$($u),*> {
+            #[inline]
+            fn as_slice(&self) -> &[$elem] {
+                self.inner.as_slice()
+            }
+
+            #[inline]
+            fn as_mut_slice(&mut self) -> &mut [$elem] {
+                self.inner.as_mut_slice()
+            }
+
+            #[inline]
+            fn into_boxed_slice(self) -> Box<[$elem]> {
+                self.inner.into_boxed_slice()
+            }
+
+            #[inline]
+            fn base_align(&self) -> usize {
+                self.inner.base_align()
+            }
+        }
+
+        forward_sized!([$($g)*] $name<$($u),*>);
+    };
+}
+
+/// Implements the methods and traits shared by the owned collections of single elements.
+macro_rules! owned_elements {
+    (
+        [$($g:tt)*] $name:ident<$($u:ident),*>, $storage:ty, $elem:ty, $to_box:path;
+        $pointer:ident, $no_ref:ident, $unsafe_:ident
+    ) => {
+        impl<$($g)*> $name<$($u),*> {
+            /// Creates a collection that takes ownership of the elements of `collection`,
+            /// without moving or copying them.
+            #[inline]
+            pub fn new(collection: $storage) -> Self {
+                Self::from_inner(UnsafeCellSlice::new_owned($to_box(collection)))
+            }
+
+            /// Shortens the collection, keeping the first `new_len` elements and dropping the rest.
+            ///
+            /// This has no effect if `new_len` is greater than or equal to the length of the collection.
+            /// The elements are moved to a new allocation of `new_len` elements.
+            #[inline]
+            pub fn truncate(&mut self, new_len: usize) {
+                self.inner.truncate(new_len);
+            }
+
+            /// Resizes the collection so that its length is `new_len`, either dropping the elements
+            /// past `new_len` or filling the new slots with clones of `value`.
+            #[inline]
+            pub fn resize(&mut self, new_len: usize, value: $elem)
+            where
+                $elem: Clone,
+            {
+                self.inner.resize(new_len, value);
+            }
+
+            /// Reverses the order of the elements of the collection in place.
+            #[inline]
+            pub fn reverse(&mut self) {
+                self.inner.reverse();
+            }
+
+            /// Replaces the backing allocation of the collection with `new`, returning the previous one
+            /// (*e.g.* to swap the buffers of a double-buffering scheme between passes).
+            ///
+            /// # Panics
+            ///
+            /// Panics if `new` does not have the same length as the collection.
+            #[inline]
+            pub fn replace_inner(&mut self, new: Box<[$elem]>) -> Box<[$elem]> {
+                self.inner.replace_inner(new)
+            }
+
+            /// Consumes both collections, returning a collection with the elements of `self` followed
+            /// by the elements of `other` (*e.g.* to stitch together the results of two phases).
+            ///
+            /// The elements are moved to a new allocation of the total length.
+            #[inline]
+            pub fn append(self, other: Self) -> Self {
+                Self::from_inner(self.inner.append(other.inner))
+            }
+
+            /// Converts the collection into one that allows unsynchronized access to its elements
+            /// through [`PointerIndex`], without moving or copying them.
+            #[inline]
+            pub fn into_pointer(self) -> $pointer<$($u),*> {
+                $pointer::from_inner(self.inner)
+            }
+
+            /// Converts the collection into one that allows unsynchronized access to its elements
+            /// through [`UnsafeNoRefIndex`], without moving or copying them.
+            #[inline]
+            pub fn into_no_ref(self) -> $no_ref<$($u),*> {
+                $no_ref::from_inner(self.inner)
+            }
+
+            /// Converts the collection into one that allows unsynchronized access to its elements
+            /// through [`UnsafeIndex`], without moving or copying them.
+            #[inline]
+            pub fn into_unsafe_index(self) -> $unsafe_<$($u),*> {
+                $unsafe_::from_inner(self.inner)
+            }
+        }
+
+        impl<$($g)*> Default for $name<$($u),*> {
+            /// Returns an empty collection.
+            #[inline]
+            fn default() -> Self {
+                Self::from_inner(Default::default())
+            }
+        }
+
+        impl<$($g)*> PartialEq for $name<$($u),*>
+        where
+            $elem: PartialEq,
+        {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.inner == other.inner
+            }
+        }
+
+        impl<$($g)*> Eq for $name<$($u),*> where $elem: Eq {}
+
+        impl<$($g)*> PartialEq<Vec<$elem>> for $name<$($u),*>
+        where
+            $elem: PartialEq,
+        {
+            #[inline]
+            fn eq(&self, other: &Vec<$elem>) -> bool {
+                self.inner == *other
+            }
+        }
+
+        impl<$($g)*> PartialEq<&[$elem]> for $name<$($u),*>
+        where
+            $elem: PartialEq,
+        {
+            #[inline]
+            fn eq(&self, other: &&[$elem]) -> bool {
+                self.inner == *other
+            }
+        }
+
+        impl<$($g)*> PartialEq<$name<$($u),*>> for Vec<$elem>
+        where
+            $elem: PartialEq,
+        {
+            #[inline]
+            fn eq(&self, other: &$name<$($u),*>) -> bool {
+                *self == other.inner
+            }
+        }
+
+        impl<$($g)*> PartialOrd for $name<$($u),*>
+        where
+            $elem: PartialOrd,
+        {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                self.inner.partial_cmp(&other.inner)
+            }
+        }
+
+        impl<$($g)*> Ord for $name<$($u),*>
+        where
+            $elem: Ord,
+        {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.inner.cmp(&other.inner)
+            }
+        }
+
+        impl<$($g)*> Hash for $name<$($u),*>
+        where
+            $elem: Hash,
+        {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.inner.hash(state);
+            }
+        }
+
+        impl<$($g)*> IntoIterator for $name<$($u),*> {
+            type Item = $elem;
+            type IntoIter = std::vec::IntoIter<$elem>;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                self.inner.into_iter()
+            }
+        }
+
+        impl<$($g)*> Index<usize> for $name<$($u),*> {
+            type Output = $elem;
+
+            /// Returns a shared reference to the element at position `index`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is out of bounds.
+            #[inline]
+            fn index(&self, index: usize) -> &$elem {
+                &self.inner[index]
+            }
+        }
+
+        impl<$($g)*> IndexMut<usize> for $name<$($u),*> {
+            /// Returns a mutable reference to the element at position `index`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is out of bounds.
+            #[inline]
+            fn index_mut(&mut self, index: usize) -> &mut $elem {
+                &mut self.inner[index]
+            }
+        }
+    };
+}
+
+/// Implements the methods shared by the owned collections of chunks.
+macro_rules! owned_chunks {
+    ([$($g:tt)*] $name:ident<$($u:ident),*>, $storage:ty, $elem:ty, $to_box:path) => {
+        impl<$($g)*> $name<$($u),*> {
+            /// Creates a collection with chunks of `chunk_size` elements that takes ownership
+            /// of the elements of `collection`, without moving or copying them.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the length of `collection` is not divisible by `chunk_size`.
+            #[inline]
+            pub fn new(collection: $storage, chunk_size: usize) -> Self {
+                assert_chunk_size(collection.len(), chunk_size);
+                Self::from_inner(UnsafeCellChunkSlice::new_owned(
+                    $to_box(collection),
+                    chunk_size,
+                ))
+            }
+
+            /// Reverses the order of the chunks of the collection in place, keeping the order
+            /// of the elements inside each chunk.
+            #[inline]
+            pub fn reverse(&mut self) {
+                self.inner.reverse();
+            }
+
+            /// Consumes the collection, merging its chunks into a single sorted [`Vec`].
+            ///
+            /// Each chunk must already be sorted (*e.g.* after `par_sort_chunks`): the chunks are
+            /// merged with a heap-based k-way merge in `O(n log k)` time, where `k` is the number
+            /// of chunks. If a chunk is not sorted the result is still a permutation of the
+            /// elements, but it is not sorted either.
+            ///
+            /// Equal elements keep the order of their chunks.
+            #[inline]
+            pub fn into_sorted_vec(self) -> Vec<$elem>
+            where
+                $elem: Ord,
+            {
+                self.inner.into_sorted_vec()
+            }
+        }
+    };
+}
+
+/// Declares the six owned collections backed by `$storage`.
+macro_rules! owned_family {
+    (
+        [$($g:tt)*] <$($u:ident),*> $storage:ty, $elem:ty, $to_box:path, $storage_doc:literal;
+        $(#[$pointer_attr:meta])* $pointer:ident,
+        $(#[$no_ref_attr:meta])* $no_ref:ident,
+        $(#[$unsafe_attr:meta])* $unsafe_:ident,
+        $(#[$pointer_chunk_attr:meta])* $pointer_chunk:ident,
+        $(#[$no_ref_chunk_attr:meta])* $no_ref_chunk:ident,
+        $(#[$unsafe_chunk_attr:meta])* $unsafe_chunk:ident $(,)?
+    ) => {
+        owned! {
+            #[doc = concat!(
+                "An owned collection backed by ", $storage_doc,
+                " that allows unsynchronized access to its elements through [`PointerIndex`].",
+            )]
+            ///
+            #[doc = concat!(
+                "This is the collection returned by [`IntoParIndex::into_pointer_par_index`] for ", $storage_doc, ",",
+            )]
+            /// so it can be named (*e.g.* in a struct field).
+            $(#[$pointer_attr])*
+            [$($g)*] $pointer<$($u),*>(UnsafeCellSlice<Box<UnsafeCell<[$elem]>>>) for $storage, $elem
+        }
+        owned_elements!(
+            [$($g)*] $pointer<$($u),*>, $storage, $elem, $to_box;
+            $pointer, $no_ref, $unsafe_
+        );
+        forward_pointer!([$($g)*] $pointer<$($u),*>, $elem);
+
+        owned! {
+            #[doc = concat!(
+                "An owned collection backed by ", $storage_doc,
+                " that allows unsynchronized access to its elements through [`UnsafeNoRefIndex`].",
+            )]
+            ///
+            #[doc = concat!(
+                "This is the collection returned by [`IntoParIndex::into_par_index_no_ref`] for ", $storage_doc, ",",
+            )]
+            /// so it can be named (*e.g.* in a struct field).
+            $(#[$no_ref_attr])*
+            [$($g)*] $no_ref<$($u),*>(UnsafeCellSlice<Box<UnsafeCell<[$elem]>>>) for $storage, $elem
+        }
+        owned_elements!(
+            [$($g)*] $no_ref<$($u),*>, $storage, $elem, $to_box;
+            $pointer, $no_ref, $unsafe_
+        );
+        forward_pointer!([$($g)*] $no_ref<$($u),*>, $elem);
+        forward_no_ref!([$($g)*] $no_ref<$($u),*>, $elem);
+
+        owned! {
+            #[doc = concat!(
+                "An owned collection backed by ", $storage_doc,
+                " that allows unsynchronized access to its elements through [`UnsafeIndex`].",
+            )]
+            ///
+            #[doc = concat!(
+                "This is the collection returned by [`IntoParIndex::into_par_index`] for ", $storage_doc, ",",
+            )]
+            /// so it can be named (*e.g.* in a struct field).
+            $(#[$unsafe_attr])*
+            [$($g)*] $unsafe_<$($u),*>(UnsafeCellSlice<Box<UnsafeCell<[$elem]>>>) for $storage, $elem
+        }
+        owned_elements!(
+            [$($g)*] $unsafe_<$($u),*>, $storage, $elem, $to_box;
+            $pointer, $no_ref, $unsafe_
+        );
+        forward_unsafe!([$($g)*] $unsafe_<$($u),*>, $elem);
+
+        owned! {
+            #[doc = concat!(
+                "An owned collection backed by ", $storage_doc,
+                " that allows unsynchronized access to chunks of its elements through [`PointerChunkIndex`].",
+            )]
+            ///
+            #[doc = concat!(
+                "This is the collection returned by [`IntoParIndex::into_pointer_par_chunk_index`] for ", $storage_doc, ",",
+            )]
+            /// so it can be named (*e.g.* in a struct field).
+            $(#[$pointer_chunk_attr])*
+            [$($g)*] $pointer_chunk<$($u),*>(UnsafeCellChunkSlice<Box<UnsafeCell<[$elem]>>>) for $storage, $elem
+        }
+        owned_chunks!([$($g)*] $pointer_chunk<$($u),*>, $storage, $elem, $to_box);
+        forward_pointer_chunk!([$($g)*] $pointer_chunk<$($u),*>, $elem);
+
+        owned! {
+            #[doc = concat!(
+                "An owned collection backed by ", $storage_doc,
+                " that allows unsynchronized access to chunks of its elements through [`UnsafeNoRefChunkIndex`].",
+            )]
+            ///
+            #[doc = concat!(
+                "This is the collection returned by [`IntoParIndex::into_par_chunk_index_no_ref`] for ", $storage_doc, ",",
+            )]
+            /// so it can be named (*e.g.* in a struct field).
+            $(#[$no_ref_chunk_attr])*
+            [$($g)*] $no_ref_chunk<$($u),*>(UnsafeCellChunkSlice<Box<UnsafeCell<[$elem]>>>) for $storage, $elem
+        }
+        owned_chunks!([$($g)*] $no_ref_chunk<$($u),*>, $storage, $elem, $to_box);
+        forward_no_ref_chunk!([$($g)*] $no_ref_chunk<$($u),*>, $elem);
+
+        owned! {
+            #[doc = concat!(
+                "An owned collection backed by ", $storage_doc,
+                " that allows unsynchronized access to chunks of its elements through [`UnsafeChunkIndex`].",
+            )]
+            ///
+            #[doc = concat!(
+                "This is the collection returned by [`IntoParIndex::into_par_chunk_index`] for ", $storage_doc, ",",
+            )]
+            /// so it can be named (*e.g.* in a struct field).
+            $(#[$unsafe_chunk_attr])*
+            [$($g)*] $unsafe_chunk<$($u),*>(UnsafeCellChunkSlice<Box<UnsafeCell<[$elem]>>>) for $storage, $elem
+        }
+        owned_chunks!([$($g)*] $unsafe_chunk<$($u),*>, $storage, $elem, $to_box);
+        forward_unsafe_chunk!([$($g)*] $unsafe_chunk<$($u),*>, $elem);
+    };
+}
+
+view! {
+    /// A view over a borrowed slice that allows unsynchronized access to its elements
+    /// through [`PointerIndex`].
+    ///
+    /// This is the view returned by [`ParIndexView::as_pointer_par_index`] for slices and arrays,
+    /// so it can be named (*e.g.* in a struct field).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// struct Buffer<'a> {
+    ///     view: PointerView<'a, u32>,
+    /// }
+    ///
+    /// let mut v = vec![0; 4];
+    /// let buffer = Buffer {
+    ///     view: PointerView::new(&mut v),
+    /// };
+    /// unsafe { *buffer.view.get_mut_ptr(1) = 42 };
+    ///
+    /// assert_eq!(v, vec![0, 42, 0, 0]);
+    /// ```
+    PointerView(UnsafeCellSlice<&'a mut UnsafeCell<[T]>>)
+}
+element_view_new!(PointerView);
+forward_pointer!(['a, T] PointerView<'a, T>, T);
+
+view! {
+    /// A view over a borrowed slice that allows unsynchronized access to its elements
+    /// through [`UnsafeNoRefIndex`].
+    ///
+    /// This is the view returned by [`ParIndexView::as_par_index_no_ref`] for slices and arrays,
+    /// so it can be named (*e.g.* in a struct field).
+    NoRefView(UnsafeCellSlice<&'a mut UnsafeCell<[T]>>)
+}
+element_view_new!(NoRefView);
+forward_pointer!(['a, T] NoRefView<'a, T>, T);
+forward_no_ref!(['a, T] NoRefView<'a, T>, T);
+
+view! {
+    /// A view over a borrowed slice that allows unsynchronized access to its elements
+    /// through [`UnsafeIndex`].
+    ///
+    /// This is the view returned by [`ParIndexView::as_par_index`] for slices and arrays,
+    /// so it can be named (*e.g.* in a struct field).
+    ///
+    /// The view is [`Sync`] only if `T` is both [`Send`] and [`Sync`], as threads sharing the view
+    /// may both read and move the elements: for example, a view over
+    /// [`MutexGuard`](`std::sync::MutexGuard`)s (which are [`Sync`] but not [`Send`]) is not [`Sync`].
+    ///
+    /// ```compile_fail
+    /// # use par_slice::*;
+    /// # use std::sync::MutexGuard;
+    /// fn assert_sync<T: Sync>() {}
+    ///
+    /// assert_sync::<UnsafeView<'_, MutexGuard<'static, u32>>>();
+    /// ```
+    UnsafeView(UnsafeCellSlice<&'a mut UnsafeCell<[T]>>)
+}
+element_view_new!(UnsafeView);
+forward_unsafe!(['a, T] UnsafeView<'a, T>, T);
+
+view! {
+    /// A view over a borrowed slice that allows unsynchronized access to chunks of its elements
+    /// through [`PointerChunkIndex`].
+    ///
+    /// This is the view returned by [`ParIndexView::as_pointer_par_chunk_index`] for slices and arrays,
+    /// so it can be named (*e.g.* in a struct field).
+    PointerChunkView(UnsafeCellChunkSlice<&'a mut UnsafeCell<[T]>>)
+}
+chunk_view_new!(PointerChunkView);
+forward_pointer_chunk!(['a, T] PointerChunkView<'a, T>, T);
+
+view! {
+    /// A view over a borrowed slice that allows unsynchronized access to chunks of its elements
+    /// through [`UnsafeNoRefChunkIndex`].
+    ///
+    /// This is the view returned by [`ParIndexView::as_par_chunk_index_no_ref`] for slices and arrays,
+    /// so it can be named (*e.g.* in a struct field).
+    NoRefChunkView(UnsafeCellChunkSlice<&'a mut UnsafeCell<[T]>>)
+}
+chunk_view_new!(NoRefChunkView);
+forward_no_ref_chunk!(['a, T] NoRefChunkView<'a, T>, T);
+
+view! {
+    /// A view over a borrowed slice that allows unsynchronized access to chunks of its elements
+    /// through [`UnsafeChunkIndex`].
+    ///
+    /// This is the view returned by [`ParIndexView::as_par_chunk_index`] for slices and arrays,
+    /// so it can be named (*e.g.* in a struct field).
+    UnsafeChunkView(UnsafeCellChunkSlice<&'a mut UnsafeCell<[T]>>)
+}
+chunk_view_new!(UnsafeChunkView);
+forward_unsafe_chunk!(['a, T] UnsafeChunkView<'a, T>, T);
+
+view! {
+    /// A view over a borrowed slice that allows unsynchronized access to chunks of its elements
+    /// through [`UnsafeChunkIndex`], where chunks are indexed starting from the end of the slice.
+    ///
+    /// This is the view returned by [`ParIndexView::as_rchunk_index`] for slices and arrays,
+    /// so it can be named (*e.g.* in a struct field).
+    RevChunkView(RevChunkSlice<UnsafeCellChunkSlice<&'a mut UnsafeCell<[T]>>>)
+}
+forward_non_contiguous_unsafe_chunk!(['a, T] RevChunkView<'a, T>, T);
+
+impl<'a, T> RevChunkView<'a, T> {
+    /// Creates a view over `slice` with chunks of `chunk_size` elements, indexed starting
+    /// from the end of `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `slice` is not divisible by `chunk_size`.
+    #[inline]
+    pub fn new(slice: &'a mut [T], chunk_size: usize) -> Self {
+        assert_chunk_size(slice.len(), chunk_size);
+        Self::from_inner(RevChunkSlice::new(UnsafeCellChunkSlice::new_borrowed(
+            slice, chunk_size,
+        )))
+    }
+}
+
+owned_family! {
+    [T] <T> Box<[T]>, T, std::convert::identity, "a [`Box<[T]>`](`Box`)";
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// struct Buffer {
+    ///     collection: OwnedPointerParIndex<u32>,
+    /// }
+    ///
+    /// let buffer = Buffer {
+    ///     collection: OwnedPointerParIndex::new(vec![0; 4].into_boxed_slice()),
+    /// };
+    /// unsafe { *buffer.collection.get_mut_ptr(1) = 42 };
+    ///
+    /// let boxed: Box<[u32]> = buffer.collection.into();
+    /// assert_eq!(boxed.as_ref(), &[0, 42, 0, 0]);
+    /// ```
+    OwnedPointerParIndex,
+    OwnedNoRefParIndex,
+    /// The collection is [`Sync`] only if `T` is both [`Send`] and [`Sync`]: for example, a collection
+    /// of [`MutexGuard`](`std::sync::MutexGuard`)s (which are [`Sync`] but not [`Send`]) is not [`Sync`].
+    ///
+    /// ```compile_fail
+    /// # use par_slice::*;
+    /// # use std::sync::MutexGuard;
+    /// fn assert_sync<T: Sync>() {}
+    ///
+    /// assert_sync::<OwnedParIndex<MutexGuard<'static, u32>>>();
+    /// ```
+    OwnedParIndex,
+    OwnedPointerChunkParIndex,
+    OwnedNoRefChunkParIndex,
+    OwnedChunkParIndex,
+}
+
+owned_family! {
+    [T] <T> Vec<T>, T, Vec::into_boxed_slice, "a [`Vec<T>`](`Vec`)";
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// struct Buffer {
+    ///     collection: VecPointerParIndex<u32>,
+    /// }
+    ///
+    /// let buffer = Buffer {
+    ///     collection: VecPointerParIndex::new(vec![0; 4]),
+    /// };
+    /// unsafe { *buffer.collection.get_mut_ptr(1) = 42 };
+    ///
+    /// assert_eq!(Vec::from(buffer.collection), vec![0, 42, 0, 0]);
+    /// ```
+    VecPointerParIndex,
+    VecNoRefParIndex,
+    VecParIndex,
+    VecPointerChunkParIndex,
+    VecNoRefChunkParIndex,
+    VecChunkParIndex,
+}
+
+#[cfg(feature = "smallvec")]
+owned_family! {
+    [A: smallvec::Array] <A> smallvec::SmallVec<A>, A::Item, smallvec::SmallVec::into_boxed_slice,
+    "a [`SmallVec<A>`](`smallvec::SmallVec`)";
+    /// The elements are always moved to the heap for the parallel phase: converting back into
+    /// a [`SmallVec`](`smallvec::SmallVec`) moves them back to inline storage if they fit.
+    SmallVecPointerParIndex,
+    SmallVecNoRefParIndex,
+    SmallVecParIndex,
+    SmallVecPointerChunkParIndex,
+    SmallVecNoRefChunkParIndex,
+    SmallVecChunkParIndex,
+}
+
+owned_base! {
+    /// An owned collection whose first element has a stricter alignment than the one of `T`,
+    /// that allows unsynchronized access to its elements through [`UnsafeIndex`].
+    ///
+    /// This is the collection returned by the aligned constructors of [`ParSlice`]
+    /// (*e.g.* [`ParSlice::new_aligned`]), so it can be named (*e.g.* in a struct field).
+    /// Converting it into a [`Box<[T]>`](`Box`) moves its elements into a new allocation
+    /// with the default alignment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// struct Lanes {
+    ///     collection: AlignedParIndex<f32>,
+    /// }
+    ///
+    /// let lanes = Lanes {
+    ///     collection: ParSlice::new_aligned::<64, _>(8),
+    /// };
+    ///
+    /// assert_eq!(lanes.collection.base_align(), 64);
+    /// ```
+    [T] AlignedParIndex<T>(UnsafeCellSlice<AlignedBox<T>>) for Box<[T]>, T
+}
+forward_unsafe!([T] AlignedParIndex<T>, T);
+
+#[cfg(feature = "allocator_api")]
+owned_base! {
+    /// An owned collection allocated with the allocator `A`, that allows unsynchronized access
+    /// to its elements through [`UnsafeIndex`].
+    ///
+    /// This is the collection returned by the allocator-parameterized constructors of [`ParSlice`]
+    /// (*e.g.* [`ParSlice::new_in`]), so it can be named (*e.g.* in a struct field).
+    /// Converting it into a [`Vec<T, A>`](`Vec`) keeps its allocation.
+    [T, A: std::alloc::Allocator] AllocatorParIndex<T, A>(UnsafeCellSlice<AllocatorBox<T, A>>)
+        for Vec<T, A>, T
+}
+#[cfg(feature = "allocator_api")]
+forward_unsafe!([T, A: std::alloc::Allocator] AllocatorParIndex<T, A>, T);
@@ -0,0 +1,51 @@
+use crate::*;
+use std::{ops::Range, thread::scope};
+
+/// Calls `f` on the first and on the second half of the indices of `collection`,
+/// each in its own thread.
+///
+/// The first half is `0..mid` and the second one `mid..len`, where `mid = len / 2`.
+#[inline]
+pub(crate) fn par_update_halves<C: TrustedSizedCollection + Sync>(
+    collection: &C,
+    f: impl Fn(&C, Range<usize>) + Sync,
+) {
+    let len = collection.len();
+    let mid = len / 2;
+    scope(|s| {
+        let f = &f;
+        s.spawn(move || f(collection, 0..mid));
+        f(collection, mid..len);
+    });
+}
+
+/// Calls `f` on every index of `collection` using `num_threads` threads.
+///
+/// Indices are distributed round-robin: thread `tid` processes indices
+/// `tid, tid + num_threads, tid + 2 * num_threads, ...`.
+/// The last thread runs on the calling thread.
+///
+/// # Panics
+///
+/// Panics if `num_threads` is `0`.
+#[inline]
+pub(crate) fn scope_for_each<C: TrustedSizedCollection + Sync>(
+    collection: &C,
+    num_threads: usize,
+    f: impl Fn(usize, &C) + Sync,
+) {
+    assert!(num_threads != 0, "num_threads should be greater than 0");
+    let len = collection.len();
+    let f = &f;
+    let worker = move |tid: usize| {
+        for index in (tid..len).step_by(num_threads) {
+            f(index, collection);
+        }
+    };
+    scope(|s| {
+        for tid in 0..num_threads - 1 {
+            s.spawn(move || worker(tid));
+        }
+        worker(num_threads - 1);
+    });
+}
@@ -0,0 +1,176 @@
+use crate::*;
+use std::{fmt::Debug, marker::PhantomData};
+
+/// A view over the chunks with even (or odd) indices of a collection that allows
+/// unsynchronized access to them through references.
+///
+/// Chunk `k` of the view refers to chunk `2k` of the parent collection for the even view
+/// and to chunk `2k + 1` for the odd view.
+///
+/// The even and odd views of a collection never share a chunk, so each of them may be handed
+/// to a different thread (*e.g.* for red-black iterations, where even chunks are updated in a pass
+/// and odd chunks in the next one).
+///
+/// # Examples
+///
+/// ```
+/// # use par_slice::*;
+/// let collection = vec![0, 1, 2, 3, 4, 5].into_par_chunk_index(2);
+/// let (even, odd) = collection.split_parity();
+///
+/// assert_eq!(even.len(), 2);
+/// assert_eq!(unsafe { even.get(1) }, &[4, 5]);
+/// assert_eq!(unsafe { odd.get(0) }, &[2, 3]);
+/// ```
+pub struct ParityView<'a, T> {
+    parent: *const (),
+    get_mut_unchecked: unsafe fn(*const (), usize) -> *mut [T],
+    #[cfg(feature = "track-borrows")]
+    get: unsafe fn(*const (), usize) -> SharedBorrowGuard<'a, [T]>,
+    #[cfg(feature = "track-borrows")]
+    get_mut: unsafe fn(*const (), usize) -> BorrowGuard<'a, [T]>,
+    parity: usize,
+    len: usize,
+    chunk_size: usize,
+    _marker: PhantomData<&'a ()>,
+}
+
+// Safety: views are only created from shared references to `Sync` collections.
+unsafe impl<T> Send for ParityView<'_, T> {}
+unsafe impl<T> Sync for ParityView<'_, T> {}
+
+impl<T> Clone for ParityView<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ParityView<'_, T> {}
+
+impl<T> Debug for ParityView<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ParityView")
+            .field("parity", &self.parity)
+            .field("len", &self.len)
+            .field("chunk_size", &self.chunk_size)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, T> ParityView<'a, T> {
+    /// Creates the views over the even and odd chunks of `parent`.
+    #[inline]
+    pub(crate) fn split<C: UnsafeChunkIndex<T> + Sync>(parent: &'a C) -> (Self, Self) {
+        /// Calls `get_mut_unchecked` on a type-erased `C`.
+        unsafe fn erased_get_mut_unchecked<T, C: UnsafeChunkIndex<T>>(
+            parent: *const (),
+            index: usize,
+        ) -> *mut [T] {
+            unsafe {
+                // Safety: parent was created from a &C that outlives the view and
+                // the caller guarantees index is valid
+                (*(parent as *const C)).get_mut_unchecked(index)
+            }
+        }
+
+        /// Borrows a chunk of a type-erased `C` through `get`.
+        #[cfg(feature = "track-borrows")]
+        unsafe fn erased_get<'a, T, C: UnsafeChunkIndex<T> + 'a>(
+            parent: *const (),
+            index: usize,
+        ) -> SharedBorrowGuard<'a, [T]> {
+            // Safety: parent was created from a &'a C
+            let parent = unsafe { &*(parent as *const C) };
+            unsafe { parent.get(index) }
+        }
+
+        /// Mutably borrows a chunk of a type-erased `C` through `get_mut`.
+        #[cfg(feature = "track-borrows")]
+        unsafe fn erased_get_mut<'a, T, C: UnsafeChunkIndex<T> + 'a>(
+            parent: *const (),
+            index: usize,
+        ) -> BorrowGuard<'a, [T]> {
+            // Safety: parent was created from a &'a C
+            let parent = unsafe { &*(parent as *const C) };
+            unsafe { parent.get_mut(index) }
+        }
+
+        let view = |parity| Self {
+            parent: parent as *const C as *const (),
+            get_mut_unchecked: erased_get_mut_unchecked::<T, C>,
+            #[cfg(feature = "track-borrows")]
+            get: erased_get::<'a, T, C>,
+            #[cfg(feature = "track-borrows")]
+            get_mut: erased_get_mut::<'a, T, C>,
+            parity,
+            len: (parent.num_chunks() + 1 - parity) / 2,
+            chunk_size: parent.chunk_size(),
+            _marker: PhantomData,
+        };
+
+        (view(0), view(1))
+    }
+}
+
+unsafe impl<T> TrustedSizedCollection for ParityView<'_, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+unsafe impl<T> TrustedChunkSizedCollection for ParityView<'_, T> {
+    #[inline]
+    fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+}
+
+unsafe impl<T> UnsafeIndex<[T]> for ParityView<'_, T> {
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> &[T] {
+        unsafe {
+            // Safety: the caller guarantees Rust's aliasing rules are respected and that
+            // index is valid
+            &*self.get_mut_unchecked(index)
+        }
+    }
+
+    #[inline]
+    unsafe fn get_mut_unchecked(&self, index: usize) -> &mut [T] {
+        #[cfg(feature = "checked")]
+        assert_in_bounds(self.len(), index);
+        debug_assert!(index < self.len());
+
+        unsafe {
+            // Safety: the caller guarantees Rust's aliasing rules are respected and that
+            // index is valid, so the chunk with the same parity in the parent is valid as well
+            &mut *(self.get_mut_unchecked)(self.parent, 2 * index + self.parity)
+        }
+    }
+
+    #[cfg(feature = "track-borrows")]
+    #[inline]
+    unsafe fn get(&self, index: usize) -> SharedBorrowGuard<'_, [T]> {
+        assert_in_bounds(self.len(), index);
+        unsafe {
+            // Safety: we just checked that index is in bounds, so the chunk with the
+            // same parity in the parent is valid as well
+            (self.get)(self.parent, 2 * index + self.parity)
+        }
+    }
+
+    #[cfg(feature = "track-borrows")]
+    #[inline]
+    unsafe fn get_mut(&self, index: usize) -> BorrowGuard<'_, [T]> {
+        assert_in_bounds(self.len(), index);
+        unsafe {
+            // Safety: we just checked that index is in bounds, so the chunk with the
+            // same parity in the parent is valid as well
+            (self.get_mut)(self.parent, 2 * index + self.parity)
+        }
+    }
+}
+
+unsafe impl<T> UnsafeChunkIndex<T> for ParityView<'_, T> {}
@@ -0,0 +1,154 @@
+use crate::*;
+use std::marker::PhantomData;
+
+/// A wrapper on a collection that allows access to its elements through pointers
+/// as a row-major grid of `ncols` columns.
+///
+/// Cell `(row, col)` of the grid refers to index `row * ncols + col` of the wrapped collection.
+///
+/// # Examples
+///
+/// ```
+/// # use par_slice::*;
+/// let grid = PointerGrid::new(vec![0; 6].into_pointer_par_index(), 3);
+///
+/// assert_eq!(grid.nrows(), 2);
+/// unsafe {
+///     *grid.get_mut_ptr(1, 2) = 42;
+/// }
+///
+/// assert_eq!(grid.into_inner().into(), vec![0, 0, 0, 0, 0, 42]);
+/// ```
+#[derive(Debug)]
+pub struct PointerGrid<T, B> {
+    inner: B,
+    ncols: usize,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T, B: PointerIndex<T>> PointerGrid<T, B> {
+    /// Wraps the given collection into a `PointerGrid` with `ncols` columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ncols` is not a divisor of the length of the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let grid = PointerGrid::new(vec![0; 12].into_pointer_par_index(), 4);
+    /// assert_eq!(grid.nrows(), 3);
+    /// ```
+    #[inline]
+    pub fn new(collection: B, ncols: usize) -> Self {
+        assert!(
+            ncols != 0 && collection.len() % ncols == 0,
+            "ncols should be a divisor of len. Got {} columns for a collection of len {}",
+            ncols,
+            collection.len()
+        );
+        Self {
+            inner: collection,
+            ncols,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of rows of the grid.
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.inner.len() / self.ncols
+    }
+
+    /// Returns the number of columns of the grid.
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    /// Returns a mutable pointer to the element in cell `(row, col)` of the grid.
+    ///
+    /// This method performs bounds checking on `row` and `col` to ensure their validity.
+    /// If you can guarantee their validity, you may want to use the
+    /// [`get_mut_ptr_unchecked`](`Self::get_mut_ptr_unchecked`) method instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` or `col` are out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let grid = PointerGrid::new(vec![0, 1, 2, 3, 4, 5].into_pointer_par_index(), 2);
+    /// assert_eq!(unsafe { *grid.get_mut_ptr(2, 1) }, 5);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn get_mut_ptr(&self, row: usize, col: usize) -> *mut T {
+        assert_cell_in_bounds(self.nrows(), self.ncols, row, col);
+        unsafe {
+            // Safety: we just checked that the cell is in bounds
+            self.get_mut_ptr_unchecked(row, col)
+        }
+    }
+
+    /// Returns a mutable pointer to the element in cell `(row, col)` of the grid,
+    /// without performing bounds checking.
+    ///
+    /// For a safe alternative see [`get_mut_ptr`](`Self::get_mut_ptr`).
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with a cell out of bounds is [undefined behavior].
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let grid = PointerGrid::new(vec![0, 1, 2, 3, 4, 5].into_pointer_par_index(), 2);
+    /// assert_eq!(unsafe { *grid.get_mut_ptr_unchecked(1, 0) }, 2);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub unsafe fn get_mut_ptr_unchecked(&self, row: usize, col: usize) -> *mut T {
+        #[cfg(feature = "checked")]
+        assert_cell_in_bounds(self.nrows(), self.ncols, row, col);
+        debug_assert!(row < self.nrows() && col < self.ncols);
+
+        unsafe {
+            // Safety: the caller guarantees that the cell is in bounds, so its index is too
+            self.inner.get_mut_ptr_unchecked(row * self.ncols + col)
+        }
+    }
+}
+
+impl<T, B> PointerGrid<T, B> {
+    /// Consumes the `PointerGrid`, returning the wrapped collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let grid = PointerGrid::new(vec![0, 1, 2, 3].into_pointer_par_index(), 2);
+    ///
+    /// assert_eq!(grid.into_inner().into(), vec![0, 1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+/// Asserts that cell `(row, col)` is in bounds of a grid of `nrows` rows and `ncols` columns,
+/// panicking otherwise.
+#[inline]
+fn assert_cell_in_bounds(nrows: usize, ncols: usize, row: usize, col: usize) {
+    assert!(
+        row < nrows && col < ncols,
+        "Cell ({row}, {col}) invalid for grid of {nrows} rows and {ncols} columns"
+    )
+}
@@ -0,0 +1,70 @@
+use crate::*;
+use rayon::prelude::*;
+
+/// Sorts the elements inside each chunk of `collection`, processing the chunks in parallel
+/// using [`rayon`]'s global thread pool.
+#[inline]
+pub(crate) fn par_sort_chunks<T: Ord + Send, C: UnsafeChunkIndex<T> + Sync>(collection: &C) {
+    (0..collection.num_chunks())
+        .into_par_iter()
+        .for_each(|chunk| {
+            unsafe {
+                // Safety: each chunk is visited exactly once and chunks are disjoint,
+                // so no other reference to this chunk exists
+                collection.get_mut_unchecked(chunk)
+            }
+            .sort_unstable()
+        });
+}
+
+/// Returns the number of elements of `slice` that satisfy `pred`, evaluating it in parallel
+/// using [`rayon`]'s global thread pool.
+#[inline]
+pub(crate) fn par_count<T: Sync>(slice: &[T], pred: impl Fn(&T) -> bool + Sync) -> usize {
+    (0..slice.len())
+        .into_par_iter()
+        .filter(|&index| pred(&slice[index]))
+        .count()
+}
+
+/// Creates a new boxed slice of `len` elements, initializing the element with index `i` to
+/// `f(state, i)` in parallel using [`rayon`]'s global thread pool.
+///
+/// `state` is created with `make_state` once per [`rayon`] job, so each worker thread gets its own
+/// state that is reused across the disjoint indices it initializes.
+pub(crate) fn par_new_boxed_slice_with_state<T: Send, S>(
+    len: usize,
+    make_state: impl Fn() -> S + Sync,
+    f: impl Fn(&mut S, usize) -> T + Sync,
+) -> Box<[T]> {
+    let mut boxed = Box::new_uninit_slice(len);
+    boxed
+        .par_iter_mut()
+        .enumerate()
+        .for_each_init(&make_state, |state, (i, elem)| {
+            elem.write(f(state, i));
+        });
+    unsafe {
+        // Safety: all len elements were just initialized
+        boxed.assume_init()
+    }
+}
+
+/// Maps each chunk of `chunk_size` elements of `slice` to a partial result with `map` and
+/// combines the partial results with `reduce`, processing the chunks in parallel using
+/// [`rayon`]'s global thread pool.
+///
+/// `identity` is only combined once with the result, so it does not need to be [`Clone`].
+#[inline]
+pub(crate) fn par_map_reduce_chunks<T: Sync, A: Send>(
+    slice: &[T],
+    chunk_size: usize,
+    map: impl Fn(&[T]) -> A + Sync,
+    reduce: impl Fn(A, A) -> A + Sync,
+    identity: A,
+) -> A {
+    match slice.par_chunks(chunk_size).map(&map).reduce_with(&reduce) {
+        Some(result) => reduce(identity, result),
+        None => identity,
+    }
+}
@@ -0,0 +1,91 @@
+use crate::*;
+
+/// Wrapper around a chunk collection that reverses the order of its chunks,
+/// so that chunk `0` is the last chunk of the wrapped collection
+/// (like [`slice::rchunks`]).
+///
+/// Elements inside each chunk keep the order of the wrapped collection.
+#[derive(Debug)]
+pub(crate) struct RevChunkSlice<S>(S);
+
+impl<S: TrustedSizedCollection> RevChunkSlice<S> {
+    /// Wraps `inner`, reversing the order of its chunks.
+    pub(crate) fn new(inner: S) -> Self {
+        Self(inner)
+    }
+
+    /// Returns the index in the wrapped collection of the chunk identified by `index`.
+    #[inline]
+    fn rev(&self, index: usize) -> usize {
+        #[cfg(feature = "checked")]
+        assert_in_bounds(self.len(), index);
+        debug_assert!(index < self.len());
+
+        self.len() - 1 - index
+    }
+}
+
+unsafe impl<S: TrustedSizedCollection> TrustedSizedCollection for RevChunkSlice<S> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+unsafe impl<S: TrustedChunkSizedCollection> TrustedChunkSizedCollection for RevChunkSlice<S> {
+    #[inline]
+    fn chunk_size(&self) -> usize {
+        self.0.chunk_size()
+    }
+}
+
+unsafe impl<T, S: UnsafeChunkIndex<T>> UnsafeIndex<[T]> for RevChunkSlice<S> {
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> &[T] {
+        unsafe {
+            // Safety: the caller guarantees Rust's aliasing rules are respected and that
+            // index is valid, so the reversed index is valid too
+            self.0.get_unchecked(self.rev(index))
+        }
+    }
+
+    #[inline]
+    unsafe fn get_mut_unchecked(&self, index: usize) -> &mut [T] {
+        unsafe {
+            // Safety: the caller guarantees Rust's aliasing rules are respected and that
+            // index is valid, so the reversed index is valid too
+            self.0.get_mut_unchecked(self.rev(index))
+        }
+    }
+
+    #[cfg(feature = "track-borrows")]
+    #[inline]
+    unsafe fn get(&self, index: usize) -> SharedBorrowGuard<'_, [T]> {
+        assert_in_bounds(self.len(), index);
+        unsafe {
+            // Safety: we just checked that index is in bounds, so the reversed index is valid too
+            self.0.get(self.rev(index))
+        }
+    }
+
+    #[cfg(feature = "track-borrows")]
+    #[inline]
+    unsafe fn get_mut(&self, index: usize) -> BorrowGuard<'_, [T]> {
+        assert_in_bounds(self.len(), index);
+        unsafe {
+            // Safety: we just checked that index is in bounds, so the reversed index is valid too
+            self.0.get_mut(self.rev(index))
+        }
+    }
+}
+
+unsafe impl<T, S: UnsafeChunkIndex<T>> UnsafeChunkIndex<T> for RevChunkSlice<S> {
+    #[inline]
+    unsafe fn get_mut_at_unchecked(&self, chunk: usize, offset: usize) -> &mut T {
+        unsafe {
+            // Safety: the caller guarantees that chunk and offset are valid, so the
+            // reversed chunk is valid too
+            self.0.get_mut_at_unchecked(self.rev(chunk), offset)
+        }
+    }
+}
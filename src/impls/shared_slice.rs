@@ -0,0 +1,56 @@
+use crate::*;
+use std::{fmt::Debug, mem::size_of, sync::Arc};
+
+/// Wrapper around an immutable slice shared through an [`Arc`].
+pub(crate) struct SharedSlice<T>(Arc<[T]>);
+
+impl<T> Clone for SharedSlice<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<T> Debug for SharedSlice<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SharedSlice")
+            .field("len", &self.0.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> From<SharedSlice<T>> for Arc<[T]> {
+    #[inline]
+    fn from(value: SharedSlice<T>) -> Self {
+        value.0
+    }
+}
+
+impl<T> SharedSlice<T> {
+    /// Creates a new shared slice.
+    pub(crate) fn new(slice: Arc<[T]>) -> Self {
+        Self(slice)
+    }
+}
+
+unsafe impl<T> TrustedSizedCollection for SharedSlice<T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+unsafe impl<T> SharedPointerIndex<T> for SharedSlice<T> {
+    #[inline]
+    unsafe fn get_ptr_unchecked(&self, index: usize) -> *const T {
+        #[cfg(feature = "checked")]
+        assert_in_bounds(self.len(), index);
+        debug_assert!(index < self.len());
+        debug_assert!(index * size_of::<T>() < isize::MAX as usize);
+
+        unsafe {
+            // Safety: the caller guarantees index is valid
+            self.0.as_ptr().add(index)
+        }
+    }
+}
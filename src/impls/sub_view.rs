@@ -0,0 +1,152 @@
+use crate::*;
+use std::{fmt::Debug, marker::PhantomData};
+
+/// A view over a contiguous range of the indices of a collection that allows
+/// unsynchronized access to its elements through pointers.
+///
+/// Index `i` of the view refers to index `offset + i` of the parent collection, where
+/// `offset` is the first index of the range.
+///
+/// The view does not depend on the type of the parent collection, so splitting a view
+/// again returns a view of the same type (which makes it usable in recursive algorithms).
+///
+/// # Examples
+///
+/// ```
+/// # use par_slice::*;
+/// let collection = vec![1, 2, 3].into_pointer_par_index();
+/// let (head, tail) = collection.split_first().unwrap();
+///
+/// assert_eq!(tail.len(), 2);
+/// assert_eq!(tail.get_mut_ptr(0), collection.get_mut_ptr(1));
+/// ```
+pub struct SubView<'a, T: ?Sized> {
+    parent: *const (),
+    get_mut_ptr_unchecked: unsafe fn(*const (), usize) -> *mut T,
+    offset: usize,
+    len: usize,
+    _marker: PhantomData<&'a ()>,
+}
+
+// Safety: views are only created from shared references to `Sync` collections.
+unsafe impl<T: ?Sized> Send for SubView<'_, T> {}
+unsafe impl<T: ?Sized> Sync for SubView<'_, T> {}
+
+impl<T: ?Sized> Clone for SubView<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for SubView<'_, T> {}
+
+impl<T: ?Sized> Debug for SubView<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SubView")
+            .field("offset", &self.offset)
+            .field("len", &self.len)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, T: ?Sized> SubView<'a, T> {
+    /// Creates a view over the `len` indices of `parent` starting from `offset`.
+    ///
+    /// The caller must guarantee that `offset + len <= parent.len()`.
+    #[inline]
+    pub(crate) fn new<C: PointerIndex<T> + Sync>(parent: &'a C, offset: usize, len: usize) -> Self {
+        debug_assert!(offset <= parent.len() && len <= parent.len() - offset);
+
+        /// Calls `get_mut_ptr_unchecked` on a type-erased `C`.
+        unsafe fn erased_get_mut_ptr_unchecked<T: ?Sized, C: PointerIndex<T>>(
+            parent: *const (),
+            index: usize,
+        ) -> *mut T {
+            unsafe {
+                // Safety: parent was created from a &C that outlives the view and
+                // the caller guarantees index is valid
+                (*(parent as *const C)).get_mut_ptr_unchecked(index)
+            }
+        }
+
+        Self {
+            parent: parent as *const C as *const (),
+            get_mut_ptr_unchecked: erased_get_mut_ptr_unchecked::<T, C>,
+            offset,
+            len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a view over the `len` indices of this view starting from `offset`.
+    #[inline]
+    fn sub_view(&self, offset: usize, len: usize) -> Self {
+        debug_assert!(offset <= self.len && len <= self.len - offset);
+
+        Self {
+            offset: self.offset + offset,
+            len,
+            ..*self
+        }
+    }
+}
+
+unsafe impl<T: ?Sized> TrustedSizedCollection for SubView<'_, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+unsafe impl<T: ?Sized> PointerIndex<T> for SubView<'_, T> {
+    #[inline]
+    unsafe fn get_ptr_unchecked(&self, index: usize) -> *const T {
+        unsafe { self.get_mut_ptr_unchecked(index) as *const T }
+    }
+
+    #[inline]
+    unsafe fn get_mut_ptr_unchecked(&self, index: usize) -> *mut T {
+        #[cfg(feature = "checked")]
+        assert_in_bounds(self.len(), index);
+        debug_assert!(index < self.len());
+
+        unsafe {
+            // Safety: the caller guarantees index is valid, so it is valid
+            // for the parent as well once offset
+            (self.get_mut_ptr_unchecked)(self.parent, self.offset + index)
+        }
+    }
+
+    #[inline]
+    fn split_first(&self) -> Option<(*mut T, SubView<'_, T>)>
+    where
+        Self: Sized + Sync,
+    {
+        if self.is_empty() {
+            return None;
+        }
+
+        let head = unsafe {
+            // Safety: the view is not empty
+            self.get_mut_ptr_unchecked(0)
+        };
+        Some((head, self.sub_view(1, self.len - 1)))
+    }
+
+    #[inline]
+    fn split_last(&self) -> Option<(*mut T, SubView<'_, T>)>
+    where
+        Self: Sized + Sync,
+    {
+        if self.is_empty() {
+            return None;
+        }
+
+        let last = unsafe {
+            // Safety: the view is not empty
+            self.get_mut_ptr_unchecked(self.len - 1)
+        };
+        Some((last, self.sub_view(0, self.len - 1)))
+    }
+}
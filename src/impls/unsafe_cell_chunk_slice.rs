@@ -1,13 +1,17 @@
 use crate::*;
-use std::{cell::UnsafeCell, mem::size_of, ops::Deref};
+use std::{cell::UnsafeCell, cmp::Reverse, collections::BinaryHeap, mem::size_of, ops::Deref};
 
 /// Wrapper around an [`UnsafeCell`] (either mutable reference or owned)
 /// that divides the underlying slice in chunks.
+///
+/// With the `track-borrows` feature, it also keeps the borrow state of each of its chunks.
 #[derive(Debug)]
 pub(crate) struct UnsafeCellChunkSlice<B> {
     inner: B,
     len: usize,
     chunk_size: usize,
+    #[cfg(feature = "track-borrows")]
+    tracker: BorrowTracker,
 }
 
 // Safety: access paradigms shift responsability to the user to ensure
@@ -15,6 +19,14 @@ pub(crate) struct UnsafeCellChunkSlice<B> {
 unsafe impl<T: Send + Sync> Sync for UnsafeCellChunkSlice<&mut UnsafeCell<[T]>> {}
 unsafe impl<T: Send + Sync> Sync for UnsafeCellChunkSlice<Box<UnsafeCell<[T]>>> {}
 
+// Safety: both wrappers uniquely own (or uniquely borrow) their elements, so moving
+// them to another thread only moves the elements. This is already implied by
+// `UnsafeCell`, `&mut` and `Box` being `Send`, but it is stated explicitly so that
+// it can't be lost by accident (*e.g.* to move an owned collection between a
+// producer and a consumer thread).
+unsafe impl<T: Send> Send for UnsafeCellChunkSlice<&mut UnsafeCell<[T]>> {}
+unsafe impl<T: Send> Send for UnsafeCellChunkSlice<Box<UnsafeCell<[T]>>> {}
+
 impl<T> From<UnsafeCellChunkSlice<Box<UnsafeCell<[T]>>>> for Box<[T]> {
     #[inline]
     fn from(value: UnsafeCellChunkSlice<Box<UnsafeCell<[T]>>>) -> Self {
@@ -29,6 +41,31 @@ impl<T> From<UnsafeCellChunkSlice<Box<UnsafeCell<[T]>>>> for Vec<T> {
     }
 }
 
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> From<UnsafeCellChunkSlice<Box<UnsafeCell<[A::Item]>>>>
+    for smallvec::SmallVec<A>
+{
+    /// Converts the slice back into a [`SmallVec`](`smallvec::SmallVec`), moving its elements
+    /// back to inline storage if they fit.
+    #[inline]
+    fn from(value: UnsafeCellChunkSlice<Box<UnsafeCell<[A::Item]>>>) -> Self {
+        let mut small_vec = Self::from_vec(value.into_inner().into_vec());
+        small_vec.shrink_to_fit();
+        small_vec
+    }
+}
+
+impl<T: Clone> Clone for UnsafeCellChunkSlice<Box<UnsafeCell<[T]>>> {
+    /// Returns a copy of the slice with the same chunk size, cloning each of its elements.
+    ///
+    /// This reads the elements through a shared reference, so it must only be called
+    /// when no mutation of the slice is happening concurrently.
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new_owned(self.as_slice().into(), self.chunk_size)
+    }
+}
+
 impl<'a, T> UnsafeCellChunkSlice<&'a mut UnsafeCell<[T]>> {
     /// Creates a new borrowed slice with chunks of `chunk_size`.
     ///
@@ -43,6 +80,8 @@ impl<'a, T> UnsafeCellChunkSlice<&'a mut UnsafeCell<[T]>> {
             inner: UnsafeCell::from_mut(slice),
             len,
             chunk_size,
+            #[cfg(feature = "track-borrows")]
+            tracker: BorrowTracker::new(len),
         }
     }
 }
@@ -67,6 +106,8 @@ impl<T> UnsafeCellChunkSlice<Box<UnsafeCell<[T]>>> {
             inner: boxed,
             len,
             chunk_size,
+            #[cfg(feature = "track-borrows")]
+            tracker: BorrowTracker::new(len),
         }
     }
 
@@ -80,6 +121,72 @@ impl<T> UnsafeCellChunkSlice<Box<UnsafeCell<[T]>>> {
     }
 }
 
+impl<T> UnsafeCellChunkSlice<Box<UnsafeCell<[T]>>> {
+    /// Reverses the order of the chunks of the slice in place, keeping the order
+    /// of the elements inside each chunk.
+    pub(crate) fn reverse(&mut self) {
+        let chunk_size = self.chunk_size;
+        let elems = self.as_mut_slice();
+        elems.reverse();
+        for chunk in elems.chunks_exact_mut(chunk_size) {
+            chunk.reverse();
+        }
+    }
+
+    /// Consumes the slice, merging its chunks into a single sorted vector.
+    ///
+    /// Each chunk must already be sorted: the chunks are merged with a heap-based k-way merge.
+    /// Equal elements keep the order of their chunks.
+    pub(crate) fn into_sorted_vec(self) -> Vec<T>
+    where
+        T: Ord,
+    {
+        let chunk_size = self.chunk_size;
+        let mut elems = self.into_inner().into_vec();
+        let total = elems.len();
+
+        let mut chunks = Vec::with_capacity(total / chunk_size);
+        while !elems.is_empty() {
+            chunks.push(elems.split_off(elems.len() - chunk_size).into_iter());
+        }
+        chunks.reverse();
+
+        let mut heap: BinaryHeap<_> = chunks
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(chunk, elems)| elems.next().map(|elem| Reverse((elem, chunk))))
+            .collect();
+        let mut sorted = Vec::with_capacity(total);
+        while let Some(Reverse((elem, chunk))) = heap.pop() {
+            sorted.push(elem);
+            if let Some(next) = chunks[chunk].next() {
+                heap.push(Reverse((next, chunk)));
+            }
+        }
+        sorted
+    }
+}
+
+impl<T> SerialAccess<T> for UnsafeCellChunkSlice<Box<UnsafeCell<[T]>>> {
+    #[inline]
+    fn as_slice(&self) -> &[T] {
+        unsafe {
+            // Safety: the caller guarantees that no mutation is happening concurrently
+            &*self.inner.get()
+        }
+    }
+
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self.inner.get_mut()
+    }
+
+    #[inline]
+    fn into_boxed_slice(self) -> Box<[T]> {
+        self.into_inner()
+    }
+}
+
 unsafe impl<T, B: Deref<Target = UnsafeCell<[T]>>> TrustedSizedCollection
     for UnsafeCellChunkSlice<B>
 {
@@ -116,6 +223,8 @@ unsafe impl<T, B: Deref<Target = UnsafeCell<[T]>>> PointerIndex<[T]> for UnsafeC
 
     #[inline]
     unsafe fn get_mut_ptr_unchecked(&self, index: usize) -> *mut [T] {
+        #[cfg(feature = "checked")]
+        assert_in_bounds(self.len(), index);
         debug_assert!(index < self.len());
 
         let offset = index * self.chunk_size;
@@ -136,6 +245,15 @@ unsafe impl<T, B: Deref<Target = UnsafeCell<[T]>>> PointerChunkIndex<T>
 {
 }
 
+unsafe impl<T, B: Deref<Target = UnsafeCell<[T]>>> ContiguousPointerChunkIndex<T>
+    for UnsafeCellChunkSlice<B>
+{
+    #[inline]
+    fn full_mut_ptr(&self) -> *mut T {
+        self.inner.get() as *mut T
+    }
+}
+
 unsafe impl<T, B: Deref<Target = UnsafeCell<[T]>>> UnsafeNoRefChunkIndex<T>
     for UnsafeCellChunkSlice<B>
 {
@@ -145,6 +263,11 @@ unsafe impl<T, B: Deref<Target = UnsafeCell<[T]>>> UnsafeNoRefChunkIndex<T>
         T: Copy,
     {
         let slice = out.as_mut();
+        #[cfg(feature = "checked")]
+        {
+            assert_in_bounds(self.len, index);
+            assert_chunk_compatible(self.chunk_size, slice);
+        }
         debug_assert!(index < self.len);
         debug_assert_eq!(slice.len(), self.chunk_size);
 
@@ -171,6 +294,11 @@ unsafe impl<T, B: Deref<Target = UnsafeCell<[T]>>> UnsafeNoRefChunkIndex<T>
     where
         T: Clone,
     {
+        #[cfg(feature = "checked")]
+        {
+            assert_in_bounds(self.len, index);
+            assert_chunk_compatible(self.chunk_size, value);
+        }
         debug_assert!(index < self.len);
         debug_assert_eq!(value.len(), self.chunk_size);
 
@@ -207,6 +335,93 @@ unsafe impl<T, B: Deref<Target = UnsafeCell<[T]>>> UnsafeIndex<[T]> for UnsafeCe
             &mut *self.get_mut_ptr_unchecked(index)
         }
     }
+
+    #[cfg(feature = "track-borrows")]
+    #[inline]
+    unsafe fn get(&self, index: usize) -> SharedBorrowGuard<'_, [T]> {
+        assert_in_bounds(self.len(), index);
+        let release = self.tracker.borrow(index);
+        SharedBorrowGuard::new(
+            unsafe {
+                // Safety: we just checked that index is in bounds and the tracker
+                // guarantees that no mutable reference to the chunk exists
+                self.get_unchecked(index)
+            },
+            release,
+        )
+    }
+
+    #[cfg(feature = "track-borrows")]
+    #[inline]
+    unsafe fn get_mut(&self, index: usize) -> BorrowGuard<'_, [T]> {
+        assert_in_bounds(self.len(), index);
+        let release = self.tracker.borrow_mut(index);
+        BorrowGuard::new(
+            unsafe {
+                // Safety: we just checked that index is in bounds and the tracker
+                // guarantees that no other reference to the chunk exists
+                self.get_mut_unchecked(index)
+            },
+            release,
+        )
+    }
+}
+
+unsafe impl<T, B: Deref<Target = UnsafeCell<[T]>>> UnsafeChunkIndex<T> for UnsafeCellChunkSlice<B> {
+    #[inline]
+    unsafe fn get_mut_at_unchecked(&self, chunk: usize, offset: usize) -> &mut T {
+        #[cfg(feature = "checked")]
+        assert_in_bounds(self.chunk_size, offset);
+        debug_assert!(offset < self.chunk_size);
+
+        unsafe {
+            // Safety: the caller guarantees that chunk and offset are in bounds and
+            // that Rust's aliasing rules are respected
+            &mut *(self.get_mut_ptr_unchecked(chunk) as *mut T).add(offset)
+        }
+    }
 }
 
-unsafe impl<T, B: Deref<Target = UnsafeCell<[T]>>> UnsafeChunkIndex<T> for UnsafeCellChunkSlice<B> {}
+unsafe impl<T, B: Deref<Target = UnsafeCell<[T]>>> ContiguousUnsafeChunkIndex<T>
+    for UnsafeCellChunkSlice<B>
+{
+    #[inline]
+    unsafe fn get_mut_chunks_unchecked(&self, start: usize, count: usize) -> &mut [T] {
+        #[cfg(feature = "checked")]
+        assert_chunks_in_bounds(self.num_chunks(), start, count);
+        debug_assert!(start <= self.num_chunks() && count <= self.num_chunks() - start);
+
+        let offset = start * self.chunk_size;
+        debug_assert!(offset * size_of::<T>() < isize::MAX as usize);
+
+        let mut ptr = self.inner.get() as *mut T;
+        unsafe {
+            // Safety: caller is responsible for guaranteeing that
+            // offset stays in bounds of allocated object
+            ptr = ptr.add(offset);
+        }
+        let chunks = std::ptr::slice_from_raw_parts_mut(ptr, count * self.chunk_size);
+        unsafe {
+            // Safety: consecutive chunks are contiguous and the caller guarantees
+            // Rust's aliasing rules are respected
+            &mut *chunks
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clone_owned() {
+        let slice = UnsafeCellChunkSlice::new_owned(vec![1, 2, 3, 4].into_boxed_slice(), 2);
+        let cloned = slice.clone();
+
+        unsafe { cloned.get_mut(1)[0] = 42 };
+
+        assert_eq!(cloned.chunk_size(), 2);
+        assert_eq!(Vec::from(slice), vec![1, 2, 3, 4]);
+        assert_eq!(Vec::from(cloned), vec![1, 2, 42, 4]);
+    }
+}
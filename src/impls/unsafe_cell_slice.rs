@@ -1,14 +1,39 @@
 use crate::*;
-use std::{cell::UnsafeCell, mem::size_of, ops::Deref};
+use std::{
+    cell::UnsafeCell,
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+    mem::size_of,
+    ops::{Deref, Index, IndexMut},
+};
 
 /// Wrapper around an [`UnsafeCell`] (either mutable reference or owned).
+///
+/// With the `track-borrows` feature, it also keeps the borrow state of each of its elements.
 #[derive(Debug)]
-pub(crate) struct UnsafeCellSlice<B>(B);
+pub(crate) struct UnsafeCellSlice<B>(B, #[cfg(feature = "track-borrows")] BorrowTracker);
 
 // Safety: access paradigms shift responsability to the user to ensure
 // no data races happen.
 unsafe impl<T: Send + Sync> Sync for UnsafeCellSlice<&mut UnsafeCell<[T]>> {}
 unsafe impl<T: Send + Sync> Sync for UnsafeCellSlice<Box<UnsafeCell<[T]>>> {}
+unsafe impl<T: Send + Sync> Sync for UnsafeCellSlice<AlignedBox<T>> {}
+#[cfg(feature = "allocator_api")]
+unsafe impl<T: Send + Sync, A: std::alloc::Allocator + Sync> Sync
+    for UnsafeCellSlice<AllocatorBox<T, A>>
+{
+}
+
+// Safety: both wrappers uniquely own (or uniquely borrow) their elements, so moving
+// them to another thread only moves the elements. This is already implied by
+// `UnsafeCell`, `&mut` and `Box` being `Send`, but it is stated explicitly so that
+// it can't be lost by accident (*e.g.* to move an owned collection between a
+// producer and a consumer thread).
+unsafe impl<T: Send> Send for UnsafeCellSlice<&mut UnsafeCell<[T]>> {}
+unsafe impl<T: Send> Send for UnsafeCellSlice<Box<UnsafeCell<[T]>>> {}
+unsafe impl<T: Send> Send for UnsafeCellSlice<AlignedBox<T>> {}
+#[cfg(feature = "allocator_api")]
+unsafe impl<T: Send, A: std::alloc::Allocator + Send> Send for UnsafeCellSlice<AllocatorBox<T, A>> {}
 
 impl<T> From<UnsafeCellSlice<Box<UnsafeCell<[T]>>>> for Box<[T]> {
     #[inline]
@@ -24,10 +49,189 @@ impl<T> From<UnsafeCellSlice<Box<UnsafeCell<[T]>>>> for Vec<T> {
     }
 }
 
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> From<UnsafeCellSlice<Box<UnsafeCell<[A::Item]>>>>
+    for smallvec::SmallVec<A>
+{
+    /// Converts the slice back into a [`SmallVec`](`smallvec::SmallVec`), moving its elements
+    /// back to inline storage if they fit.
+    #[inline]
+    fn from(value: UnsafeCellSlice<Box<UnsafeCell<[A::Item]>>>) -> Self {
+        let mut small_vec = Self::from_vec(value.into_inner().into_vec());
+        small_vec.shrink_to_fit();
+        small_vec
+    }
+}
+
+impl<T> From<UnsafeCellSlice<AlignedBox<T>>> for Box<[T]> {
+    #[inline]
+    fn from(value: UnsafeCellSlice<AlignedBox<T>>) -> Self {
+        value.0.into_boxed_slice()
+    }
+}
+
+impl<T> From<UnsafeCellSlice<AlignedBox<T>>> for Vec<T> {
+    #[inline]
+    fn from(value: UnsafeCellSlice<AlignedBox<T>>) -> Self {
+        value.0.into_boxed_slice().into_vec()
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+impl<T, A: std::alloc::Allocator> From<UnsafeCellSlice<AllocatorBox<T, A>>> for Vec<T, A> {
+    /// Converts the slice back into a vector, keeping its allocation and allocator.
+    #[inline]
+    fn from(value: UnsafeCellSlice<AllocatorBox<T, A>>) -> Self {
+        value.0.into_boxed_slice_in().into_vec()
+    }
+}
+
+impl<T: Clone> Clone for UnsafeCellSlice<Box<UnsafeCell<[T]>>> {
+    /// Returns a copy of the slice, cloning each of its elements.
+    ///
+    /// This reads the elements through a shared reference, so it must only be called
+    /// when no mutation of the slice is happening concurrently.
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new_owned(self.as_slice().into())
+    }
+}
+
+impl<T> Default for UnsafeCellSlice<Box<UnsafeCell<[T]>>> {
+    /// Returns an empty slice.
+    #[inline]
+    fn default() -> Self {
+        Self::new_owned(Box::new([]) as Box<[T]>)
+    }
+}
+
+impl<T: PartialEq> PartialEq for UnsafeCellSlice<Box<UnsafeCell<[T]>>> {
+    /// Returns `true` if the two slices have the same elements in the same order.
+    ///
+    /// This reads the elements through shared references, so it must only be called
+    /// when no mutation of either slice is happening concurrently.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq> Eq for UnsafeCellSlice<Box<UnsafeCell<[T]>>> {}
+
+impl<T: PartialEq> PartialEq<Vec<T>> for UnsafeCellSlice<Box<UnsafeCell<[T]>>> {
+    /// Returns `true` if the slice and the vector have the same elements in the same order.
+    ///
+    /// This reads the elements through a shared reference, so it must only be called
+    /// when no mutation of the slice is happening concurrently.
+    #[inline]
+    fn eq(&self, other: &Vec<T>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq> PartialEq<&[T]> for UnsafeCellSlice<Box<UnsafeCell<[T]>>> {
+    /// Returns `true` if the two slices have the same elements in the same order.
+    ///
+    /// This reads the elements through a shared reference, so it must only be called
+    /// when no mutation of the slice is happening concurrently.
+    #[inline]
+    fn eq(&self, other: &&[T]) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+impl<T: PartialEq> PartialEq<UnsafeCellSlice<Box<UnsafeCell<[T]>>>> for Vec<T> {
+    /// Returns `true` if the vector and the slice have the same elements in the same order.
+    ///
+    /// This reads the elements through a shared reference, so it must only be called
+    /// when no mutation of the slice is happening concurrently.
+    #[inline]
+    fn eq(&self, other: &UnsafeCellSlice<Box<UnsafeCell<[T]>>>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for UnsafeCellSlice<Box<UnsafeCell<[T]>>> {
+    /// Compares the elements of the two slices lexicographically.
+    ///
+    /// This reads the elements through shared references, so it must only be called
+    /// when no mutation of either slice is happening concurrently.
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Ord> Ord for UnsafeCellSlice<Box<UnsafeCell<[T]>>> {
+    /// Compares the elements of the two slices lexicographically.
+    ///
+    /// This reads the elements through shared references, so it must only be called
+    /// when no mutation of either slice is happening concurrently.
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<T: Hash> Hash for UnsafeCellSlice<Box<UnsafeCell<[T]>>> {
+    /// Hashes the elements of the slice, consistently with the hash of the equivalent `[T]`.
+    ///
+    /// This reads the elements through a shared reference, so it must only be called
+    /// when no mutation of the slice is happening concurrently.
+    /// As for any key with interior mutability, the elements of a slice must not be mutated
+    /// while it is stored in a hashed collection.
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<T> IntoIterator for UnsafeCellSlice<Box<UnsafeCell<[T]>>> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    /// Consumes the slice, returning an iterator over its elements by value.
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_inner().into_vec().into_iter()
+    }
+}
+
+impl<T> Index<usize> for UnsafeCellSlice<Box<UnsafeCell<[T]>>> {
+    type Output = T;
+
+    /// Returns a shared reference to the element identified by `index`.
+    ///
+    /// This reads the element through a shared reference, so it must only be called
+    /// when no mutation of the slice is happening concurrently (*i.e.* outside of parallel sections).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of the slice.
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        &self.as_slice()[index]
+    }
+}
+
+impl<T> IndexMut<usize> for UnsafeCellSlice<Box<UnsafeCell<[T]>>> {
+    /// Returns a mutable reference to the element identified by `index`.
+    ///
+    /// This is safe as `&mut self` guarantees that no other access to the slice exists.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of the slice.
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.as_mut_slice()[index]
+    }
+}
+
 impl<'a, T> UnsafeCellSlice<&'a mut UnsafeCell<[T]>> {
     /// Creates a new borrowed slice.
     pub(crate) fn new_borrowed(slice: &'a mut [T]) -> Self {
-        Self(UnsafeCell::from_mut(slice))
+        Self::wrap(UnsafeCell::from_mut(slice))
     }
 }
 
@@ -39,7 +243,7 @@ impl<T> UnsafeCellSlice<Box<UnsafeCell<[T]>>> {
             // Safety: UnsafeCell is repr(transparent)
             Box::from_raw(ptr)
         };
-        Self(boxed)
+        Self::wrap(boxed)
     }
 
     /// Extracts the inner boxed slice from the wrapper.
@@ -52,6 +256,172 @@ impl<T> UnsafeCellSlice<Box<UnsafeCell<[T]>>> {
     }
 }
 
+impl<T> UnsafeCellSlice<Box<UnsafeCell<[T]>>> {
+    /// Shortens the slice, keeping the first `new_len` elements and dropping the rest.
+    ///
+    /// This has no effect if `new_len` is greater than or equal to the length of the slice.
+    /// The elements are moved to a new allocation of `new_len` elements.
+    pub(crate) fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len() {
+            let mut vec = Vec::from(std::mem::take(self));
+            vec.truncate(new_len);
+            *self = Self::new_owned(vec.into_boxed_slice());
+        }
+    }
+
+    /// Resizes the slice so that its length is `new_len`, either dropping the elements
+    /// past `new_len` or filling the new slots with clones of `value`.
+    pub(crate) fn resize(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        if new_len != self.len() {
+            let mut vec = Vec::from(std::mem::take(self));
+            vec.resize(new_len, value);
+            *self = Self::new_owned(vec.into_boxed_slice());
+        }
+    }
+
+    /// Reverses the order of the elements of the slice in place.
+    pub(crate) fn reverse(&mut self) {
+        self.as_mut_slice().reverse();
+    }
+
+    /// Replaces the backing allocation of the slice with `new`, returning the previous one
+    /// (*e.g.* to swap the buffers of a double-buffering scheme between passes).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new` does not have the same length as the slice.
+    pub(crate) fn replace_inner(&mut self, new: Box<[T]>) -> Box<[T]> {
+        assert!(
+            new.len() == self.len(),
+            "new should have the same length as the slice. Got new of length {} for a slice of length {}",
+            new.len(),
+            self.len()
+        );
+        std::mem::replace(self, Self::new_owned(new)).into_inner()
+    }
+
+    /// Consumes both slices, returning a slice with the elements of `self` followed by
+    /// the elements of `other`.
+    pub(crate) fn append(self, other: Self) -> Self {
+        let mut vec = Vec::from(self);
+        vec.extend(Vec::from(other));
+        Self::new_owned(vec.into_boxed_slice())
+    }
+}
+
+impl<T> SerialAccess<T> for UnsafeCellSlice<Box<UnsafeCell<[T]>>> {
+    #[inline]
+    fn as_slice(&self) -> &[T] {
+        unsafe {
+            // Safety: the caller guarantees that no mutation is happening concurrently
+            &*self.0.get()
+        }
+    }
+
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe {
+            // Safety: &mut self guarantees that no other access exists
+            &mut *self.0.get()
+        }
+    }
+
+    #[inline]
+    fn into_boxed_slice(self) -> Box<[T]> {
+        self.into_inner()
+    }
+}
+
+impl<T> UnsafeCellSlice<AlignedBox<T>> {
+    /// Creates a new owned slice with an aligned allocation.
+    pub(crate) fn new_aligned(slice: AlignedBox<T>) -> Self {
+        Self::wrap(slice)
+    }
+}
+
+impl<T> SerialAccess<T> for UnsafeCellSlice<AlignedBox<T>> {
+    #[inline]
+    fn as_slice(&self) -> &[T] {
+        unsafe {
+            // Safety: the caller guarantees that no mutation is happening concurrently
+            &*self.0.get()
+        }
+    }
+
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe {
+            // Safety: &mut self guarantees that no other access exists
+            &mut *self.0.get()
+        }
+    }
+
+    #[inline]
+    fn into_boxed_slice(self) -> Box<[T]> {
+        self.0.into_boxed_slice()
+    }
+
+    #[inline]
+    fn base_align(&self) -> usize {
+        self.0.align()
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+impl<T, A: std::alloc::Allocator> UnsafeCellSlice<AllocatorBox<T, A>> {
+    /// Creates a new owned slice with an allocation managed by a custom allocator.
+    pub(crate) fn new_allocator(slice: AllocatorBox<T, A>) -> Self {
+        Self::wrap(slice)
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+impl<T, A: std::alloc::Allocator> SerialAccess<T> for UnsafeCellSlice<AllocatorBox<T, A>> {
+    #[inline]
+    fn as_slice(&self) -> &[T] {
+        unsafe {
+            // Safety: the caller guarantees that no mutation is happening concurrently
+            &*self.0.get()
+        }
+    }
+
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe {
+            // Safety: &mut self guarantees that no other access exists
+            &mut *self.0.get()
+        }
+    }
+
+    /// Consumes the slice, moving its elements into a [`Box<[T]>`](`Box`) allocated with
+    /// the global allocator.
+    #[inline]
+    fn into_boxed_slice(self) -> Box<[T]> {
+        self.0
+            .into_boxed_slice_in()
+            .into_vec()
+            .into_iter()
+            .collect()
+    }
+}
+
+impl<T, B: Deref<Target = UnsafeCell<[T]>>> UnsafeCellSlice<B> {
+    /// Wraps `inner`, with none of its elements borrowed.
+    #[inline]
+    fn wrap(inner: B) -> Self {
+        #[cfg(feature = "track-borrows")]
+        {
+            let tracker = BorrowTracker::new(inner.get().len());
+            Self(inner, tracker)
+        }
+        #[cfg(not(feature = "track-borrows"))]
+        Self(inner)
+    }
+}
+
 unsafe impl<T, B: Deref<Target = UnsafeCell<[T]>>> TrustedSizedCollection for UnsafeCellSlice<B> {
     #[inline]
     fn len(&self) -> usize {
@@ -67,6 +437,8 @@ unsafe impl<T, B: Deref<Target = UnsafeCell<[T]>>> PointerIndex<T> for UnsafeCel
 
     #[inline]
     unsafe fn get_mut_ptr_unchecked(&self, index: usize) -> *mut T {
+        #[cfg(feature = "checked")]
+        assert_in_bounds(self.len(), index);
         debug_assert!(index < self.len());
         debug_assert!(index * size_of::<T>() < isize::MAX as usize);
 
@@ -96,6 +468,9 @@ unsafe impl<T, B: Deref<Target = UnsafeCell<[T]>>> UnsafeNoRefIndex<T> for Unsaf
     where
         T: Sized,
     {
+        #[cfg(feature = "tracing")]
+        tracing::trace!(index, "set_value");
+
         unsafe {
             // Safety: the caller guarantees that there are no data races and that
             // index is valid
@@ -116,10 +491,68 @@ unsafe impl<T, B: Deref<Target = UnsafeCell<[T]>>> UnsafeIndex<T> for UnsafeCell
 
     #[inline]
     unsafe fn get_mut_unchecked(&self, index: usize) -> &mut T {
+        #[cfg(feature = "tracing")]
+        tracing::trace!(index, "get_mut");
+
         unsafe {
             // Safety: the caller guarantees Rust's aliasing rules are respected and that
             // index is valid
             &mut *self.get_mut_ptr_unchecked(index)
         }
     }
+
+    #[cfg(feature = "track-borrows")]
+    #[inline]
+    unsafe fn get(&self, index: usize) -> SharedBorrowGuard<'_, T> {
+        assert_in_bounds(self.len(), index);
+        let release = self.1.borrow(index);
+        SharedBorrowGuard::new(
+            unsafe {
+                // Safety: we just checked that index is in bounds and the tracker
+                // guarantees that no mutable reference to the element exists
+                self.get_unchecked(index)
+            },
+            release,
+        )
+    }
+
+    #[cfg(feature = "track-borrows")]
+    #[inline]
+    unsafe fn get_mut(&self, index: usize) -> BorrowGuard<'_, T> {
+        assert_in_bounds(self.len(), index);
+        let release = self.1.borrow_mut(index);
+        BorrowGuard::new(
+            unsafe {
+                // Safety: we just checked that index is in bounds and the tracker
+                // guarantees that no other reference to the element exists
+                self.get_mut_unchecked(index)
+            },
+            release,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clone_owned() {
+        let slice = UnsafeCellSlice::new_owned(vec![1, 2, 3].into_boxed_slice());
+        let cloned = slice.clone();
+
+        unsafe { *cloned.get_mut(1) = 42 };
+
+        assert_eq!(Vec::from(slice), vec![1, 2, 3]);
+        assert_eq!(Vec::from(cloned), vec![1, 42, 3]);
+    }
+
+    #[test]
+    fn default_owned() {
+        let slice = UnsafeCellSlice::<Box<UnsafeCell<[u32]>>>::default();
+
+        assert_eq!(slice.len(), 0);
+        assert!(slice.is_empty());
+        assert_eq!(Vec::from(slice), Vec::<u32>::new());
+    }
 }
@@ -0,0 +1,206 @@
+use crate::*;
+
+/// A wrapper on two collections of the same length that allows access to
+/// the elements with the same index in both collections at once.
+///
+/// This is useful when two collections are always updated together (*e.g.* distances
+/// and parents in a breadth-first visit), as it avoids keeping their accesses in sync by hand.
+pub struct ZipParIndex<T, U, A, B> {
+    first: A,
+    second: B,
+    _marker: std::marker::PhantomData<fn() -> (T, U)>,
+}
+
+impl<T, U, A: UnsafeIndex<T> + ParView<T>, B: UnsafeIndex<U> + ParView<U>> ZipParIndex<T, U, A, B> {
+    /// Wraps the given collections into a `ZipParIndex`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `first` and `second` do not have the same length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let zipped = ZipParIndex::new(vec![0; 3].into_par_index(), vec![1; 3].into_par_index());
+    /// ```
+    #[inline]
+    pub fn new(first: A, second: B) -> Self {
+        assert!(
+            first.len() == second.len(),
+            "collections should have the same length. Got collections of length {} and {}",
+            first.len(),
+            second.len()
+        );
+
+        Self {
+            first,
+            second,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T, U, A, B> ZipParIndex<T, U, A, B> {
+    /// Consumes the `ZipParIndex`, returning the wrapped collections.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let zipped = ZipParIndex::new(vec![0; 3].into_par_index(), vec![1; 3].into_par_index());
+    /// let (first, second) = zipped.into_inner();
+    ///
+    /// assert_eq!(first.into(), vec![0, 0, 0]);
+    /// assert_eq!(second.into(), vec![1, 1, 1]);
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> (A, B) {
+        (self.first, self.second)
+    }
+}
+
+impl<T, U, A: UnsafeIndex<T>, B: UnsafeIndex<U>> ZipParIndex<T, U, A, B> {
+    /// Returns shared references to the elements identified by `index` in both collections.
+    ///
+    /// This method performs bounds checking on `index` to ensure its validity.
+    /// If you can guarantee its validity, you may want to use the [`get_unchecked`](`Self::get_unchecked`)
+    /// method instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of the collections.
+    ///
+    /// # Safety
+    ///
+    /// See [`UnsafeIndex::get`]'s safety section.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let zipped = ZipParIndex::new(vec![0; 3].into_par_index(), vec![1; 3].into_par_index());
+    /// assert_eq!(unsafe { zipped.get(0) }, (&0, &1));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub unsafe fn get(&self, index: usize) -> (&T, &U) {
+        assert_in_bounds(self.len(), index);
+        unsafe {
+            // Safety: we just checked that index is in bounds
+            self.get_unchecked(index)
+        }
+    }
+
+    /// Returns shared references to the elements identified by `index` in both collections,
+    /// without performing bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// See [`UnsafeIndex::get_unchecked`]'s safety section.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let zipped = ZipParIndex::new(vec![0; 3].into_par_index(), vec![1; 3].into_par_index());
+    /// // We know 0 is a valid index for collections of length 3
+    /// assert_eq!(unsafe { zipped.get_unchecked(0) }, (&0, &1));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub unsafe fn get_unchecked(&self, index: usize) -> (&T, &U) {
+        unsafe {
+            // Safety: both collections have the same length and the caller
+            // guarantees Rust's aliasing rules are respected
+            (
+                self.first.get_unchecked(index),
+                self.second.get_unchecked(index),
+            )
+        }
+    }
+
+    /// Returns mutable references to the elements identified by `index` in both collections.
+    ///
+    /// This method performs bounds checking on `index` to ensure its validity.
+    /// If you can guarantee its validity, you may want to use the [`get_mut_unchecked`](`Self::get_mut_unchecked`)
+    /// method instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of the collections.
+    ///
+    /// # Safety
+    ///
+    /// See [`UnsafeIndex::get_mut`]'s safety section.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let zipped = ZipParIndex::new(vec![0; 3].into_par_index(), vec![1; 3].into_par_index());
+    /// {
+    ///     let (first, second) = unsafe { zipped.get_mut(0) };
+    ///     *first = 42;
+    ///     *second = 69;
+    /// }
+    /// assert_eq!(unsafe { zipped.get(0) }, (&42, &69));
+    /// ```
+    #[must_use]
+    #[allow(clippy::mut_from_ref)]
+    #[inline]
+    pub unsafe fn get_mut(&self, index: usize) -> (&mut T, &mut U) {
+        assert_in_bounds(self.len(), index);
+        unsafe {
+            // Safety: we just checked that index is in bounds
+            self.get_mut_unchecked(index)
+        }
+    }
+
+    /// Returns mutable references to the elements identified by `index` in both collections,
+    /// without performing bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// See [`UnsafeIndex::get_mut_unchecked`]'s safety section.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let zipped = ZipParIndex::new(vec![0; 3].into_par_index(), vec![1; 3].into_par_index());
+    /// {
+    ///     // We know 0 is a valid index for collections of length 3
+    ///     let (first, second) = unsafe { zipped.get_mut_unchecked(0) };
+    ///     *first = 42;
+    ///     *second = 69;
+    /// }
+    /// assert_eq!(unsafe { zipped.get(0) }, (&42, &69));
+    /// ```
+    #[must_use]
+    #[allow(clippy::mut_from_ref)]
+    #[inline]
+    pub unsafe fn get_mut_unchecked(&self, index: usize) -> (&mut T, &mut U) {
+        unsafe {
+            // Safety: both collections have the same length and the caller
+            // guarantees Rust's aliasing rules are respected
+            (
+                self.first.get_mut_unchecked(index),
+                self.second.get_mut_unchecked(index),
+            )
+        }
+    }
+}
+
+// Safety: both collections have the same length by construction.
+unsafe impl<T, U, A: TrustedSizedCollection, B> TrustedSizedCollection for ZipParIndex<T, U, A, B> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.first.len()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.first.is_empty()
+    }
+}
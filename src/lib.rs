@@ -19,16 +19,14 @@
 //!     s.spawn(|| {
 //!         for i in 0..6 {
 //!             if i % 2 == 0 {
-//!                 let mut_ref = unsafe { slice.get_mut(i) };
-//!                 *mut_ref = 42;
+//!                 unsafe { *slice.get_mut(i) = 42 };
 //!             }
 //!         }
 //!     });
 //!     s.spawn(|| {
 //!         for i in 0..6 {
 //!             if i % 2 != 0 {
-//!                 let mut_ref = unsafe { slice.get_mut(i) };
-//!                 *mut_ref = 69;
+//!                 unsafe { *slice.get_mut(i) = 69 };
 //!             }
 //!         }
 //!     });
@@ -56,7 +54,7 @@
 //! scope(|s|{
 //!     s.spawn(|| {
 //!         for i in 0..6 {
-//!             let mut_ref = unsafe { slice.get_mut(i) };
+//!             let mut mut_ref = unsafe { slice.get_mut(i) };
 //!             if i % 2 == 0 {
 //!                 *mut_ref = 42;
 //!             }
@@ -64,7 +62,7 @@
 //!     });
 //!     s.spawn(|| {
 //!         for i in 0..6 {
-//!             let mut_ref = unsafe { slice.get_mut(i) };
+//!             let mut mut_ref = unsafe { slice.get_mut(i) };
 //!             if i % 2 != 0 {
 //!                 *mut_ref = 69;
 //!             }
@@ -262,7 +260,27 @@
 //! }
 //! ```
 //!
+//! # Feature flags
+//!
+//! - `rayon`: enables methods that run closures over the collection using [rayon](https://docs.rs/rayon).
+//! - `checked`: makes the `*_unchecked` methods perform bounds checking too, panicking on
+//!   out of bounds accesses instead of causing [undefined behavior]. This is meant for debugging
+//!   and does not make data races or aliasing violations any less undefined.
+//! - `smallvec`: implements [`IntoParIndex`] for [`SmallVec`](https://docs.rs/smallvec).
+//! - `bytemuck`: enables viewing owned collections of [`Pod`](https://docs.rs/bytemuck) elements as bytes.
+//! - `track-borrows`: makes owned and borrowed slices keep the borrow state of each of their elements (or chunks), so that
+//!   [`get`](`UnsafeIndex::get`) and [`get_mut`](`UnsafeIndex::get_mut`) return guards that keep the element
+//!   borrowed until they are dropped and panic on aliasing violations instead of causing [undefined behavior].
+//! - `tracing`: emits a [`trace!`](https://docs.rs/tracing) event recording the index of each mutable reference
+//!   obtained from and each value set into a slice, to profile access patterns.
+//! - `rand`: enables shuffling owned collections with a [`Rng`](https://docs.rs/rand).
+//! - `allocator_api`: enables constructors of owned slices in a custom
+//!   [`Allocator`](https://doc.rust-lang.org/std/alloc/trait.Allocator.html).
+//!   Requires a nightly toolchain, as it relies on the unstable `allocator_api` language feature.
+//!
 //! [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+
 mod impls;
 pub use impls::*;
 
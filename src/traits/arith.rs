@@ -0,0 +1,480 @@
+use crate::*;
+use std::ops::{Add, BitAnd, BitOr, Sub};
+
+/// Unsynchronized arithmetic updates of elements of a collection without creating references
+/// to its elements.
+///
+/// Each method performs a non-atomic read-modify-write of a single element through
+/// [`UnsafeNoRefIndex`]: the element is read, combined with the given operand and written back.
+/// This is useful to accumulate values (*e.g.* in the bins of a histogram) when it is known that
+/// each element is only ever updated by one thread at a time, without paying for atomics.
+///
+/// The user is responsible to avoid data races: as the updates are **not atomic**, two threads
+/// updating the same element at the same time is undefined behavior and would lose updates even if it wasn't.
+///
+/// This trait is implemented for every collection that implements [`UnsafeNoRefIndex`].
+///
+/// # Examples
+///
+/// ```
+/// # use par_slice::*;
+/// # use std::thread::scope;
+/// let bins = vec![0_u32; 4].into_par_index_no_ref();
+///
+/// scope(|s| {
+///     // Each thread only updates its own bins
+///     s.spawn(|| {
+///         for value in [0, 1, 0, 1, 1] {
+///             unsafe { bins.add(value, 1) };
+///         }
+///     });
+///     s.spawn(|| {
+///         for value in [2, 3, 3] {
+///             unsafe { bins.add(value, 1) };
+///         }
+///     });
+/// });
+///
+/// assert_eq!(bins.into(), vec![2, 3, 1, 2]);
+/// ```
+pub trait UnsafeArith<T>: UnsafeNoRefIndex<T> {
+    /// Adds `delta` to the element identified by `index` in the collection.
+    ///
+    /// This method performs bounds checking on `index` to ensure its validity.
+    /// If you can guarantee its validity, you may want to use the [`add_unchecked`](`Self::add_unchecked`)
+    /// method instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of the collection.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also writing or reading the same element from another thread
+    /// is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![1; 3].into_par_index_no_ref();
+    ///
+    /// // This is single threaded so no data races can happen
+    /// unsafe { collection.add(0, 41) };
+    ///
+    /// assert_eq!(collection.into(), vec![42, 1, 1]);
+    /// ```
+    #[inline]
+    unsafe fn add(&self, index: usize, delta: T)
+    where
+        T: Copy + Add<Output = T>,
+    {
+        assert_in_bounds(self.len(), index);
+        unsafe {
+            // Safety: we just checked that index is in bounds
+            self.add_unchecked(index, delta)
+        }
+    }
+
+    /// Adds `delta` to the element identified by `index` in the collection, without performing
+    /// bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also writing or reading the same element from another thread
+    /// is undefined behavior.
+    /// Calling this method with an index `i` that would panic [`add`](`Self::add`) is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![1; 3].into_par_index_no_ref();
+    ///
+    /// // We know 0 is a valid index for a collection of length 3
+    /// // and this is single threaded so no data races can happen
+    /// unsafe { collection.add_unchecked(0, 41) };
+    ///
+    /// assert_eq!(collection.into(), vec![42, 1, 1]);
+    /// ```
+    #[inline]
+    unsafe fn add_unchecked(&self, index: usize, delta: T)
+    where
+        T: Copy + Add<Output = T>,
+    {
+        unsafe {
+            // Safety: the caller guarantees that there are no data races and that
+            // index is valid
+            let value = self.get_value_unchecked(index);
+            self.set_value_unchecked(index, value + delta);
+        }
+    }
+
+    /// Subtracts `delta` from the element identified by `index` in the collection.
+    ///
+    /// This method performs bounds checking on `index` to ensure its validity.
+    /// If you can guarantee its validity, you may want to use the [`sub_unchecked`](`Self::sub_unchecked`)
+    /// method instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of the collection.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also writing or reading the same element from another thread
+    /// is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![43; 3].into_par_index_no_ref();
+    ///
+    /// // This is single threaded so no data races can happen
+    /// unsafe { collection.sub(0, 1) };
+    ///
+    /// assert_eq!(collection.into(), vec![42, 43, 43]);
+    /// ```
+    #[inline]
+    unsafe fn sub(&self, index: usize, delta: T)
+    where
+        T: Copy + Sub<Output = T>,
+    {
+        assert_in_bounds(self.len(), index);
+        unsafe {
+            // Safety: we just checked that index is in bounds
+            self.sub_unchecked(index, delta)
+        }
+    }
+
+    /// Subtracts `delta` from the element identified by `index` in the collection, without performing
+    /// bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also writing or reading the same element from another thread
+    /// is undefined behavior.
+    /// Calling this method with an index `i` that would panic [`sub`](`Self::sub`) is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![43; 3].into_par_index_no_ref();
+    ///
+    /// // We know 0 is a valid index for a collection of length 3
+    /// // and this is single threaded so no data races can happen
+    /// unsafe { collection.sub_unchecked(0, 1) };
+    ///
+    /// assert_eq!(collection.into(), vec![42, 43, 43]);
+    /// ```
+    #[inline]
+    unsafe fn sub_unchecked(&self, index: usize, delta: T)
+    where
+        T: Copy + Sub<Output = T>,
+    {
+        unsafe {
+            // Safety: the caller guarantees that there are no data races and that
+            // index is valid
+            let value = self.get_value_unchecked(index);
+            self.set_value_unchecked(index, value - delta);
+        }
+    }
+
+    /// Sets the element identified by `index` in the collection to the bitwise or of
+    /// the element and `bits`.
+    ///
+    /// This method performs bounds checking on `index` to ensure its validity.
+    /// If you can guarantee its validity, you may want to use the [`or_unchecked`](`Self::or_unchecked`)
+    /// method instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of the collection.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also writing or reading the same element from another thread
+    /// is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0b0001_u8; 3].into_par_index_no_ref();
+    ///
+    /// // This is single threaded so no data races can happen
+    /// unsafe { collection.or(0, 0b0100) };
+    ///
+    /// assert_eq!(collection.into(), vec![0b0101, 0b0001, 0b0001]);
+    /// ```
+    #[inline]
+    unsafe fn or(&self, index: usize, bits: T)
+    where
+        T: Copy + BitOr<Output = T>,
+    {
+        assert_in_bounds(self.len(), index);
+        unsafe {
+            // Safety: we just checked that index is in bounds
+            self.or_unchecked(index, bits)
+        }
+    }
+
+    /// Sets the element identified by `index` in the collection to the bitwise or of
+    /// the element and `bits`, without performing bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also writing or reading the same element from another thread
+    /// is undefined behavior.
+    /// Calling this method with an index `i` that would panic [`or`](`Self::or`) is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0b0001_u8; 3].into_par_index_no_ref();
+    ///
+    /// // We know 0 is a valid index for a collection of length 3
+    /// // and this is single threaded so no data races can happen
+    /// unsafe { collection.or_unchecked(0, 0b0100) };
+    ///
+    /// assert_eq!(collection.into(), vec![0b0101, 0b0001, 0b0001]);
+    /// ```
+    #[inline]
+    unsafe fn or_unchecked(&self, index: usize, bits: T)
+    where
+        T: Copy + BitOr<Output = T>,
+    {
+        unsafe {
+            // Safety: the caller guarantees that there are no data races and that
+            // index is valid
+            let value = self.get_value_unchecked(index);
+            self.set_value_unchecked(index, value | bits);
+        }
+    }
+
+    /// Sets the element identified by `index` in the collection to the bitwise and of
+    /// the element and `bits`.
+    ///
+    /// This method performs bounds checking on `index` to ensure its validity.
+    /// If you can guarantee its validity, you may want to use the [`and_unchecked`](`Self::and_unchecked`)
+    /// method instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of the collection.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also writing or reading the same element from another thread
+    /// is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0b0101_u8; 3].into_par_index_no_ref();
+    ///
+    /// // This is single threaded so no data races can happen
+    /// unsafe { collection.and(0, 0b0100) };
+    ///
+    /// assert_eq!(collection.into(), vec![0b0100, 0b0101, 0b0101]);
+    /// ```
+    #[inline]
+    unsafe fn and(&self, index: usize, bits: T)
+    where
+        T: Copy + BitAnd<Output = T>,
+    {
+        assert_in_bounds(self.len(), index);
+        unsafe {
+            // Safety: we just checked that index is in bounds
+            self.and_unchecked(index, bits)
+        }
+    }
+
+    /// Sets the element identified by `index` in the collection to the bitwise and of
+    /// the element and `bits`, without performing bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also writing or reading the same element from another thread
+    /// is undefined behavior.
+    /// Calling this method with an index `i` that would panic [`and`](`Self::and`) is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0b0101_u8; 3].into_par_index_no_ref();
+    ///
+    /// // We know 0 is a valid index for a collection of length 3
+    /// // and this is single threaded so no data races can happen
+    /// unsafe { collection.and_unchecked(0, 0b0100) };
+    ///
+    /// assert_eq!(collection.into(), vec![0b0100, 0b0101, 0b0101]);
+    /// ```
+    #[inline]
+    unsafe fn and_unchecked(&self, index: usize, bits: T)
+    where
+        T: Copy + BitAnd<Output = T>,
+    {
+        unsafe {
+            // Safety: the caller guarantees that there are no data races and that
+            // index is valid
+            let value = self.get_value_unchecked(index);
+            self.set_value_unchecked(index, value & bits);
+        }
+    }
+
+    /// Stores the minimum between `value` and the element identified by `index` in the collection,
+    /// returning the previous value of the element.
+    ///
+    /// This method performs bounds checking on `index` to ensure its validity.
+    /// If you can guarantee its validity, you may want to use the [`fetch_min_unchecked`](`Self::fetch_min_unchecked`)
+    /// method instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of the collection.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also writing or reading the same element from another thread
+    /// is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![u32::MAX; 3].into_par_index_no_ref();
+    ///
+    /// // This is single threaded so no data races can happen
+    /// assert_eq!(unsafe { collection.fetch_min(0, 42) }, u32::MAX);
+    ///
+    /// assert_eq!(collection.into(), vec![42, u32::MAX, u32::MAX]);
+    /// ```
+    #[inline]
+    unsafe fn fetch_min(&self, index: usize, value: T) -> T
+    where
+        T: Copy + Ord,
+    {
+        assert_in_bounds(self.len(), index);
+        unsafe {
+            // Safety: we just checked that index is in bounds
+            self.fetch_min_unchecked(index, value)
+        }
+    }
+
+    /// Stores the minimum between `value` and the element identified by `index` in the collection,
+    /// returning the previous value of the element, without performing bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also writing or reading the same element from another thread
+    /// is undefined behavior.
+    /// Calling this method with an index `i` that would panic [`fetch_min`](`Self::fetch_min`) is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![u32::MAX; 3].into_par_index_no_ref();
+    ///
+    /// // We know 0 is a valid index for a collection of length 3
+    /// // and this is single threaded so no data races can happen
+    /// assert_eq!(unsafe { collection.fetch_min_unchecked(0, 42) }, u32::MAX);
+    ///
+    /// assert_eq!(collection.into(), vec![42, u32::MAX, u32::MAX]);
+    /// ```
+    #[inline]
+    unsafe fn fetch_min_unchecked(&self, index: usize, value: T) -> T
+    where
+        T: Copy + Ord,
+    {
+        unsafe {
+            // Safety: the caller guarantees that there are no data races and that
+            // index is valid
+            let previous = self.get_value_unchecked(index);
+            self.set_value_unchecked(index, previous.min(value));
+            previous
+        }
+    }
+
+    /// Stores the maximum between `value` and the element identified by `index` in the collection,
+    /// returning the previous value of the element.
+    ///
+    /// This method performs bounds checking on `index` to ensure its validity.
+    /// If you can guarantee its validity, you may want to use the [`fetch_max_unchecked`](`Self::fetch_max_unchecked`)
+    /// method instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of the collection.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also writing or reading the same element from another thread
+    /// is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 3].into_par_index_no_ref();
+    ///
+    /// // This is single threaded so no data races can happen
+    /// assert_eq!(unsafe { collection.fetch_max(0, 42) }, 0);
+    ///
+    /// assert_eq!(collection.into(), vec![42, 0, 0]);
+    /// ```
+    #[inline]
+    unsafe fn fetch_max(&self, index: usize, value: T) -> T
+    where
+        T: Copy + Ord,
+    {
+        assert_in_bounds(self.len(), index);
+        unsafe {
+            // Safety: we just checked that index is in bounds
+            self.fetch_max_unchecked(index, value)
+        }
+    }
+
+    /// Stores the maximum between `value` and the element identified by `index` in the collection,
+    /// returning the previous value of the element, without performing bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also writing or reading the same element from another thread
+    /// is undefined behavior.
+    /// Calling this method with an index `i` that would panic [`fetch_max`](`Self::fetch_max`) is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 3].into_par_index_no_ref();
+    ///
+    /// // We know 0 is a valid index for a collection of length 3
+    /// // and this is single threaded so no data races can happen
+    /// assert_eq!(unsafe { collection.fetch_max_unchecked(0, 42) }, 0);
+    ///
+    /// assert_eq!(collection.into(), vec![42, 0, 0]);
+    /// ```
+    #[inline]
+    unsafe fn fetch_max_unchecked(&self, index: usize, value: T) -> T
+    where
+        T: Copy + Ord,
+    {
+        unsafe {
+            // Safety: the caller guarantees that there are no data races and that
+            // index is valid
+            let previous = self.get_value_unchecked(index);
+            self.set_value_unchecked(index, previous.max(value));
+            previous
+        }
+    }
+}
+
+impl<T, C: UnsafeNoRefIndex<T> + ?Sized> UnsafeArith<T> for C {}
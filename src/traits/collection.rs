@@ -101,6 +101,111 @@ pub unsafe trait TrustedChunkSizedCollection: TrustedSizedCollection {
     fn num_chunks(&self) -> usize {
         self.len()
     }
+
+    /// Returns the number of elements in the last chunk of the collection, or `0` if the collection
+    /// has no chunks.
+    ///
+    /// As every chunk of a collection has [`chunk_size`](`TrustedChunkSizedCollection::chunk_size`)
+    /// elements, this is equal to the chunk size for collections that are not empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 20].into_par_chunk_index(5);
+    /// assert_eq!(collection.last_chunk_len(), 5);
+    ///
+    /// let collection = Vec::<u32>::new().into_par_chunk_index(5);
+    /// assert_eq!(collection.last_chunk_len(), 0);
+    /// ```
+    #[inline]
+    fn last_chunk_len(&self) -> usize {
+        match self.num_chunks() {
+            0 => 0,
+            num_chunks => self.num_elements() - (num_chunks - 1) * self.chunk_size(),
+        }
+    }
+
+    /// Returns the index of the chunk containing the element identified by `element_index`
+    /// in the flat view of the collection.
+    ///
+    /// This method performs bounds checking on `element_index` to ensure its validity.
+    /// If you can guarantee its validity, you may want to use the [`chunk_index_of_unchecked`](`Self::chunk_index_of_unchecked`)
+    /// method instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `element_index` is out of bounds of the elements of the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 12].into_par_chunk_index(4);
+    /// assert_eq!(collection.chunk_index_of(6), 1);
+    /// assert_eq!(collection.offset_in_chunk(6), 2);
+    /// ```
+    #[inline]
+    fn chunk_index_of(&self, element_index: usize) -> usize {
+        assert_in_bounds(self.num_elements(), element_index);
+        self.chunk_index_of_unchecked(element_index)
+    }
+
+    /// Returns the index of the chunk containing the element identified by `element_index`
+    /// in the flat view of the collection, without performing bounds checking.
+    ///
+    /// If `element_index` is out of bounds, the returned index is out of bounds too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 12].into_par_chunk_index(4);
+    /// assert_eq!(collection.chunk_index_of_unchecked(6), 1);
+    /// ```
+    #[inline]
+    fn chunk_index_of_unchecked(&self, element_index: usize) -> usize {
+        element_index / self.chunk_size()
+    }
+
+    /// Returns the offset inside its chunk of the element identified by `element_index`
+    /// in the flat view of the collection.
+    ///
+    /// This method performs bounds checking on `element_index` to ensure its validity.
+    /// If you can guarantee its validity, you may want to use the [`offset_in_chunk_unchecked`](`Self::offset_in_chunk_unchecked`)
+    /// method instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `element_index` is out of bounds of the elements of the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 12].into_par_chunk_index(4);
+    /// assert_eq!(collection.offset_in_chunk(6), 2);
+    /// ```
+    #[inline]
+    fn offset_in_chunk(&self, element_index: usize) -> usize {
+        assert_in_bounds(self.num_elements(), element_index);
+        self.offset_in_chunk_unchecked(element_index)
+    }
+
+    /// Returns the offset inside its chunk of the element identified by `element_index`
+    /// in the flat view of the collection, without performing bounds checking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 12].into_par_chunk_index(4);
+    /// assert_eq!(collection.offset_in_chunk_unchecked(6), 2);
+    /// ```
+    #[inline]
+    fn offset_in_chunk_unchecked(&self, element_index: usize) -> usize {
+        element_index % self.chunk_size()
+    }
 }
 
 /// Traits common to parallel views on collections.
@@ -123,6 +228,16 @@ pub(crate) fn assert_in_bounds(len: usize, index: usize) {
     assert!(index < len, "Index {index} invalid for slice of len {len}")
 }
 
+/// Asserts that the `count` chunks starting from `start` are all in bounds
+/// of a collection of `num_chunks` chunks, panicking otherwise
+#[inline]
+pub(crate) fn assert_chunks_in_bounds(num_chunks: usize, start: usize, count: usize) {
+    assert!(
+        start <= num_chunks && count <= num_chunks - start,
+        "Chunks {start}..{start}+{count} invalid for slice of {num_chunks} chunks"
+    )
+}
+
 /// Asserts that `chunk.len()` is equal to `chunk_size`, panicking otherwise
 #[inline]
 pub(crate) fn assert_chunk_compatible<T>(chunk_size: usize, chunk: &[T]) {
@@ -134,6 +249,16 @@ pub(crate) fn assert_chunk_compatible<T>(chunk_size: usize, chunk: &[T]) {
     )
 }
 
+/// Asserts that an array of length `n` has the same length as a chunk of `chunk_size` elements,
+/// panicking otherwise
+#[inline]
+pub(crate) fn assert_array_compatible(chunk_size: usize, n: usize) {
+    assert!(
+        n == chunk_size,
+        "array should have the same length as the chunk. Got an array of length {n} for a chunk of length {chunk_size}"
+    )
+}
+
 /// Asserts that a collection of size `len` can be split exactly in chunks of size `chunk_size`,
 /// panicking if this is not true.
 #[inline]
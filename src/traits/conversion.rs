@@ -44,6 +44,10 @@ use crate::*;
 ///   equal to `num_elements / chunk_size` and chunk indices follow the collection's original
 ///   indices (*i.e.* chunk 0 of a collection of `chunk_size` 4 includes indices from 0 to 3,
 ///   chunk 1 includes indices from 4 to 7, etc.).
+/// * [`as_rchunk_index`](`Self::as_rchunk_index`) panics if the collection's size is not divisible by `chunk_size`
+///   and returns a view on the collection that behaves like the one returned by
+///   [`as_par_chunk_index`](`Self::as_par_chunk_index`), except that chunk `i` of the view is chunk
+///   `num_chunks - 1 - i` of the collection.
 ///
 /// # Examples
 ///
@@ -78,7 +82,7 @@ use crate::*;
 /// {
 ///     // Let's use references to chunks of size 5
 ///     let view = collection.as_par_chunk_index(5);
-///     let last_five = unsafe { view.get_mut(1) };
+///     let mut last_five = unsafe { view.get_mut(1) };
 ///     let mut i = 1;
 ///     for elem in last_five.iter_mut() {
 ///         *elem = i;
@@ -135,7 +139,7 @@ pub unsafe trait ParIndexView<T> {
     ///
     /// assert_eq!(collection, vec![0, 42, 2, 3, 4, 69, 6, 7, 8, 9]);
     /// ```
-    fn as_par_index_no_ref(&mut self) -> impl UnsafeNoRefIndex<T> + ParView<T>;
+    fn as_par_index_no_ref(&mut self) -> impl UnsafeNoRefPointerIndex<T> + ParView<T>;
 
     /// Returns a view of the collection that allows unsynchronized access to its
     /// elements through references.
@@ -148,8 +152,8 @@ pub unsafe trait ParIndexView<T> {
     ///
     /// {
     ///     let view = collection.as_par_index();
-    ///     let mut_ref_1 = unsafe { view.get_mut(1) };
-    ///     let mut_ref_5 = unsafe { view.get_mut(5) };
+    ///     let mut mut_ref_1 = unsafe { view.get_mut(1) };
+    ///     let mut mut_ref_5 = unsafe { view.get_mut(5) };
     ///     let ref_2 = unsafe { view.get(2) };
     ///     *mut_ref_1 = 42;
     ///     *mut_ref_5 = 69;
@@ -158,6 +162,32 @@ pub unsafe trait ParIndexView<T> {
     ///
     /// assert_eq!(collection, vec![0, 42, 2, 3, 4, 69, 6, 7, 8, 9]);
     /// ```
+    ///
+    /// Arrays and mutable references to arrays can be viewed directly, including where
+    /// a [`ParIndexView`] is expected:
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// fn set_last(mut collection: impl ParIndexView<i32>, value: i32) {
+    ///     let view = collection.as_par_index();
+    ///     unsafe { *view.get_mut(view.len() - 1) = value };
+    /// }
+    ///
+    /// let mut array = [0_i32; 4];
+    ///
+    /// {
+    ///     let view = array.as_par_index();
+    ///     unsafe { *view.get_mut(1) = 42 };
+    /// }
+    /// {
+    ///     let array_ref = &mut array;
+    ///     let view = array_ref.as_par_index();
+    ///     unsafe { *view.get_mut(2) = 69 };
+    /// }
+    /// set_last(&mut array, 7);
+    ///
+    /// assert_eq!(array, [0, 42, 69, 7]);
+    /// ```
     fn as_par_index(&mut self) -> impl UnsafeIndex<T> + ParView<T>;
 
     /// Returns a view of the collection that allows unsynchronized access to
@@ -189,7 +219,7 @@ pub unsafe trait ParIndexView<T> {
     fn as_pointer_par_chunk_index(
         &mut self,
         chunk_size: usize,
-    ) -> impl PointerChunkIndex<T> + ParView<[T]>;
+    ) -> impl ContiguousPointerChunkIndex<T> + ParView<[T]>;
 
     /// Returns a view of the collection that allows unsynchronized access to
     /// chunks of `chunk_size` of its elements through setters and getters.
@@ -218,7 +248,7 @@ pub unsafe trait ParIndexView<T> {
     fn as_par_chunk_index_no_ref(
         &mut self,
         chunk_size: usize,
-    ) -> impl UnsafeNoRefChunkIndex<T> + ParView<[T]>;
+    ) -> impl UnsafeNoRefPointerChunkIndex<T> + ParView<[T]>;
 
     /// Returns a view of the collection that allows unsynchronized access to
     /// chunks of `chunk_size` of its elements through references.
@@ -235,8 +265,8 @@ pub unsafe trait ParIndexView<T> {
     ///
     /// {
     ///     let view = collection.as_par_chunk_index(5);
-    ///     let first_five = unsafe { view.get_mut(0) };
-    ///     let last_five = unsafe { view.get_mut(1) };
+    ///     let mut first_five = unsafe { view.get_mut(0) };
+    ///     let mut last_five = unsafe { view.get_mut(1) };
     ///     first_five[1] = 42;
     ///     last_five[0] = 69;
     ///     assert_eq!(first_five[2], 2);
@@ -244,7 +274,110 @@ pub unsafe trait ParIndexView<T> {
     ///
     /// assert_eq!(collection, vec![0, 42, 2, 3, 4, 69, 6, 7, 8, 9]);
     /// ```
-    fn as_par_chunk_index(&mut self, chunk_size: usize) -> impl UnsafeChunkIndex<T> + ParView<[T]>;
+    fn as_par_chunk_index(
+        &mut self,
+        chunk_size: usize,
+    ) -> impl ContiguousUnsafeChunkIndex<T> + ParView<[T]>;
+
+    /// Returns a view of the collection that allows unsynchronized access to
+    /// chunks of `chunk_size` of its elements through references, or a [`ChunkSizeError`]
+    /// if the size of the collection is not divisible by `chunk_size`.
+    ///
+    /// This is the non-panicking version of [`as_par_chunk_index`](`Self::as_par_chunk_index`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let mut collection = vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+    ///
+    /// assert!(collection.try_as_par_chunk_index(3).is_err());
+    ///
+    /// {
+    ///     let view = collection.try_as_par_chunk_index(5).unwrap();
+    ///     let mut last_five = unsafe { view.get_mut(1) };
+    ///     last_five[0] = 69;
+    /// }
+    ///
+    /// assert_eq!(collection, vec![0, 1, 2, 3, 4, 69, 6, 7, 8, 9]);
+    /// ```
+    fn try_as_par_chunk_index(
+        &mut self,
+        chunk_size: usize,
+    ) -> Result<impl ContiguousUnsafeChunkIndex<T> + ParView<[T]>, ChunkSizeError> {
+        ChunkSizeError::check(self.as_pointer_par_index().len(), chunk_size)?;
+        Ok(self.as_par_chunk_index(chunk_size))
+    }
+
+    /// Returns a view of the collection that allows unsynchronized access to
+    /// chunks of `chunk_size` of its elements through references, where chunks are
+    /// indexed starting from the end of the collection (like [`slice::rchunks`]).
+    ///
+    /// Chunk `0` of the view is the last chunk of the collection, chunk `1` the one before it and so on,
+    /// while elements inside each chunk keep their original order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the collection is not divisible by `chunk_size`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let mut collection = vec![1, 2, 3, 4];
+    ///
+    /// {
+    ///     let view = collection.as_rchunk_index(2);
+    ///     assert_eq!(unsafe { view.get(0) }, &[3, 4]);
+    ///     unsafe { view.get_mut(1)[0] = 42 };
+    /// }
+    ///
+    /// assert_eq!(collection, vec![42, 2, 3, 4]);
+    /// ```
+    fn as_rchunk_index(&mut self, chunk_size: usize) -> impl UnsafeChunkIndex<T> + ParView<[T]> {
+        RevChunkSlice::new(self.as_par_chunk_index(chunk_size))
+    }
+
+    /// Returns a view of the longest prefix of the collection divisible by `chunk_size` that allows
+    /// unsynchronized access to chunks of `chunk_size` of its elements through references, together
+    /// with a mutable slice over the remaining `len % chunk_size` elements (like [`slice::chunks_exact_mut`]).
+    ///
+    /// This allows to process the bulk of the collection in uniform chunks and to handle the
+    /// remainder separately.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let mut collection = vec![0, 1, 2, 3, 4, 5, 6];
+    ///
+    /// {
+    ///     let (view, remainder) = collection.as_par_chunk_index_remainder(3);
+    ///     assert_eq!(view.num_chunks(), 2);
+    ///     unsafe { view.get_mut(1)[0] = 42 };
+    ///     remainder[0] = 69;
+    /// }
+    ///
+    /// assert_eq!(collection, vec![0, 1, 2, 42, 4, 5, 69]);
+    /// ```
+    fn as_par_chunk_index_remainder(
+        &mut self,
+        chunk_size: usize,
+    ) -> (impl ContiguousUnsafeChunkIndex<T> + ParView<[T]>, &mut [T])
+    where
+        Self: AsMut<[T]>,
+        T: Send + Sync,
+    {
+        assert!(chunk_size > 0, "chunk_size should be greater than 0");
+        let slice = self.as_mut();
+        let mid = slice.len() - slice.len() % chunk_size;
+        let (chunks, remainder) = slice.split_at_mut(mid);
+        (UnsafeChunkView::new(chunks, chunk_size), remainder)
+    }
 }
 
 /// A value-to-value conversion that consumes the input collection and produces one
@@ -328,13 +461,15 @@ pub unsafe trait ParIndexView<T> {
 /// // Let's use references to chunks of size 5
 /// let par_collection = collection.into_par_chunk_index(5);
 ///
-/// let last_five = unsafe { par_collection.get_mut(1) };
-/// let mut i = 1;
-/// for elem in last_five.iter_mut() {
-///     *elem = i;
-///     i += 1;
+/// {
+///     let mut last_five = unsafe { par_collection.get_mut(1) };
+///     let mut i = 1;
+///     for elem in last_five.iter_mut() {
+///         *elem = i;
+///         i += 1;
+///     }
+///     last_five[2] = 42;
 /// }
-/// last_five[2] = 42;
 ///
 /// collection = par_collection.into();
 /// assert_eq!(collection, vec![0, 42, 69, 69, 0, 1, 2, 42, 4, 5]);
@@ -360,7 +495,9 @@ pub unsafe trait IntoParIndex<T>: Sized {
     ///
     /// assert_eq!(collection.into(), vec![0, 42, 2, 3, 4, 69, 6, 7, 8, 9]);
     /// ```
-    fn into_pointer_par_index(self) -> impl PointerIndex<T> + ParCollection<T, Self>;
+    fn into_pointer_par_index(
+        self,
+    ) -> impl PointerIndex<T> + ParCollection<T, Self> + SerialAccess<T>;
 
     /// Converts the collection into one that allows unsynchronized access to its
     /// elements through setters and getters.
@@ -379,7 +516,9 @@ pub unsafe trait IntoParIndex<T>: Sized {
     ///
     /// assert_eq!(collection.into(), vec![0, 42, 2, 3, 4, 69, 6, 7, 8, 9]);
     /// ```
-    fn into_par_index_no_ref(self) -> impl UnsafeNoRefIndex<T> + ParCollection<T, Self>;
+    fn into_par_index_no_ref(
+        self,
+    ) -> impl UnsafeNoRefPointerIndex<T> + ParCollection<T, Self> + SerialAccess<T>;
 
     /// Converts the collection into one that allows unsynchronized access to its
     /// elements through references.
@@ -390,16 +529,18 @@ pub unsafe trait IntoParIndex<T>: Sized {
     /// # use par_slice::*;
     /// let collection = vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9].into_par_index();
     ///
-    /// let mut_ref_1 = unsafe { collection.get_mut(1) };
-    /// let mut_ref_5 = unsafe { collection.get_mut(5) };
-    /// let ref_2 = unsafe { collection.get(2) };
-    /// *mut_ref_1 = 42;
-    /// *mut_ref_5 = 69;
-    /// assert_eq!(*ref_2, 2);
+    /// {
+    ///     let mut mut_ref_1 = unsafe { collection.get_mut(1) };
+    ///     let mut mut_ref_5 = unsafe { collection.get_mut(5) };
+    ///     let ref_2 = unsafe { collection.get(2) };
+    ///     *mut_ref_1 = 42;
+    ///     *mut_ref_5 = 69;
+    ///     assert_eq!(*ref_2, 2);
+    /// }
     ///
     /// assert_eq!(collection.into(), vec![0, 42, 2, 3, 4, 69, 6, 7, 8, 9]);
     /// ```
-    fn into_par_index(self) -> impl UnsafeIndex<T> + ParCollection<T, Self>;
+    fn into_par_index(self) -> impl UnsafeIndex<T> + ParCollection<T, Self> + SerialAccess<T>;
 
     /// Converts the collection into one that allows unsynchronized access to
     /// chunks of `chunk_size` of its elements through pointers.
@@ -427,7 +568,7 @@ pub unsafe trait IntoParIndex<T>: Sized {
     fn into_pointer_par_chunk_index(
         self,
         chunk_size: usize,
-    ) -> impl PointerChunkIndex<T> + ParCollection<[T], Self>;
+    ) -> impl ContiguousPointerChunkIndex<T> + ParCollection<[T], Self> + SerialAccess<T>;
 
     /// Converts the collection into one that allows unsynchronized access to
     /// chunks of `chunk_size` of its elements through setters and getters.
@@ -453,7 +594,7 @@ pub unsafe trait IntoParIndex<T>: Sized {
     fn into_par_chunk_index_no_ref(
         self,
         chunk_size: usize,
-    ) -> impl UnsafeNoRefChunkIndex<T> + ParCollection<[T], Self>;
+    ) -> impl UnsafeNoRefPointerChunkIndex<T> + ParCollection<[T], Self> + SerialAccess<T>;
 
     /// Converts the collection into one that allows unsynchronized access to
     /// chunks of `chunk_size` of its elements through references.
@@ -468,16 +609,166 @@ pub unsafe trait IntoParIndex<T>: Sized {
     /// # use par_slice::*;
     /// let collection = vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9].into_par_chunk_index(5);
     ///
-    /// let first_five = unsafe { collection.get_mut(0) };
-    /// let last_five = unsafe { collection.get_mut(1) };
-    /// first_five[1] = 42;
-    /// last_five[0] = 69;
-    /// assert_eq!(first_five[2], 2);
+    /// {
+    ///     let mut first_five = unsafe { collection.get_mut(0) };
+    ///     let mut last_five = unsafe { collection.get_mut(1) };
+    ///     first_five[1] = 42;
+    ///     last_five[0] = 69;
+    ///     assert_eq!(first_five[2], 2);
+    /// }
     ///
     /// assert_eq!(collection.into(), vec![0, 42, 2, 3, 4, 69, 6, 7, 8, 9]);
     /// ```
     fn into_par_chunk_index(
         self,
         chunk_size: usize,
-    ) -> impl UnsafeChunkIndex<T> + ParCollection<[T], Self>;
+    ) -> impl ContiguousUnsafeChunkIndex<T> + ParCollection<[T], Self> + SerialAccess<T>;
+
+    /// Converts the collection into one that allows unsynchronized access to
+    /// chunks of `chunk_size` of its elements through references, or returns a [`ChunkSizeError`]
+    /// if the size of the collection is not divisible by `chunk_size`.
+    ///
+    /// This is the non-panicking version of [`into_par_chunk_index`](`Self::into_par_chunk_index`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// assert!(vec![0; 10].try_into_par_chunk_index(3).is_err());
+    ///
+    /// let collection = vec![0; 10].try_into_par_chunk_index(5).unwrap();
+    /// unsafe { collection.get_mut(1)[0] = 69 };
+    ///
+    /// assert_eq!(collection.into(), vec![0, 0, 0, 0, 0, 69, 0, 0, 0, 0]);
+    /// ```
+    fn try_into_par_chunk_index(
+        self,
+        chunk_size: usize,
+    ) -> Result<
+        impl ContiguousUnsafeChunkIndex<T> + ParCollection<[T], Self> + SerialAccess<T>,
+        ChunkSizeError,
+    >
+    where
+        Self: AsRef<[T]>,
+    {
+        ChunkSizeError::check(self.as_ref().len(), chunk_size)?;
+        Ok(self.into_par_chunk_index(chunk_size))
+    }
+}
+
+/// A value-to-value conversion that consumes a shared immutable collection and produces
+/// one that allows unsynchronized read-only access to its elements through pointers.
+///
+/// The returned collection can be cloned cheaply and shared between threads, and each
+/// clone refers to the same elements.
+///
+/// As the elements are shared and immutable, the returned collection only allows to read them
+/// through [`SharedPointerIndex`], which is always free of data races.
+///
+/// # Safety
+///
+/// Implementors of this trait must guarantee that
+/// [`into_shared_pointer_par_index`](`Self::into_shared_pointer_par_index`) returns a collection
+/// such that its [`len`](`TrustedSizedCollection::len`) is the size of the input collection and that if
+/// index `i` refers to element `x` in the input collection, it refers to element `x` in the returned
+/// collection (and all of its clones) as well.
+///
+/// # Examples
+///
+/// ```
+/// # use par_slice::*;
+/// # use std::{sync::Arc, thread::scope};
+/// let table: Arc<[u32]> = Arc::from([1, 2, 3, 4]);
+/// let shared = table.into_shared_pointer_par_index();
+///
+/// scope(|s| {
+///     for i in 0..4 {
+///         let shared = shared.clone();
+///         s.spawn(move || {
+///             assert_eq!(unsafe { *shared.get_ptr(i) }, i as u32 + 1);
+///         });
+///     }
+/// });
+/// ```
+pub unsafe trait IntoSharedParIndex<T>: Sized {
+    /// Converts the collection into one that allows unsynchronized read-only access to
+    /// its elements through pointers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// # use std::sync::Arc;
+    /// let table: Arc<[u32]> = Arc::from([1, 2, 3]);
+    /// let shared = table.into_shared_pointer_par_index();
+    ///
+    /// // The elements are never written so this read is always free of data races
+    /// assert_eq!(unsafe { *shared.get_ptr(1) }, 2);
+    ///
+    /// let table: Arc<[u32]> = shared.into();
+    /// assert_eq!(table.as_ref(), &[1, 2, 3]);
+    /// ```
+    fn into_shared_pointer_par_index(
+        self,
+    ) -> impl SharedPointerIndex<T> + ParCollection<T, Self> + Clone;
+}
+
+/// View of a collection that can be mutably borrowed as a slice that allows unsynchronized
+/// access to its elements.
+///
+/// This trait is implemented for every type implementing [`AsMut<[T]>`](`AsMut`) and allows
+/// to use custom buffer types without implementing [`ParIndexView`] for them.
+/// It is a separate trait so that it does not overlap with the [`ParIndexView`] implementation
+/// for slices.
+///
+/// # Safety
+///
+/// Implementors of this trait must guarantee that [`as_par_index_via_asmut`](`Self::as_par_index_via_asmut`)
+/// returns a view on the collection such that its [`len`](`TrustedSizedCollection::len`) is the size of
+/// the collection and that if index `i` refers to element `x` in the collection, it refers to element `x`
+/// in the returned view as well.
+///
+/// # Examples
+///
+/// ```
+/// # use par_slice::*;
+/// struct Buf(Vec<u32>);
+///
+/// impl AsMut<[u32]> for Buf {
+///     fn as_mut(&mut self) -> &mut [u32] {
+///         &mut self.0
+///     }
+/// }
+///
+/// let mut buf = Buf(vec![0; 3]);
+///
+/// {
+///     let view = buf.as_par_index_via_asmut();
+///     unsafe { *view.get_mut(1) = 42 };
+/// }
+///
+/// assert_eq!(buf.0, vec![0, 42, 0]);
+/// ```
+pub unsafe trait AsParIndexExt<T> {
+    /// Returns a view of the slice obtained through [`AsMut::as_mut`] that allows unsynchronized
+    /// access to its elements through references.
+    ///
+    /// This is equivalent to `self.as_mut().as_par_index()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let mut collection = Box::new([0, 1, 2]);
+    ///
+    /// {
+    ///     let view = collection.as_par_index_via_asmut();
+    ///     unsafe { *view.get_mut(0) = 42 };
+    /// }
+    ///
+    /// assert_eq!(collection.as_ref(), &[42, 1, 2]);
+    /// ```
+    fn as_par_index_via_asmut<'a>(&'a mut self) -> impl UnsafeIndex<T> + ParView<T>
+    where
+        T: 'a;
 }
@@ -1,3 +1,6 @@
+mod arith;
+pub use arith::*;
+
 mod conversion;
 pub use conversion::*;
 
@@ -13,5 +16,8 @@ pub use no_ref::*;
 mod pointer;
 pub use pointer::*;
 
+mod serial;
+pub use serial::*;
+
 mod unsafe_index;
 pub use unsafe_index::*;
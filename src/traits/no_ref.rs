@@ -1,4 +1,5 @@
 use crate::*;
+use std::{mem::MaybeUninit, ptr};
 
 /// Unsynchronized access to elements of a collection through setters and getters without
 /// crating references to its elements.
@@ -154,6 +155,7 @@ pub unsafe trait UnsafeNoRefIndex<T: ?Sized>: TrustedSizedCollection {
     /// // This is single threaded so no data races can happen
     /// assert_eq!(unsafe { collection.get_value(0) }, 0);
     /// ```
+    #[must_use]
     #[inline]
     unsafe fn get_value(&self, index: usize) -> T
     where
@@ -187,14 +189,473 @@ pub unsafe trait UnsafeNoRefIndex<T: ?Sized>: TrustedSizedCollection {
     /// // and this is single threaded so no data races can happen
     /// assert_eq!(unsafe { collection.get_value_unchecked(0) }, 0);
     /// ```
+    #[must_use]
     unsafe fn get_value_unchecked(&self, index: usize) -> T
     where
         T: Copy;
 
+    /// Writes a bitwise copy of the element identified by `index` in the collection to `out`.
+    ///
+    /// This method performs bounds checking on `index` to ensure its validity.
+    /// If you can guarantee its validity, you may want to use the [`get_value_into_unchecked`](`Self::get_value_into_unchecked`)
+    /// method instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of the collection.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also writing to the same element from another thread is undefined behavior
+    /// (parallel reads are ok).
+    /// Calling this method with an `out` that is not valid for writes or not properly aligned is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0, 42, 0].into_par_index_no_ref();
+    /// let mut out = 0;
+    ///
+    /// // This is single threaded so no data races can happen
+    /// unsafe { collection.get_value_into(1, &mut out) };
+    ///
+    /// assert_eq!(out, 42);
+    /// ```
+    #[inline]
+    unsafe fn get_value_into(&self, index: usize, out: *mut T)
+    where
+        T: Copy,
+    {
+        assert_in_bounds(self.len(), index);
+        unsafe {
+            // Safety: we just checked that index is in bounds
+            self.get_value_into_unchecked(index, out);
+        }
+    }
+
+    /// Writes a bitwise copy of the element identified by `index` in the collection to `out`, without performing
+    /// bounds checking.
+    ///
+    /// This method does not perform bounds checking on `index` to ensure its validity.
+    /// If you can't guarantee its validity, you may want to use the [`get_value_into`](`Self::get_value_into`) method instead.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also writing to the same element from another thread is undefined behavior
+    /// (parallel reads are ok).
+    /// Calling this method with an index `i` that would panic [`get_value_into`](`Self::get_value_into`) is undefined behavior.
+    /// Calling this method with an `out` that is not valid for writes or not properly aligned is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0, 42, 0].into_par_index_no_ref();
+    /// let mut out = 0;
+    ///
+    /// // We know 1 is a valid index for a collection of length 3
+    /// // and this is single threaded so no data races can happen
+    /// unsafe { collection.get_value_into_unchecked(1, &mut out) };
+    ///
+    /// assert_eq!(out, 42);
+    /// ```
+    #[inline]
+    unsafe fn get_value_into_unchecked(&self, index: usize, out: *mut T)
+    where
+        T: Copy,
+    {
+        unsafe {
+            // Safety: the caller guarantees that index is valid, that there are no data races
+            // and that out is valid for writes. T is Copy, so nothing is dropped
+            out.write(self.get_value_unchecked(index));
+        }
+    }
+
     /// Sets the element identified by `index` in the collection to `value`.
     ///
     /// This method performs bounds checking on `index` to ensure its validity.
-    /// If you can guarantee its validity, you may want to use the [`set_value_unchecked`](`Self::set_value_unchecked`)
+    /// If you can guarantee its validity, you may want to use the [`set_value_unchecked`](`Self::set_value_unchecked`)
+    /// method instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of the collection.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also writing or reading the same element from another thread
+    /// is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 5].into_par_index_no_ref();
+    ///
+    /// // This is single threaded so no data races can happen
+    /// unsafe { collection.set_value(0, 42) };
+    ///
+    /// assert_eq!(collection.into(), vec![42, 0, 0, 0, 0]);
+    /// ```
+    #[inline]
+    unsafe fn set_value(&self, index: usize, value: T)
+    where
+        T: Sized,
+    {
+        assert_in_bounds(self.len(), index);
+        unsafe {
+            // Safety: we just checked that index is in bounds
+            self.set_value_unchecked(index, value);
+        }
+    }
+
+    /// Sets the element identified by `index` in the collection to `value`, without performing
+    /// bounds checking.
+    ///
+    /// This method does not perform bounds checking on `index` to ensure its validity.
+    /// If you can't guarantee its validity, you may want to use the [`set_value`](`Self::set_value`) method instead.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also writing or reading the same element from another thread
+    /// is undefined behavior.
+    /// Calling this method with an index `i` that would panic [`set_value`](`Self::set_value`) is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 5].into_par_index_no_ref();
+    ///
+    /// // We know 0 is a valid index for a collection of length 5
+    /// // and this is single threaded so no data races can happen
+    /// unsafe { collection.set_value_unchecked(0, 42) };
+    ///
+    /// assert_eq!(collection.into(), vec![42, 0, 0, 0, 0]);
+    /// ```
+    unsafe fn set_value_unchecked(&self, index: usize, value: T)
+    where
+        T: Sized;
+
+    /// Returns a bitwise copy of the element identified by `index.as_usize()` in the collection.
+    ///
+    /// This is equivalent to [`get_value`](`Self::get_value`) but accepts any [`AsUsize`] index, which
+    /// avoids wrapping the collection in an [`IndexWrapper`] for one-off typed indexing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index.as_usize()` panics or is out of bounds of the collection.
+    ///
+    /// # Safety
+    ///
+    /// See [`get_value`](`Self::get_value`)'s safety section.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0, 42, 0].into_par_index_no_ref();
+    /// // This is single threaded so no data races can happen
+    /// assert_eq!(unsafe { collection.get_value_at(1_u16) }, 42);
+    /// ```
+    #[must_use]
+    #[inline]
+    unsafe fn get_value_at<I: AsUsize>(&self, index: I) -> T
+    where
+        T: Copy,
+    {
+        unsafe { self.get_value(index.as_usize()) }
+    }
+
+    /// Sets the element identified by `index.as_usize()` in the collection to `value`.
+    ///
+    /// This is equivalent to [`set_value`](`Self::set_value`) but accepts any [`AsUsize`] index, which
+    /// avoids wrapping the collection in an [`IndexWrapper`] for one-off typed indexing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index.as_usize()` panics or is out of bounds of the collection.
+    ///
+    /// # Safety
+    ///
+    /// See [`set_value`](`Self::set_value`)'s safety section.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 3].into_par_index_no_ref();
+    ///
+    /// // This is single threaded so no data races can happen
+    /// unsafe { collection.set_value_at(2_u8, 42) };
+    ///
+    /// assert_eq!(collection.into(), vec![0, 0, 42]);
+    /// ```
+    #[inline]
+    unsafe fn set_value_at<I: AsUsize>(&self, index: I, value: T)
+    where
+        T: Sized,
+    {
+        unsafe { self.set_value(index.as_usize(), value) }
+    }
+}
+
+/// Extension of [`UnsafeNoRefIndex`] for collections that also allow access to their elements
+/// through pointers.
+///
+/// All of its methods are built on [`PointerIndex`], and the trait is implemented for every
+/// collection that implements both [`UnsafeNoRefIndex`] and [`PointerIndex`].
+///
+/// # Examples
+///
+/// ```
+/// # use par_slice::*;
+/// let collection = vec![0; 5].into_par_index_no_ref();
+///
+/// // This is single threaded so no data races can happen
+/// unsafe { collection.set_value_volatile(1, 42) };
+///
+/// assert_eq!(unsafe { collection.get_value_volatile(1) }, 42);
+/// ```
+pub trait UnsafeNoRefPointerIndex<T>: UnsafeNoRefIndex<T> + PointerIndex<T> {
+    /// Returns a bitwise copy of the element identified by `index` in the collection, using a volatile read.
+    ///
+    /// Volatile accesses are never elided or reordered with respect to other volatile accesses by the compiler
+    /// (see [`read_volatile`](`std::ptr::read_volatile`)), which is what memory-mapped IO requires.
+    /// They are **not** a synchronization primitive: they do not prevent data races and are not a substitute
+    /// for atomics.
+    ///
+    /// This method performs bounds checking on `index` to ensure its validity.
+    /// If you can guarantee its validity, you may want to use the
+    /// [`get_value_volatile_unchecked`](`Self::get_value_volatile_unchecked`) method instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of the collection.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also writing to the same element from another thread is undefined behavior
+    /// (parallel reads are ok).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 5].into_par_index_no_ref();
+    /// // This is single threaded so no data races can happen
+    /// assert_eq!(unsafe { collection.get_value_volatile(0) }, 0);
+    /// ```
+    #[must_use]
+    #[inline]
+    unsafe fn get_value_volatile(&self, index: usize) -> T
+    where
+        T: Copy,
+    {
+        assert_in_bounds(self.len(), index);
+        unsafe {
+            // Safety: we just checked that index is in bounds
+            self.get_value_volatile_unchecked(index)
+        }
+    }
+
+    /// Returns a bitwise copy of the element identified by `index` in the collection, using a volatile read
+    /// and without performing bounds checking.
+    ///
+    /// See [`get_value_volatile`](`Self::get_value_volatile`) for the semantics of volatile accesses.
+    /// This method does not perform bounds checking on `index` to ensure its validity.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also writing to the same element from another thread is undefined behavior
+    /// (parallel reads are ok).
+    /// Calling this method with an index `i` that would panic [`get_value_volatile`](`Self::get_value_volatile`)
+    /// is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 5].into_par_index_no_ref();
+    /// // We know 0 is a valid index for a collection of length 5
+    /// // and this is single threaded so no data races can happen
+    /// assert_eq!(unsafe { collection.get_value_volatile_unchecked(0) }, 0);
+    /// ```
+    #[must_use]
+    #[inline]
+    unsafe fn get_value_volatile_unchecked(&self, index: usize) -> T
+    where
+        T: Copy,
+    {
+        unsafe {
+            // Safety: the caller guarantees that there are no data races and that
+            // index is valid
+            self.get_ptr_unchecked(index).read_volatile()
+        }
+    }
+
+    /// Sets the element identified by `index` in the collection to `value`, using a volatile write.
+    ///
+    /// Volatile accesses are never elided or reordered with respect to other volatile accesses by the compiler
+    /// (see [`write_volatile`](`std::ptr::write_volatile`)), which is what memory-mapped IO requires.
+    /// They are **not** a synchronization primitive: they do not prevent data races and are not a substitute
+    /// for atomics.
+    /// As with [`write_volatile`](`std::ptr::write_volatile`), the previous value of the element is not dropped.
+    ///
+    /// This method performs bounds checking on `index` to ensure its validity.
+    /// If you can guarantee its validity, you may want to use the
+    /// [`set_value_volatile_unchecked`](`Self::set_value_volatile_unchecked`) method instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of the collection.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also writing or reading the same element from another thread
+    /// is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 5].into_par_index_no_ref();
+    ///
+    /// // This is single threaded so no data races can happen
+    /// unsafe { collection.set_value_volatile(0, 42) };
+    ///
+    /// assert_eq!(collection.into(), vec![42, 0, 0, 0, 0]);
+    /// ```
+    #[inline]
+    unsafe fn set_value_volatile(&self, index: usize, value: T) {
+        assert_in_bounds(self.len(), index);
+        unsafe {
+            // Safety: we just checked that index is in bounds
+            self.set_value_volatile_unchecked(index, value);
+        }
+    }
+
+    /// Sets the element identified by `index` in the collection to `value`, using a volatile write
+    /// and without performing bounds checking.
+    ///
+    /// See [`set_value_volatile`](`Self::set_value_volatile`) for the semantics of volatile accesses.
+    /// This method does not perform bounds checking on `index` to ensure its validity.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also writing or reading the same element from another thread
+    /// is undefined behavior.
+    /// Calling this method with an index `i` that would panic [`set_value_volatile`](`Self::set_value_volatile`)
+    /// is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 5].into_par_index_no_ref();
+    ///
+    /// // We know 0 is a valid index for a collection of length 5
+    /// // and this is single threaded so no data races can happen
+    /// unsafe { collection.set_value_volatile_unchecked(0, 42) };
+    ///
+    /// assert_eq!(collection.into(), vec![42, 0, 0, 0, 0]);
+    /// ```
+    #[inline]
+    unsafe fn set_value_volatile_unchecked(&self, index: usize, value: T) {
+        unsafe {
+            // Safety: the caller guarantees that there are no data races and that
+            // index is valid
+            self.get_mut_ptr_unchecked(index).write_volatile(value)
+        }
+    }
+
+    /// Moves the element identified by `index` out of the collection, leaving
+    /// [`T::default`](`Default::default`) in its place.
+    ///
+    /// This is useful to move non-[`Copy`] elements out of the collection in a parallel consume phase.
+    /// This method performs bounds checking on `index` to ensure its validity.
+    /// If you can guarantee its validity, you may want to use the [`take_unchecked`](`Self::take_unchecked`)
+    /// method instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of the collection.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also writing or reading the same element from another thread
+    /// is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![String::from("a"), String::from("b")].into_par_index_no_ref();
+    ///
+    /// // This is single threaded so no data races can happen
+    /// assert_eq!(unsafe { collection.take(1) }, "b");
+    ///
+    /// assert_eq!(collection.into(), vec!["a", ""]);
+    /// ```
+    #[must_use]
+    #[inline]
+    unsafe fn take(&self, index: usize) -> T
+    where
+        T: Default,
+    {
+        assert_in_bounds(self.len(), index);
+        unsafe {
+            // Safety: we just checked that index is in bounds
+            self.take_unchecked(index)
+        }
+    }
+
+    /// Moves the element identified by `index` out of the collection, leaving
+    /// [`T::default`](`Default::default`) in its place, without performing bounds checking.
+    ///
+    /// This method does not perform bounds checking on `index` to ensure its validity.
+    /// If you can't guarantee its validity, you may want to use the [`take`](`Self::take`) method instead.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also writing or reading the same element from another thread
+    /// is undefined behavior.
+    /// Calling this method with an index `i` that would panic [`take`](`Self::take`) is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![vec![1, 2], vec![3]].into_par_index_no_ref();
+    ///
+    /// // We know 0 is a valid index for a collection of length 2
+    /// // and this is single threaded so no data races can happen
+    /// assert_eq!(unsafe { collection.take_unchecked(0) }, vec![1, 2]);
+    ///
+    /// assert_eq!(collection.into(), vec![vec![], vec![3]]);
+    /// ```
+    #[must_use]
+    #[inline]
+    unsafe fn take_unchecked(&self, index: usize) -> T
+    where
+        T: Default,
+    {
+        unsafe {
+            // Safety: the caller guarantees that there are no data races and that
+            // index is valid
+            std::mem::take(&mut *self.get_mut_ptr_unchecked(index))
+        }
+    }
+
+    /// Writes `value` to the element identified by `index` in the collection, without reading
+    /// or dropping the element it overwrites.
+    ///
+    /// Unlike [`set_value`](`UnsafeNoRefIndex::set_value`), which drops the old element, this is the correct way
+    /// to fill a slot that is logically uninitialized (for example after its element was moved out).
+    /// Calling this method on an initialized slot of a type with a destructor leaks the old element.
+    /// This method performs bounds checking on `index` to ensure its validity.
+    /// If you can guarantee its validity, you may want to use the [`write_unchecked`](`Self::write_unchecked`)
     /// method instead.
     ///
     /// # Panics
@@ -210,36 +671,35 @@ pub unsafe trait UnsafeNoRefIndex<T: ?Sized>: TrustedSizedCollection {
     ///
     /// ```
     /// # use par_slice::*;
-    /// let collection = vec![0; 5].into_par_index_no_ref();
+    /// let collection = vec![String::new(); 2].into_par_index_no_ref();
     ///
-    /// // This is single threaded so no data races can happen
-    /// unsafe { collection.set_value(0, 42) };
+    /// // This is single threaded so no data races can happen.
+    /// // An empty string owns no memory, so not dropping it leaks nothing
+    /// unsafe { collection.write(1, String::from("b")) };
     ///
-    /// assert_eq!(collection.into(), vec![42, 0, 0, 0, 0]);
+    /// assert_eq!(collection.into(), vec!["", "b"]);
     /// ```
     #[inline]
-    unsafe fn set_value(&self, index: usize, value: T)
-    where
-        T: Sized,
-    {
+    unsafe fn write(&self, index: usize, value: T) {
         assert_in_bounds(self.len(), index);
         unsafe {
             // Safety: we just checked that index is in bounds
-            self.set_value_unchecked(index, value);
+            self.write_unchecked(index, value);
         }
     }
 
-    /// Sets the element identified by `index` in the collection to `value`, without performing
-    /// bounds checking.
+    /// Writes `value` to the element identified by `index` in the collection, without reading
+    /// or dropping the element it overwrites and without performing bounds checking.
     ///
+    /// See [`write`](`Self::write`) for the difference with [`set_value_unchecked`](`UnsafeNoRefIndex::set_value_unchecked`).
     /// This method does not perform bounds checking on `index` to ensure its validity.
-    /// If you can't guarantee its validity, you may want to use the [`set_value`](`Self::set_value`) method instead.
+    /// If you can't guarantee its validity, you may want to use the [`write`](`Self::write`) method instead.
     ///
     /// # Safety
     ///
     /// Calling this method while also writing or reading the same element from another thread
     /// is undefined behavior.
-    /// Calling this method with an index `i` that would panic [`set_value`](`Self::set_value`) is undefined behavior.
+    /// Calling this method with an index `i` that would panic [`write`](`Self::write`) is undefined behavior.
     ///
     /// # Examples
     ///
@@ -249,15 +709,22 @@ pub unsafe trait UnsafeNoRefIndex<T: ?Sized>: TrustedSizedCollection {
     ///
     /// // We know 0 is a valid index for a collection of length 5
     /// // and this is single threaded so no data races can happen
-    /// unsafe { collection.set_value_unchecked(0, 42) };
+    /// unsafe { collection.write_unchecked(0, 42) };
     ///
     /// assert_eq!(collection.into(), vec![42, 0, 0, 0, 0]);
     /// ```
-    unsafe fn set_value_unchecked(&self, index: usize, value: T)
-    where
-        T: Sized;
+    #[inline]
+    unsafe fn write_unchecked(&self, index: usize, value: T) {
+        unsafe {
+            // Safety: the caller guarantees that there are no data races and that
+            // index is valid
+            self.get_mut_ptr_unchecked(index).write(value)
+        }
+    }
 }
 
+impl<T, C: UnsafeNoRefIndex<T> + PointerIndex<T> + ?Sized> UnsafeNoRefPointerIndex<T> for C {}
+
 /// Unsynchronized access to chunks of elements of a collection through setters and getters without
 /// crating references to its elements.
 ///
@@ -537,4 +1004,344 @@ pub unsafe trait UnsafeNoRefChunkIndex<T>: TrustedChunkSizedCollection {
     unsafe fn set_values_unchecked(&self, index: usize, values: &[T])
     where
         T: Clone;
+
+    /// Calls `f` in parallel on each chunk index of the collection using [`rayon`]'s global thread pool.
+    ///
+    /// This method is only available with the `rayon` feature.
+    ///
+    /// Each chunk index is passed to `f` exactly once, but the order and the threads
+    /// in which `f` is called are unspecified.
+    /// While this method is safe, `f` must only access the chunk identified by its
+    /// index in order to avoid data races (see [`get_values`](`Self::get_values`) and
+    /// [`set_values`](`Self::set_values`)).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 6].into_par_chunk_index_no_ref(2);
+    ///
+    /// collection.for_each_chunk(|i| unsafe {
+    ///     // Each index is processed exactly once so there are no data races
+    ///     collection.set_values(i, &[i, i]);
+    /// });
+    ///
+    /// assert_eq!(collection.into(), vec![0, 0, 1, 1, 2, 2]);
+    /// ```
+    #[cfg(feature = "rayon")]
+    #[inline]
+    fn for_each_chunk(&self, f: impl Fn(usize) + Sync) {
+        use rayon::prelude::*;
+
+        (0..self.num_chunks()).into_par_iter().for_each(&f);
+    }
+}
+
+/// Extension of [`UnsafeNoRefChunkIndex`] for collections that also allow access to their chunks
+/// through pointers.
+///
+/// All of its methods are built on [`PointerChunkIndex`], and the trait is implemented for every
+/// collection that implements both [`UnsafeNoRefChunkIndex`] and [`PointerChunkIndex`].
+///
+/// # Examples
+///
+/// ```
+/// # use par_slice::*;
+/// let collection = vec![0; 4].into_par_chunk_index_no_ref(2);
+///
+/// // This is single threaded so no data races can happen
+/// unsafe { collection.set_chunk_element(1, 1, 42) };
+///
+/// assert_eq!(unsafe { collection.get_chunk_element(1, 1) }, 42);
+/// ```
+pub trait UnsafeNoRefPointerChunkIndex<T>: UnsafeNoRefChunkIndex<T> + PointerChunkIndex<T> {
+    /// Returns a bitwise copy of the element at position `offset` inside the chunk identified by `chunk`
+    /// in the collection, without copying the rest of the chunk.
+    ///
+    /// This method performs bounds checking on `chunk` and `offset` to ensure their validity.
+    /// If you can guarantee their validity, you may want to use the
+    /// [`get_chunk_element_unchecked`](`Self::get_chunk_element_unchecked`) method instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk` is out of bounds of the collection or if `offset` is out of bounds of the chunk.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also writing to the same element from another thread is undefined behavior
+    /// (parallel reads are ok).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0, 1, 2, 3].into_par_chunk_index_no_ref(2);
+    ///
+    /// // This is single threaded so no data races can happen
+    /// assert_eq!(unsafe { collection.get_chunk_element(1, 1) }, 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    unsafe fn get_chunk_element(&self, chunk: usize, offset: usize) -> T
+    where
+        T: Copy,
+    {
+        assert_in_bounds(self.num_chunks(), chunk);
+        assert_in_bounds(self.chunk_size(), offset);
+        unsafe {
+            // Safety: we just checked that chunk and offset are in bounds
+            self.get_chunk_element_unchecked(chunk, offset)
+        }
+    }
+
+    /// Returns a bitwise copy of the element at position `offset` inside the chunk identified by `chunk`
+    /// in the collection, without performing bounds checking.
+    ///
+    /// This method does not perform bounds checking on `chunk` and `offset` to ensure their validity.
+    /// If you can't guarantee their validity, you may want to use the
+    /// [`get_chunk_element`](`Self::get_chunk_element`) method instead.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also writing to the same element from another thread is undefined behavior
+    /// (parallel reads are ok).
+    /// Calling this method with a `chunk` or an `offset` that would panic
+    /// [`get_chunk_element`](`Self::get_chunk_element`) is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0, 1, 2, 3].into_par_chunk_index_no_ref(2);
+    ///
+    /// // We know chunk 1 and offset 0 are valid for a collection of 2 chunks of size 2
+    /// // and this is single threaded so no data races can happen
+    /// assert_eq!(unsafe { collection.get_chunk_element_unchecked(1, 0) }, 2);
+    /// ```
+    #[must_use]
+    #[inline]
+    unsafe fn get_chunk_element_unchecked(&self, chunk: usize, offset: usize) -> T
+    where
+        T: Copy,
+    {
+        unsafe {
+            // Safety: the caller guarantees that chunk and offset are in bounds and
+            // that there are no data races
+            *self.get_mut_element_ptr_unchecked(chunk, offset)
+        }
+    }
+
+    /// Sets the element at position `offset` inside the chunk identified by `chunk` in the collection
+    /// to `value`, without writing the rest of the chunk.
+    ///
+    /// This method performs bounds checking on `chunk` and `offset` to ensure their validity.
+    /// If you can guarantee their validity, you may want to use the
+    /// [`set_chunk_element_unchecked`](`Self::set_chunk_element_unchecked`) method instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk` is out of bounds of the collection or if `offset` is out of bounds of the chunk.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also reading or writing the same element from another thread is
+    /// undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 4].into_par_chunk_index_no_ref(2);
+    ///
+    /// // This is single threaded so no data races can happen
+    /// unsafe { collection.set_chunk_element(1, 0, 42) };
+    ///
+    /// assert_eq!(collection.into(), vec![0, 0, 42, 0]);
+    /// ```
+    #[inline]
+    unsafe fn set_chunk_element(&self, chunk: usize, offset: usize, value: T) {
+        assert_in_bounds(self.num_chunks(), chunk);
+        assert_in_bounds(self.chunk_size(), offset);
+        unsafe {
+            // Safety: we just checked that chunk and offset are in bounds
+            self.set_chunk_element_unchecked(chunk, offset, value);
+        }
+    }
+
+    /// Sets the element at position `offset` inside the chunk identified by `chunk` in the collection
+    /// to `value`, without performing bounds checking.
+    ///
+    /// This method does not perform bounds checking on `chunk` and `offset` to ensure their validity.
+    /// If you can't guarantee their validity, you may want to use the
+    /// [`set_chunk_element`](`Self::set_chunk_element`) method instead.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also reading or writing the same element from another thread is
+    /// undefined behavior.
+    /// Calling this method with a `chunk` or an `offset` that would panic
+    /// [`set_chunk_element`](`Self::set_chunk_element`) is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 4].into_par_chunk_index_no_ref(2);
+    ///
+    /// // We know chunk 0 and offset 1 are valid for a collection of 2 chunks of size 2
+    /// // and this is single threaded so no data races can happen
+    /// unsafe { collection.set_chunk_element_unchecked(0, 1, 42) };
+    ///
+    /// assert_eq!(collection.into(), vec![0, 42, 0, 0]);
+    /// ```
+    #[inline]
+    unsafe fn set_chunk_element_unchecked(&self, chunk: usize, offset: usize, value: T) {
+        unsafe {
+            // Safety: the caller guarantees that chunk and offset are in bounds and
+            // that there are no data races
+            *self.get_mut_element_ptr_unchecked(chunk, offset) = value
+        }
+    }
+
+    /// Initializes `out` with a bitwise copy of the chunk of elements identified by `index` in the collection
+    /// and returns it as an initialized slice.
+    ///
+    /// This is equivalent to [`get_values`](`UnsafeNoRefChunkIndex::get_values`), but it does not require the output buffer
+    /// to be initialized beforehand.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of the collection or if `out.len() != self.chunk_size()`.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also writing to the same chunk from another thread is undefined behavior
+    /// (parallel reads are ok).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// # use std::mem::MaybeUninit;
+    /// let collection = vec![0, 1, 2, 3].into_par_chunk_index_no_ref(2);
+    /// let mut buf = [MaybeUninit::uninit(); 2];
+    ///
+    /// // This is single threaded so no data races can happen
+    /// let values = unsafe { collection.get_values_uninit(1, &mut buf) };
+    ///
+    /// assert_eq!(values, &[2, 3]);
+    /// ```
+    #[inline]
+    unsafe fn get_values_uninit<'o>(
+        &self,
+        index: usize,
+        out: &'o mut [MaybeUninit<T>],
+    ) -> &'o mut [T]
+    where
+        T: Copy,
+    {
+        assert_in_bounds(self.len(), index);
+        assert_chunk_compatible(self.chunk_size(), out);
+        unsafe {
+            // Safety: we just checked that index is in bounds and that out has room for
+            // the whole chunk, and the caller guarantees that there are no data races
+            ptr::copy_nonoverlapping(
+                self.get_ptr_unchecked(index) as *const T,
+                out.as_mut_ptr().cast::<T>(),
+                out.len(),
+            );
+            // Safety: all the elements of out were just initialized and MaybeUninit<T>
+            // has the same layout as T
+            &mut *(out as *mut [MaybeUninit<T>] as *mut [T])
+        }
+    }
+
+    /// Returns a newly allocated [`Vec`] containing a bitwise copy of the chunk of elements identified by `index`
+    /// in the collection.
+    ///
+    /// This method performs bounds checking on `index` to ensure its validity.
+    /// If you can guarantee its validity, you may want to use the [`get_values_vec_unchecked`](`Self::get_values_vec_unchecked`)
+    /// method instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of the collection.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also writing to the same chunk from another thread is undefined behavior
+    /// (parallel reads are ok).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0, 1, 2, 3].into_par_chunk_index_no_ref(2);
+    ///
+    /// // This is single threaded so no data races can happen
+    /// assert_eq!(unsafe { collection.get_values_vec(1) }, vec![2, 3]);
+    /// ```
+    #[must_use]
+    #[inline]
+    unsafe fn get_values_vec(&self, index: usize) -> Vec<T>
+    where
+        T: Copy,
+    {
+        assert_in_bounds(self.len(), index);
+        unsafe {
+            // Safety: we just checked that index is in bounds
+            self.get_values_vec_unchecked(index)
+        }
+    }
+
+    /// Returns a newly allocated [`Vec`] containing a bitwise copy of the chunk of elements identified by `index`
+    /// in the collection, without performing bounds checking.
+    ///
+    /// This method does not perform bounds checking on `index` to ensure its validity.
+    /// If you can't guarantee its validity, you may want to use the [`get_values_vec`](`Self::get_values_vec`)
+    /// method instead.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while also writing to the same chunk from another thread is undefined behavior
+    /// (parallel reads are ok).
+    /// Calling this method with an index `i` that would panic [`get_values_vec`](`Self::get_values_vec`)
+    /// is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0, 1, 2, 3].into_par_chunk_index_no_ref(2);
+    ///
+    /// // We know 0 is a valid index for a collection of length 2
+    /// // and this is single threaded so no data races can happen
+    /// assert_eq!(unsafe { collection.get_values_vec_unchecked(0) }, vec![0, 1]);
+    /// ```
+    #[must_use]
+    #[inline]
+    unsafe fn get_values_vec_unchecked(&self, index: usize) -> Vec<T>
+    where
+        T: Copy,
+    {
+        let chunk_size = self.chunk_size();
+        let mut out = Vec::with_capacity(chunk_size);
+        unsafe {
+            // Safety: the caller guarantees that index is valid and that there are no data races,
+            // and the buffer has capacity for chunk_size elements
+            ptr::copy_nonoverlapping(
+                self.get_ptr_unchecked(index) as *const T,
+                out.as_mut_ptr(),
+                chunk_size,
+            );
+            // Safety: all chunk_size elements were just initialized
+            out.set_len(chunk_size);
+        }
+        out
+    }
+}
+
+impl<T, C: UnsafeNoRefChunkIndex<T> + PointerChunkIndex<T> + ?Sized> UnsafeNoRefPointerChunkIndex<T>
+    for C
+{
 }
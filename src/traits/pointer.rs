@@ -1,4 +1,5 @@
 use crate::*;
+use std::{mem::size_of, ptr::NonNull};
 
 /// Unsynchronized access to elements of a collection through pointers.
 ///
@@ -129,6 +130,7 @@ pub unsafe trait PointerIndex<T: ?Sized>: TrustedSizedCollection {
     /// let ptr_0: *const usize = unsafe { collection.get_ptr_unchecked(0) };
     /// assert_eq!(unsafe {*ptr_0}, 0);
     /// ```
+    #[must_use]
     unsafe fn get_ptr_unchecked(&self, index: usize) -> *const T;
 
     /// Returns a mutable pointer to the element identified by `index` in the collection, without performing
@@ -152,6 +154,7 @@ pub unsafe trait PointerIndex<T: ?Sized>: TrustedSizedCollection {
     /// unsafe { *ptr_0 = 42 };
     /// assert_eq!(unsafe { *collection.get_ptr(0) }, 42);
     /// ```
+    #[must_use]
     unsafe fn get_mut_ptr_unchecked(&self, index: usize) -> *mut T;
 
     /// Returns an immutable pointer to the element identified by `index` in the collection.
@@ -173,6 +176,7 @@ pub unsafe trait PointerIndex<T: ?Sized>: TrustedSizedCollection {
     /// // No other reference exists so we may dereference ptr_0 safely
     /// assert_eq!(unsafe { *ptr_0 }, 0);
     /// ```
+    #[must_use]
     #[inline]
     fn get_ptr(&self, index: usize) -> *const T {
         assert_in_bounds(self.len(), index);
@@ -202,6 +206,17 @@ pub unsafe trait PointerIndex<T: ?Sized>: TrustedSizedCollection {
     /// unsafe { *ptr_0 = 42 };
     /// assert_eq!(unsafe { *collection.get_ptr(0) }, 42);
     /// ```
+    ///
+    /// Discarding the returned pointer is almost certainly a bug (*e.g.* a forgotten dereference),
+    /// so it is reported by the `unused_must_use` lint:
+    ///
+    /// ```compile_fail
+    /// #![deny(unused_must_use)]
+    /// # use par_slice::*;
+    /// let collection = vec![0; 5].into_pointer_par_index();
+    /// collection.get_mut_ptr(0);
+    /// ```
+    #[must_use]
     #[inline]
     fn get_mut_ptr(&self, index: usize) -> *mut T {
         assert_in_bounds(self.len(), index);
@@ -210,9 +225,310 @@ pub unsafe trait PointerIndex<T: ?Sized>: TrustedSizedCollection {
             self.get_mut_ptr_unchecked(index)
         }
     }
+
+    /// Returns a non-null mutable pointer to the element identified by `index` in the collection, without performing
+    /// bounds checking.
+    ///
+    /// This is equivalent to [`get_mut_ptr_unchecked`](`Self::get_mut_ptr_unchecked`), but the returned pointer
+    /// keeps the non-null guarantee in its type, which makes it convenient to store.
+    /// If you can't guarantee the validity of `index`, you may want to use the [`get_non_null`](`Self::get_non_null`)
+    /// method instead.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with an index `i` that would panic [`get_non_null`](`Self::get_non_null`) is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// # use std::ptr::NonNull;
+    /// let collection = vec![0; 5].into_pointer_par_index();
+    /// // We know 0 is a valid index for a collection of length 5
+    /// let ptr_0: NonNull<usize> = unsafe { collection.get_non_null_unchecked(0) };
+    /// // No other reference exists so we may dereference ptr_0 safely
+    /// unsafe { *ptr_0.as_ptr() = 42 };
+    /// assert_eq!(unsafe { *collection.get_ptr(0) }, 42);
+    /// ```
+    #[must_use]
+    #[inline]
+    unsafe fn get_non_null_unchecked(&self, index: usize) -> NonNull<T> {
+        unsafe {
+            // Safety: the caller guarantees that index is in bounds, so the pointer
+            // points inside an allocated object and can't be null
+            NonNull::new_unchecked(self.get_mut_ptr_unchecked(index))
+        }
+    }
+
+    /// Returns a non-null mutable pointer to the element identified by `index` in the collection.
+    ///
+    /// This is equivalent to [`get_mut_ptr`](`Self::get_mut_ptr`), but the returned pointer
+    /// keeps the non-null guarantee in its type, which makes it convenient to store.
+    /// If you can guarantee the validity of `index`, you may want to use the
+    /// [`get_non_null_unchecked`](`Self::get_non_null_unchecked`) method instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// # use std::ptr::NonNull;
+    /// let collection = vec![0; 5].into_pointer_par_index();
+    /// let ptr_0: NonNull<usize> = collection.get_non_null(0);
+    /// // No other reference exists so we may dereference ptr_0 safely
+    /// unsafe { *ptr_0.as_ptr() = 42 };
+    /// assert_eq!(unsafe { *collection.get_ptr(0) }, 42);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn get_non_null(&self, index: usize) -> NonNull<T> {
+        assert_in_bounds(self.len(), index);
+        unsafe {
+            // Safety: we just checked that index is in bounds
+            self.get_non_null_unchecked(index)
+        }
+    }
+
+    /// Returns a dangling, well-aligned and non-null mutable pointer suitable for zero-length operations.
+    ///
+    /// Since an empty collection has no valid index, [`get_mut_ptr`](`Self::get_mut_ptr`) always
+    /// panics on it. The pointer returned by this method can instead be passed along with a
+    /// length of `0` to APIs (such as [`std::slice::from_raw_parts_mut`] or FFI functions) that
+    /// expect a valid base pointer even when no element is accessed.
+    ///
+    /// The returned pointer must never be dereferenced and it does not point inside the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = Vec::<u64>::new().into_pointer_par_index();
+    ///
+    /// let ptr = collection.dangling_ptr();
+    /// assert!(!ptr.is_null());
+    /// assert!(ptr.is_aligned());
+    ///
+    /// let empty: &mut [u64] = unsafe { std::slice::from_raw_parts_mut(ptr, 0) };
+    /// assert!(empty.is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    fn dangling_ptr(&self) -> *mut T
+    where
+        T: Sized,
+    {
+        NonNull::dangling().as_ptr()
+    }
+
+    /// Returns a mutable pointer to the first element of the collection and a [`SubView`] over
+    /// the rest of its elements, or [`None`] if the collection is empty.
+    ///
+    /// Index `i` of the returned view refers to index `i + 1` of the collection.
+    /// This mirrors [`slice::split_first_mut`] and is useful for recursive algorithms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![1, 2, 3].into_pointer_par_index();
+    ///
+    /// let (head, tail) = collection.split_first().unwrap();
+    /// assert_eq!(head, collection.get_mut_ptr(0));
+    /// assert_eq!(tail.get_mut_ptr(0), collection.get_mut_ptr(1));
+    ///
+    /// // The view can be split again
+    /// let (_, tail) = tail.split_first().unwrap();
+    /// assert_eq!(unsafe { *tail.get_ptr(0) }, 3);
+    /// ```
+    #[inline]
+    fn split_first(&self) -> Option<(*mut T, SubView<'_, T>)>
+    where
+        Self: Sized + Sync,
+    {
+        if self.is_empty() {
+            return None;
+        }
+
+        let head = unsafe {
+            // Safety: the collection is not empty
+            self.get_mut_ptr_unchecked(0)
+        };
+        Some((head, SubView::new(self, 1, self.len() - 1)))
+    }
+
+    /// Returns a mutable pointer to the last element of the collection and a [`SubView`] over
+    /// the rest of its elements, or [`None`] if the collection is empty.
+    ///
+    /// Index `i` of the returned view refers to index `i` of the collection.
+    /// This mirrors [`slice::split_last_mut`] and is useful for recursive algorithms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![1, 2, 3].into_pointer_par_index();
+    ///
+    /// let (last, init) = collection.split_last().unwrap();
+    /// assert_eq!(last, collection.get_mut_ptr(2));
+    /// assert_eq!(init.len(), 2);
+    /// assert_eq!(init.get_mut_ptr(1), collection.get_mut_ptr(1));
+    /// ```
+    #[inline]
+    fn split_last(&self) -> Option<(*mut T, SubView<'_, T>)>
+    where
+        Self: Sized + Sync,
+    {
+        if self.is_empty() {
+            return None;
+        }
+
+        let last = unsafe {
+            // Safety: the collection is not empty
+            self.get_mut_ptr_unchecked(self.len() - 1)
+        };
+        Some((last, SubView::new(self, 0, self.len() - 1)))
+    }
+
+    /// Returns a view over the collection that reinterprets its elements of type `T`
+    /// as elements of type `U`.
+    ///
+    /// Index `i` of the view refers to the same memory as index `i` of the collection.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that:
+    /// * `size_of::<T>() == size_of::<U>()` and `align_of::<T>() >= align_of::<U>()`
+    ///   (this is checked in debug builds only).
+    /// * Every bit pattern of every element of the collection that is read through the view
+    ///   is a valid `U` and every `U` written through the view is a valid `T`, exactly as if
+    ///   the elements were [`transmute`](`std::mem::transmute`)d.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![u32::MAX, 1].into_pointer_par_index();
+    /// let view = unsafe {
+    ///     // u32 and i32 have the same size and alignment and every
+    ///     // bit pattern is valid for both
+    ///     collection.cast::<i32>()
+    /// };
+    /// assert_eq!(unsafe { *view.get_ptr(0) }, -1);
+    /// unsafe { *view.get_mut_ptr(1) = -2 };
+    /// drop(view);
+    /// assert_eq!(collection.into(), vec![u32::MAX, u32::MAX - 1]);
+    /// ```
+    #[inline]
+    unsafe fn cast<U>(&self) -> impl PointerIndex<U> + ParView<U>
+    where
+        Self: Sized + Sync,
+        T: Sized,
+    {
+        CastView::new::<U>(self)
+    }
+
+    /// Returns the offset in bytes of the element identified by `index` from the first element
+    /// of the collection, *i.e.* `index * size_of::<T>()`.
+    ///
+    /// This is useful to address buffers that mirror the layout of the collection (*e.g.* a mapped
+    /// GPU buffer) without hardcoding the size of the elements.
+    /// This method performs bounds checking on `index` to ensure its validity.
+    /// If you can guarantee its validity, you may want to use the
+    /// [`byte_offset_of_unchecked`](`Self::byte_offset_of_unchecked`) method instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0_u64; 5].into_pointer_par_index();
+    /// assert_eq!(collection.byte_offset_of(3), 24);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn byte_offset_of(&self, index: usize) -> usize
+    where
+        T: Sized,
+    {
+        assert_in_bounds(self.len(), index);
+        self.byte_offset_of_unchecked(index)
+    }
+
+    /// Returns the offset in bytes of the element identified by `index` from the first element
+    /// of the collection, *i.e.* `index * size_of::<T>()`, without performing bounds checking.
+    ///
+    /// Computing the offset is always safe, but the result is meaningless if `index` is out of bounds.
+    /// For a checked alternative see [`byte_offset_of`](`Self::byte_offset_of`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0_u16; 5].into_pointer_par_index();
+    /// assert_eq!(collection.byte_offset_of_unchecked(4), 8);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn byte_offset_of_unchecked(&self, index: usize) -> usize
+    where
+        T: Sized,
+    {
+        #[cfg(feature = "checked")]
+        assert_in_bounds(self.len(), index);
+        debug_assert!(index < self.len());
+
+        index * size_of::<T>()
+    }
+
+    /// Returns the index of the element of the collection pointed to by `ptr`, or [`None`] if `ptr`
+    /// does not point to the start of an element of the collection.
+    ///
+    /// This is the inverse of [`get_ptr`](`Self::get_ptr`) and [`get_mut_ptr`](`Self::get_mut_ptr`)
+    /// and is mainly meant for debugging assertions.
+    /// For zero-sized types every element has the same address, so the index of the first element
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0_u32; 5].into_pointer_par_index();
+    /// let ptr = collection.get_mut_ptr(3);
+    ///
+    /// assert_eq!(collection.index_of_ptr(ptr), Some(3));
+    /// assert_eq!(collection.index_of_ptr(&42), None);
+    /// ```
+    #[inline]
+    fn index_of_ptr(&self, ptr: *const T) -> Option<usize>
+    where
+        T: Sized,
+    {
+        if self.is_empty() {
+            return None;
+        }
+
+        let base = unsafe {
+            // Safety: the collection is not empty
+            self.get_ptr_unchecked(0)
+        };
+        let index = element_offset(base, ptr)?;
+        // The collection is not guaranteed to be contiguous, so the index must be confirmed
+        (index < self.len()
+            && unsafe {
+                // Safety: we just checked that index is in bounds
+                self.get_ptr_unchecked(index)
+            } == ptr)
+            .then_some(index)
+    }
 }
 
-/// Marker trait for collections that allow unsynchronized access to non-overlapping chunks of their elements through pointers.
+/// Unsynchronized access to non-overlapping chunks of elements of a collection through pointers.
 ///
 /// The trait allows *unsynchronized* access to chunks of elements of a collection by
 /// allowing the creation of *mutable pointers* to chunks of elements from a *shared reference* to the
@@ -234,4 +550,283 @@ pub unsafe trait PointerIndex<T: ?Sized>: TrustedSizedCollection {
 pub unsafe trait PointerChunkIndex<T>:
     PointerIndex<[T]> + TrustedChunkSizedCollection
 {
+    /// Returns a mutable pointer to the element at position `offset` inside the chunk identified by `chunk`
+    /// in the collection, without performing bounds checking.
+    ///
+    /// This method does not perform bounds checking on `chunk` and `offset` to ensure their validity.
+    /// If you can't guarantee their validity, you may want to use the
+    /// [`get_mut_element_ptr`](`Self::get_mut_element_ptr`) method instead.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with a `chunk` or an `offset` that would panic
+    /// [`get_mut_element_ptr`](`Self::get_mut_element_ptr`) is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 6].into_pointer_par_chunk_index(2);
+    /// // We know chunk 1 and offset 1 are valid for a collection of 3 chunks of size 2
+    /// let ptr: *mut usize = unsafe { collection.get_mut_element_ptr_unchecked(1, 1) };
+    /// // No other reference exists so we may dereference ptr safely
+    /// unsafe { *ptr = 42 };
+    /// assert_eq!(collection.into(), vec![0, 0, 0, 42, 0, 0]);
+    /// ```
+    #[must_use]
+    #[inline]
+    unsafe fn get_mut_element_ptr_unchecked(&self, chunk: usize, offset: usize) -> *mut T {
+        #[cfg(feature = "checked")]
+        assert_in_bounds(self.chunk_size(), offset);
+        debug_assert!(offset < self.chunk_size());
+        unsafe {
+            // Safety: the caller guarantees that chunk and offset are in bounds
+            (self.get_mut_ptr_unchecked(chunk) as *mut T).add(offset)
+        }
+    }
+
+    /// Returns a mutable pointer to the element at position `offset` inside the chunk identified by `chunk`
+    /// in the collection.
+    ///
+    /// This method performs bounds checking on `chunk` and `offset` to ensure their validity.
+    /// If you can guarantee their validity, you may want to use the
+    /// [`get_mut_element_ptr_unchecked`](`Self::get_mut_element_ptr_unchecked`) method instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk` is out of bounds of the collection or if `offset` is out of bounds of the chunk.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 6].into_pointer_par_chunk_index(2);
+    /// let ptr: *mut usize = collection.get_mut_element_ptr(1, 1);
+    /// // No other reference exists so we may dereference ptr safely
+    /// unsafe { *ptr = 42 };
+    /// assert_eq!(collection.into(), vec![0, 0, 0, 42, 0, 0]);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn get_mut_element_ptr(&self, chunk: usize, offset: usize) -> *mut T {
+        assert_in_bounds(self.num_chunks(), chunk);
+        assert_in_bounds(self.chunk_size(), offset);
+        unsafe {
+            // Safety: we just checked that chunk and offset are in bounds
+            self.get_mut_element_ptr_unchecked(chunk, offset)
+        }
+    }
+}
+
+/// Extension of [`PointerChunkIndex`] for collections whose chunks are stored one after
+/// the other in contiguous memory.
+///
+/// This allows to treat the whole collection as a single contiguous region and to walk its
+/// chunks by advancing a pointer.
+///
+/// # Safety
+///
+/// Implementors of this trait must guarantee, in addition to the invariants of [`PointerChunkIndex`],
+/// that the chunk of index `i + 1` starts right after the end of the chunk of index `i`, so that
+/// [`full_mut_ptr`](`Self::full_mut_ptr`) points to the first of
+/// [`num_elements`](`TrustedChunkSizedCollection::num_elements`) contiguous elements and the chunk of
+/// index `i` starts at `collection.full_mut_ptr().add(i * collection.chunk_size())`.
+pub unsafe trait ContiguousPointerChunkIndex<T>: PointerChunkIndex<T> {
+    /// Returns a mutable pointer to the first element of the collection, which is followed by
+    /// the rest of its [`num_elements`](`TrustedChunkSizedCollection::num_elements`) elements
+    /// in contiguous memory.
+    ///
+    /// This allows to treat the whole collection as a single contiguous region (*e.g.* for IO).
+    /// The pointer is always valid to obtain, but the usual aliasing and data race rules apply
+    /// when dereferencing it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 6].into_pointer_par_chunk_index(2);
+    /// let ptr: *mut usize = collection.full_mut_ptr();
+    /// // No other reference exists so we may write the whole collection safely
+    /// unsafe { std::ptr::write_bytes(ptr, 0xFF, collection.num_elements()) };
+    /// assert_eq!(collection.into(), vec![usize::MAX; 6]);
+    /// ```
+    #[inline]
+    fn full_mut_ptr(&self) -> *mut T {
+        if self.num_chunks() == 0 {
+            NonNull::dangling().as_ptr()
+        } else {
+            unsafe {
+                // Safety: the collection has at least one chunk
+                self.get_mut_ptr_unchecked(0) as *mut T
+            }
+        }
+    }
+
+    /// Returns a [`ChunkWriter`] that yields mutable pointers to the chunks of the collection in order,
+    /// advancing a running pointer instead of computing the address of each chunk from its index.
+    ///
+    /// This is meant for tight loops that write the chunks of the collection sequentially.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 6].into_pointer_par_chunk_index(3);
+    /// let mut writer = collection.sequential_chunk_writer();
+    ///
+    /// assert_eq!(writer.remaining(), 2);
+    /// assert_eq!(unsafe { writer.next_chunk() }, collection.get_mut_ptr(0));
+    /// assert_eq!(unsafe { writer.next_chunk() }, collection.get_mut_ptr(1));
+    /// assert_eq!(writer.remaining(), 0);
+    /// ```
+    #[inline]
+    fn sequential_chunk_writer(&self) -> ChunkWriter<'_, T> {
+        ChunkWriter::new(self)
+    }
+
+    /// Returns the index of the chunk of the collection containing the element pointed to by `ptr`,
+    /// or [`None`] if `ptr` does not point to the start of an element of the collection.
+    ///
+    /// This is mainly meant for debugging assertions.
+    /// For zero-sized types every element has the same address, so the index of the first chunk
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0_u32; 6].into_pointer_par_chunk_index(2);
+    /// let ptr = collection.get_mut_element_ptr(2, 1);
+    ///
+    /// assert_eq!(collection.chunk_of_ptr(ptr), Some(2));
+    /// assert_eq!(collection.chunk_of_ptr(&42), None);
+    /// ```
+    #[inline]
+    fn chunk_of_ptr(&self, ptr: *const T) -> Option<usize> {
+        let index = element_offset(self.full_mut_ptr(), ptr)?;
+        (index < self.num_elements()).then(|| index / self.chunk_size())
+    }
+}
+
+/// Unsynchronized read-only access to the elements of a shared immutable collection.
+///
+/// Unlike [`PointerIndex`], this trait only allows the creation of *immutable pointers* and
+/// *shared references* to the elements of the collection: as the elements are never written,
+/// reading them from multiple threads is always free of data races.
+///
+/// # Safety
+///
+/// Implementors must guarantee that the elements of the collection are never mutated as long
+/// as the collection (or any of its clones) exists, and that pointers are valid and dereferenceable
+/// for the lifetime of the collection.
+/// In addition, the following invariants must hold:
+/// * The collection has size [`len`](`TrustedSizedCollection::len`).
+/// * For each collection of size `n`, indexes are defined from `0` to `n - 1`, each univocally identifying an element in
+///   the collection.
+/// * For each index `i`, `collection.get_ptr_unchecked(i)` returns an immutable pointer to the element identified by
+///   index `i` in the collection. It is up to the caller to ensure that `i` is in bounds.
+///
+/// # Examples
+///
+/// ```
+/// # use par_slice::*;
+/// # use std::sync::Arc;
+/// let table: Arc<[u32]> = Arc::from([1, 2, 3]);
+/// let shared = table.into_shared_pointer_par_index();
+///
+/// assert_eq!(unsafe { *shared.get_ptr(1) }, 2);
+/// assert_eq!(*shared.get(2), 3);
+/// ```
+///
+/// Mutable pointers can't be obtained:
+///
+/// ```compile_fail
+/// # use par_slice::*;
+/// # use std::sync::Arc;
+/// let table: Arc<[u32]> = Arc::from([1, 2, 3]);
+/// let shared = table.into_shared_pointer_par_index();
+///
+/// let _ = shared.get_mut_ptr(1);
+/// ```
+pub unsafe trait SharedPointerIndex<T>: TrustedSizedCollection {
+    /// Returns an immutable pointer to the element identified by `index` in the collection.
+    ///
+    /// This method performs bounds checking on `index` to ensure its validity.
+    /// If you can guarantee its validity, you may want to use the
+    /// [`get_ptr_unchecked`](`Self::get_ptr_unchecked`) method instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// # use std::sync::Arc;
+    /// let shared = Arc::<[u32]>::from([1, 2, 3]).into_shared_pointer_par_index();
+    /// assert_eq!(unsafe { *shared.get_ptr(0) }, 1);
+    /// ```
+    #[inline]
+    fn get_ptr(&self, index: usize) -> *const T {
+        assert_in_bounds(self.len(), index);
+        unsafe {
+            // Safety: we just checked that index is in bounds
+            self.get_ptr_unchecked(index)
+        }
+    }
+
+    /// Returns an immutable pointer to the element identified by `index` in the collection,
+    /// without performing bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with an index `i` that would panic [`get_ptr`](`Self::get_ptr`) is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// # use std::sync::Arc;
+    /// let shared = Arc::<[u32]>::from([1, 2, 3]).into_shared_pointer_par_index();
+    /// // We know 2 is a valid index for a collection of length 3
+    /// assert_eq!(unsafe { *shared.get_ptr_unchecked(2) }, 3);
+    /// ```
+    unsafe fn get_ptr_unchecked(&self, index: usize) -> *const T;
+
+    /// Returns a shared reference to the element identified by `index` in the collection.
+    ///
+    /// This is safe as the elements of the collection are never mutated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// # use std::sync::Arc;
+    /// let shared = Arc::<[u32]>::from([1, 2, 3]).into_shared_pointer_par_index();
+    /// assert_eq!(*shared.get(1), 2);
+    /// ```
+    #[inline]
+    fn get(&self, index: usize) -> &T {
+        unsafe {
+            // Safety: the pointer is valid for the lifetime of the collection and
+            // the element is never mutated
+            &*self.get_ptr(index)
+        }
+    }
+}
+
+/// Returns the number of elements of type `T` between `base` and `ptr`, or [`None`]
+/// if `ptr` comes before `base` or does not point to the start of an element.
+#[inline]
+fn element_offset<T>(base: *const T, ptr: *const T) -> Option<usize> {
+    let bytes = ptr.addr().checked_sub(base.addr())?;
+    match size_of::<T>() {
+        0 => (bytes == 0).then_some(0),
+        size => (bytes % size == 0).then_some(bytes / size),
+    }
 }
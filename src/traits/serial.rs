@@ -0,0 +1,710 @@
+use crate::*;
+use std::{
+    io::{self, Read},
+    mem::{align_of, size_of, size_of_val, MaybeUninit},
+    ops::Add,
+    sync::Arc,
+};
+
+/// Serial access to the elements of an owned collection outside of parallel sections.
+///
+/// While [`PointerIndex`](crate::PointerIndex), [`UnsafeNoRefIndex`](crate::UnsafeNoRefIndex)
+/// and [`UnsafeIndex`](crate::UnsafeIndex) are meant to be used while the collection is accessed
+/// from multiple threads, this trait is meant for the phases before and after the parallel
+/// section (*e.g.* checking the results of a parallel fill) without having to convert the
+/// collection back first.
+///
+/// Methods taking `&self` read the elements of the collection through a shared reference:
+/// they must only be called when no mutation of the collection is happening concurrently
+/// (*i.e.* when no other thread may be writing to any of its elements).
+///
+/// # Examples
+///
+/// ```
+/// # use par_slice::*;
+/// # use std::thread::scope;
+/// let dists = ParSlice::with_value(-1, 4);
+///
+/// scope(|s| {
+///     s.spawn(|| unsafe { *dists.get_mut(0) = 0 });
+///     s.spawn(|| unsafe { *dists.get_mut(1) = 1 });
+/// });
+///
+/// // The parallel section is over: we can read the collection serially.
+/// assert!(dists.contains(&-1));
+/// assert_eq!(dists.position(|&d| d == -1), Some(2));
+/// ```
+pub trait SerialAccess<T> {
+    /// Returns a shared slice over the elements of the collection.
+    ///
+    /// This must only be called when no mutation of the collection is happening concurrently.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![1, 2, 3].into_par_index();
+    /// assert_eq!(collection.as_slice(), &[1, 2, 3]);
+    /// ```
+    fn as_slice(&self) -> &[T];
+
+    /// Returns a mutable slice over the elements of the collection.
+    ///
+    /// This is safe as `&mut self` guarantees that no other access to the collection exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let mut collection = vec![1, 2, 3].into_par_index();
+    /// collection.as_mut_slice()[1] = 42;
+    /// assert_eq!(collection.as_slice(), &[1, 42, 3]);
+    /// ```
+    fn as_mut_slice(&mut self) -> &mut [T];
+
+    /// Consumes the collection, returning its elements as a boxed slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![1, 2, 3].into_par_index();
+    /// assert_eq!(collection.into_boxed_slice().as_ref(), &[1, 2, 3]);
+    /// ```
+    fn into_boxed_slice(self) -> Box<[T]>
+    where
+        Self: Sized;
+
+    /// Consumes the collection, returning its elements as an [`Arc<[T]>`](`Arc`) that can be cheaply
+    /// shared between threads for read-only access.
+    ///
+    /// The elements are moved (not cloned) into the new allocation required by [`Arc`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// # use std::sync::Arc;
+    /// let collection = vec![1, 2, 3].into_par_index();
+    /// unsafe { *collection.get_mut(0) = 42 };
+    ///
+    /// let shared: Arc<[i32]> = collection.into_arc();
+    /// assert_eq!(shared.as_ref(), &[42, 2, 3]);
+    /// ```
+    #[inline]
+    fn into_arc(self) -> Arc<[T]>
+    where
+        Self: Sized,
+    {
+        self.into_boxed_slice().into()
+    }
+
+    /// Consumes the collection, returning its elements as a [`Vec`] with no spare capacity
+    /// (*i.e.* its [`capacity`](`Vec::capacity`) is equal to its [`len`](`Vec::len`)).
+    ///
+    /// The capacity of the original collection is not preserved by the conversion to a parallel
+    /// collection, so converting a [`Vec`] back and forth never over-allocates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let mut v = Vec::with_capacity(10);
+    /// v.extend([1, 2, 3]);
+    ///
+    /// let v = v.into_par_index().into_vec_exact();
+    /// assert_eq!(v, vec![1, 2, 3]);
+    /// assert_eq!(v.capacity(), 3);
+    /// ```
+    #[inline]
+    fn into_vec_exact(self) -> Vec<T>
+    where
+        Self: Sized,
+    {
+        let mut vec = self.into_boxed_slice().into_vec();
+        vec.shrink_to_fit();
+        debug_assert!(size_of::<T>() == 0 || vec.capacity() == vec.len());
+        vec
+    }
+
+    /// Returns the alignment in bytes guaranteed for the first element of the collection.
+    ///
+    /// This is `align_of::<T>()` unless the collection was created with a stricter alignment
+    /// (*e.g.* through [`ParSlice::new_aligned`](`crate::ParSlice::new_aligned`)).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0_u32; 3].into_par_index();
+    /// assert_eq!(collection.base_align(), 4);
+    /// ```
+    #[inline]
+    fn base_align(&self) -> usize {
+        align_of::<T>()
+    }
+
+    /// Returns the size in bytes of the elements of the collection.
+    ///
+    /// For chunk collections this accounts for the elements of every chunk, *i.e.* it is
+    /// `num_elements() * size_of::<T>()`.
+    ///
+    /// This must only be called when no mutation of the collection is happening concurrently.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0_u32; 4].into_par_index();
+    /// assert_eq!(collection.size_in_bytes(), 16);
+    ///
+    /// let chunks = vec![0_u32; 20].into_par_chunk_index(5);
+    /// assert_eq!(chunks.size_in_bytes(), 80);
+    /// ```
+    #[inline]
+    fn size_in_bytes(&self) -> usize {
+        size_of_val(self.as_slice())
+    }
+
+    /// Returns a view of the memory of the collection as bytes.
+    ///
+    /// This must only be called when no mutation of the collection is happening concurrently.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![1_u16, 2].into_par_index();
+    /// assert_eq!(collection.as_bytes(), &[1_u16.to_ne_bytes(), 2_u16.to_ne_bytes()].concat());
+    /// ```
+    #[cfg(feature = "bytemuck")]
+    #[inline]
+    fn as_bytes(&self) -> &[u8]
+    where
+        T: bytemuck::Pod,
+    {
+        bytemuck::cast_slice(self.as_slice())
+    }
+
+    /// Returns a mutable view of the memory of the collection as bytes.
+    ///
+    /// This is safe as `&mut self` guarantees that no other access to the collection exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let mut collection = vec![0_u16; 2].into_par_index();
+    /// collection.as_bytes_mut()[2..].copy_from_slice(&42_u16.to_ne_bytes());
+    /// assert_eq!(collection.as_slice(), &[0, 42]);
+    /// ```
+    #[cfg(feature = "bytemuck")]
+    #[inline]
+    fn as_bytes_mut(&mut self) -> &mut [u8]
+    where
+        T: bytemuck::Pod,
+    {
+        bytemuck::cast_slice_mut(self.as_mut_slice())
+    }
+
+    /// Fills a collection of bytes with the bytes read from `reader`, returning the number of bytes read.
+    ///
+    /// Bytes are read directly into the collection, without an intermediate buffer, until either the
+    /// collection is full or `reader` reaches its end. In the latter case the remaining bytes of the
+    /// collection are left unchanged.
+    ///
+    /// This is safe as `&mut self` guarantees that no other access to the collection exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error returned by `reader`, except for [`ErrorKind::Interrupted`](`io::ErrorKind::Interrupted`)
+    /// which causes the read to be retried. Bytes read before the error are kept in the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// # use std::io::Cursor;
+    /// let mut collection = ParSlice::new(4);
+    /// let read = collection.fill_from_reader(&mut Cursor::new(b"abc")).unwrap();
+    ///
+    /// assert_eq!(read, 3);
+    /// assert_eq!(collection.into().as_ref(), &[b'a', b'b', b'c', 0]);
+    /// ```
+    #[inline]
+    fn fill_from_reader(&mut self, reader: &mut impl Read) -> io::Result<usize>
+    where
+        Self: SerialAccess<u8>,
+    {
+        let buf = SerialAccess::<u8>::as_mut_slice(self);
+        let mut filled = 0;
+        while filled < buf.len() {
+            match reader.read(&mut buf[filled..]) {
+                Ok(0) => break,
+                Ok(read) => filled += read,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(filled)
+    }
+
+    /// Copies all elements from `src` into the collection.
+    ///
+    /// This is safe as `&mut self` guarantees that no other access to the collection exists.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` does not have the same number of elements as the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let mut collection = ParSlice::new(3);
+    /// collection.copy_from_slice(&[1, 2, 3]);
+    /// assert_eq!(collection.into().as_ref(), &[1, 2, 3]);
+    /// ```
+    #[inline]
+    fn copy_from_slice(&mut self, src: &[T])
+    where
+        T: Copy,
+    {
+        let dst = self.as_mut_slice();
+        assert!(
+            src.len() == dst.len(),
+            "src should have the same length as the collection. Got a src of length {} for a collection of length {}",
+            src.len(),
+            dst.len()
+        );
+        dst.copy_from_slice(src);
+    }
+
+    /// Swaps all elements of the collection with those in `other`.
+    ///
+    /// This is useful for double-buffering against an externally-owned buffer.
+    /// This is safe as `&mut self` guarantees that no other access to the collection exists.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` does not have the same number of elements as the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let mut collection = vec![1, 2, 3].into_par_index();
+    /// let mut buffer = vec![4, 5, 6];
+    ///
+    /// collection.swap_with_slice(&mut buffer);
+    ///
+    /// assert_eq!(collection.as_slice(), &[4, 5, 6]);
+    /// assert_eq!(buffer, vec![1, 2, 3]);
+    /// ```
+    #[inline]
+    fn swap_with_slice(&mut self, other: &mut [T]) {
+        let this = self.as_mut_slice();
+        assert!(
+            other.len() == this.len(),
+            "other should have the same length as the collection. Got an other of length {} for a collection of length {}",
+            other.len(),
+            this.len()
+        );
+        this.swap_with_slice(other);
+    }
+
+    /// Replaces each element of the collection with the sum of itself and all the elements
+    /// before it (*i.e.* computes the inclusive prefix sum of the collection in place).
+    ///
+    /// This is safe as `&mut self` guarantees that no other access to the collection exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let mut collection = vec![1, 2, 3, 4].into_par_index();
+    /// collection.prefix_sum_in_place();
+    /// assert_eq!(collection.as_slice(), &[1, 3, 6, 10]);
+    /// ```
+    #[inline]
+    fn prefix_sum_in_place(&mut self)
+    where
+        T: Copy + Add<Output = T>,
+    {
+        let mut iter = self.as_mut_slice().iter_mut();
+        if let Some(first) = iter.next() {
+            let mut acc = *first;
+            for x in iter {
+                acc = acc + *x;
+                *x = acc;
+            }
+        }
+    }
+
+    /// Calls `f` on each element of the collection in order, together with its index.
+    ///
+    /// This is safe as `&mut self` guarantees that no other access to the collection exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let mut collection = vec![1, 1, 1].into_par_index();
+    /// collection.apply(|i, x| *x += i);
+    /// assert_eq!(collection.as_slice(), &[1, 2, 3]);
+    /// ```
+    #[inline]
+    fn apply(&mut self, mut f: impl FnMut(usize, &mut T)) {
+        self.as_mut_slice()
+            .iter_mut()
+            .enumerate()
+            .for_each(|(i, x)| f(i, x));
+    }
+
+    /// Shuffles the elements of the collection in place using `rng`.
+    ///
+    /// This method is only available with the `rand` feature.
+    ///
+    /// This is safe as `&mut self` guarantees that no other access to the collection exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// # use rand::{rngs::SmallRng, SeedableRng};
+    /// let mut collection = vec![1, 2, 3, 4, 5].into_par_index();
+    /// collection.shuffle(&mut SmallRng::seed_from_u64(42));
+    ///
+    /// let mut elements: Vec<_> = collection.into();
+    /// elements.sort();
+    /// assert_eq!(elements, vec![1, 2, 3, 4, 5]);
+    /// ```
+    #[cfg(feature = "rand")]
+    #[inline]
+    fn shuffle(&mut self, rng: &mut impl rand::Rng) {
+        use rand::seq::SliceRandom;
+
+        self.as_mut_slice().shuffle(rng)
+    }
+
+    /// Returns the index of the first element of the collection that satisfies `pred`, or
+    /// [`None`] if no element does.
+    ///
+    /// This must only be called when no mutation of the collection is happening concurrently.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![1, 2, 3].into_par_index_no_ref();
+    /// assert_eq!(collection.position(|&x| x > 1), Some(1));
+    /// assert_eq!(collection.position(|&x| x > 3), None);
+    /// ```
+    #[inline]
+    fn position(&self, pred: impl Fn(&T) -> bool) -> Option<usize> {
+        self.as_slice().iter().position(pred)
+    }
+
+    /// Returns `true` if the collection contains an element equal to `x`.
+    ///
+    /// This must only be called when no mutation of the collection is happening concurrently.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![1, 2, 3].into_pointer_par_index();
+    /// assert!(collection.contains(&2));
+    /// assert!(!collection.contains(&42));
+    /// ```
+    #[inline]
+    fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.as_slice().contains(x)
+    }
+
+    /// Folds every element of the collection into an accumulator by applying `f`,
+    /// starting from `init`, and returns the final accumulator.
+    ///
+    /// This must only be called when no mutation of the collection is happening concurrently.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![1_u8, 2, 3].into_par_index();
+    /// assert_eq!(collection.fold(0_usize, |acc, &x| acc + x as usize), 6);
+    /// assert_eq!(collection.fold(u8::MAX, |acc, &x| acc.min(x)), 1);
+    /// ```
+    #[inline]
+    fn fold<A>(&self, init: A, f: impl Fn(A, &T) -> A) -> A {
+        self.as_slice().iter().fold(init, f)
+    }
+
+    /// Returns the number of elements of the collection that satisfy `pred`, evaluating it in
+    /// parallel using [`rayon`]'s global thread pool.
+    ///
+    /// This method is only available with the `rayon` feature.
+    ///
+    /// This must only be called when no mutation of the collection is happening concurrently.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let dists = vec![0, 3, -1, 2, -1].into_par_index();
+    /// assert_eq!(dists.par_count(|&d| d >= 0), 3);
+    /// ```
+    #[cfg(feature = "rayon")]
+    #[inline]
+    fn par_count(&self, pred: impl Fn(&T) -> bool + Sync) -> usize
+    where
+        T: Sync,
+    {
+        par_count(self.as_slice(), pred)
+    }
+
+    /// Maps each chunk of the collection to a partial result with `map` and combines the partial
+    /// results with `reduce`, processing the chunks in parallel using [`rayon`]'s global thread pool.
+    ///
+    /// `identity` must be an identity for `reduce` (*e.g.* `0` for a sum) and it is returned
+    /// if the collection has no chunks.
+    ///
+    /// This method is only available with the `rayon` feature.
+    ///
+    /// This must only be called when no mutation of the collection is happening concurrently.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![1, 2, 3, 4, 5, 6].into_par_chunk_index(2);
+    /// let max_chunk_sum =
+    ///     collection.par_map_reduce_chunks(|chunk| chunk.iter().sum::<i32>(), i32::max, i32::MIN);
+    /// assert_eq!(max_chunk_sum, 11);
+    /// ```
+    #[cfg(feature = "rayon")]
+    #[inline]
+    fn par_map_reduce_chunks<A: Send>(
+        &self,
+        map: impl Fn(&[T]) -> A + Sync,
+        reduce: impl Fn(A, A) -> A + Sync,
+        identity: A,
+    ) -> A
+    where
+        Self: TrustedChunkSizedCollection,
+        T: Sync,
+    {
+        par_map_reduce_chunks(self.as_slice(), self.chunk_size(), map, reduce, identity)
+    }
+
+    /// Returns an iterator over all contiguous overlapping windows of length `size` of the
+    /// collection (see [`slice::windows`]).
+    ///
+    /// The windows are read-only and the iterator is meant for serial passes (*e.g.* to verify
+    /// a stencil computation): it must only be used when no mutation of the collection is
+    /// happening concurrently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![1, 2, 3, 4].into_par_index();
+    /// let sums: Vec<_> = collection.windows(2).map(|w| w[0] + w[1]).collect();
+    /// assert_eq!(sums, vec![3, 5, 7]);
+    /// ```
+    #[inline]
+    fn windows<'a>(&'a self, size: usize) -> impl Iterator<Item = &'a [T]>
+    where
+        T: 'a,
+    {
+        self.as_slice().windows(size)
+    }
+
+    /// Consumes the collection, returning its elements as an array of length `N` if the
+    /// collection has exactly `N` elements, or the collection itself otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![1, 2, 3].into_par_index();
+    /// let collection = collection.try_into_array::<4>().unwrap_err();
+    /// assert_eq!(collection.try_into_array::<3>().ok(), Some([1, 2, 3]));
+    /// ```
+    #[inline]
+    fn try_into_array<const N: usize>(self) -> Result<[T; N], Self>
+    where
+        Self: Sized,
+    {
+        if self.as_slice().len() != N {
+            return Err(self);
+        }
+
+        match self.into_boxed_slice().try_into() {
+            Ok(array) => {
+                let array: Box<[T; N]> = array;
+                Ok(*array)
+            }
+            Err(_) => unreachable!("the length of the collection was already checked"),
+        }
+    }
+
+    /// Consumes the collection, returning a collection of the values obtained by calling `f` on each
+    /// of its elements in order, that allows unsynchronized access to them through [`UnsafeIndex`].
+    ///
+    /// This is useful for pipelines that change the type of the elements between phases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let counts = vec![1_u32, 2, 4].into_par_index();
+    /// let weights = counts.map_into(|count| 1.0 / count as f64);
+    /// assert_eq!(weights.into().as_ref(), &[1.0, 0.5, 0.25]);
+    /// ```
+    #[inline]
+    fn map_into<U: Send + Sync>(
+        self,
+        f: impl FnMut(T) -> U,
+    ) -> impl UnsafeIndex<U> + ParCollection<U, Box<[U]>> + SerialAccess<U>
+    where
+        Self: Sized,
+    {
+        self.into_boxed_slice()
+            .into_vec()
+            .into_iter()
+            .map(f)
+            .collect::<Box<[U]>>()
+            .into_par_index()
+    }
+
+    /// Consumes a collection of chunks, returning its elements as a boxed slice of arrays of `N` elements,
+    /// one for each chunk.
+    ///
+    /// The elements are not moved: the allocation of the collection is reused.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is not equal to [`chunk_size`](`TrustedChunkSizedCollection::chunk_size`),
+    /// if `N` is 0 or if the boxed slice returned by [`into_boxed_slice`](`Self::into_boxed_slice`)
+    /// can't be split exactly in arrays of `N` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![1, 2, 3, 4].into_par_chunk_index(2);
+    /// let arrays: Box<[[i32; 2]]> = collection.into_boxed_arrays();
+    /// assert_eq!(arrays.as_ref(), &[[1, 2], [3, 4]]);
+    /// ```
+    #[inline]
+    fn into_boxed_arrays<const N: usize>(self) -> Box<[[T; N]]>
+    where
+        Self: Sized + TrustedChunkSizedCollection,
+    {
+        assert_array_compatible(self.chunk_size(), N);
+        assert!(N > 0, "N should be greater than 0");
+
+        // The number of arrays is computed from the boxed slice itself, as a safe implementation
+        // of this trait may return a boxed slice that does not match its chunks
+        let boxed = self.into_boxed_slice();
+        assert_chunk_size(boxed.len(), N);
+        let num_chunks = boxed.len() / N;
+
+        let ptr = Box::into_raw(boxed) as *mut [T; N];
+        unsafe {
+            // Safety: the allocation holds num_chunks * N contiguous elements of T, which has
+            // the same layout as num_chunks arrays of N elements of T
+            Box::from_raw(std::ptr::slice_from_raw_parts_mut(ptr, num_chunks))
+        }
+    }
+
+    /// Consumes a collection of [`MaybeUninit<U>`] elements, returning a collection of `U` elements
+    /// that allows unsynchronized access to them through [`UnsafeIndex`].
+    ///
+    /// This allows to allocate a collection without initializing it, to initialize its elements
+    /// in parallel and then to keep using it without copying its elements.
+    ///
+    /// # Safety
+    ///
+    /// Every element of the collection must have been initialized before calling this method
+    /// (see [`MaybeUninit::assume_init`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// # use std::mem::{align_of, MaybeUninit};
+    /// let collection = Box::new_uninit_slice(3).into_par_index_no_ref();
+    ///
+    /// for i in 0..3 {
+    ///     unsafe { collection.set_value(i, MaybeUninit::new(i)) };
+    /// }
+    ///
+    /// // Every element was initialized
+    /// let collection = unsafe { collection.assume_init() };
+    /// assert_eq!(collection.into().as_ref(), &[0, 1, 2]);
+    /// ```
+    #[inline]
+    unsafe fn assume_init<U: Send + Sync>(
+        self,
+    ) -> impl UnsafeIndex<U> + ParCollection<U, Box<[U]>> + SerialAccess<U>
+    where
+        Self: Sized + SerialAccess<MaybeUninit<U>>,
+    {
+        let boxed = <Self as SerialAccess<MaybeUninit<U>>>::into_boxed_slice(self);
+        unsafe {
+            // Safety: the caller guarantees that every element was initialized
+            boxed.assume_init()
+        }
+        .into_par_index()
+    }
+
+    /// Consumes a collection of chunks of [`MaybeUninit<U>`] elements, returning a collection of chunks
+    /// of the same size of `U` elements that allows unsynchronized access to them through [`UnsafeChunkIndex`].
+    ///
+    /// This is the chunked version of [`assume_init`](`Self::assume_init`).
+    ///
+    /// # Safety
+    ///
+    /// Every element of the collection must have been initialized before calling this method
+    /// (see [`MaybeUninit::assume_init`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// # use std::mem::MaybeUninit;
+    /// let collection = Box::new_uninit_slice(4).into_par_chunk_index_no_ref(2);
+    ///
+    /// for i in 0..2 {
+    ///     unsafe { collection.set_values(i, &[MaybeUninit::new(i), MaybeUninit::new(i + 1)]) };
+    /// }
+    ///
+    /// // Every element was initialized
+    /// let collection = unsafe { collection.assume_init_chunks() };
+    /// assert_eq!(unsafe { collection.get(1) }, &[1, 2]);
+    /// ```
+    #[inline]
+    unsafe fn assume_init_chunks<U: Send + Sync>(
+        self,
+    ) -> impl ContiguousUnsafeChunkIndex<U> + ParCollection<[U], Box<[U]>> + SerialAccess<U>
+    where
+        Self: Sized + SerialAccess<MaybeUninit<U>> + TrustedChunkSizedCollection,
+    {
+        let chunk_size = self.chunk_size();
+        let boxed = <Self as SerialAccess<MaybeUninit<U>>>::into_boxed_slice(self);
+        unsafe {
+            // Safety: the caller guarantees that every element was initialized
+            boxed.assume_init()
+        }
+        .into_par_chunk_index(chunk_size)
+    }
+}
@@ -1,4 +1,5 @@
 use crate::*;
+use std::ops::Range;
 
 /// Unsynchronized access to elements of a collection through references.
 ///
@@ -138,9 +139,14 @@ pub unsafe trait UnsafeIndex<T: ?Sized>: TrustedSizedCollection {
     /// If you can guarantee its validity, you may want to use the [`get_unchecked`](`Self::get_unchecked`)
     /// method instead.
     ///
+    /// With the `track-borrows` feature, the reference is wrapped in a `SharedBorrowGuard` that keeps
+    /// the element borrowed until it is dropped.
+    ///
     /// # Panics
     ///
     /// Panics if `index` is out of bounds of the collection.
+    /// With the `track-borrows` feature, collections that track their borrows also panic if the element
+    /// is mutably borrowed through a guard returned by [`get_mut`](`Self::get_mut`).
     ///
     /// # Safety
     ///
@@ -151,9 +157,11 @@ pub unsafe trait UnsafeIndex<T: ?Sized>: TrustedSizedCollection {
     /// ```
     /// # use par_slice::*;
     /// let collection = vec![0; 5].into_par_index();
-    /// let ref_0: &usize = unsafe { collection.get(0) };
+    /// let ref_0 = unsafe { collection.get(0) };
     /// assert_eq!(*ref_0, 0);
     /// ```
+    #[must_use]
+    #[cfg(not(feature = "track-borrows"))]
     #[inline]
     unsafe fn get(&self, index: usize) -> &T {
         assert_in_bounds(self.len(), index);
@@ -163,6 +171,38 @@ pub unsafe trait UnsafeIndex<T: ?Sized>: TrustedSizedCollection {
         }
     }
 
+    /// With the `track-borrows` feature, the reference is wrapped in a `SharedBorrowGuard` that keeps
+    /// the element borrowed until it is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of the collection.
+    /// With the `track-borrows` feature, collections that track their borrows also panic if the element
+    /// is mutably borrowed through a guard returned by [`get_mut`](`Self::get_mut`).
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while a mutable reference to the same element still exists is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 5].into_par_index();
+    /// let ref_0 = unsafe { collection.get(0) };
+    /// assert_eq!(*ref_0, 0);
+    /// ```
+    #[must_use]
+    #[cfg(feature = "track-borrows")]
+    #[inline]
+    unsafe fn get(&self, index: usize) -> SharedBorrowGuard<'_, T> {
+        assert_in_bounds(self.len(), index);
+        SharedBorrowGuard::untracked(unsafe {
+            // Safety: we just checked that index is in bounds
+            self.get_unchecked(index)
+        })
+    }
+
     /// Returns a shared reference to the element identified by `index` in the collection, without performing
     /// bounds checking.
     ///
@@ -183,6 +223,7 @@ pub unsafe trait UnsafeIndex<T: ?Sized>: TrustedSizedCollection {
     /// let ref_0: &usize = unsafe { collection.get_unchecked(0) };
     /// assert_eq!(*ref_0, 0);
     /// ```
+    #[must_use]
     unsafe fn get_unchecked(&self, index: usize) -> &T;
 
     /// Returns a mutable reference to the element identified by `index` in the collection.
@@ -191,9 +232,14 @@ pub unsafe trait UnsafeIndex<T: ?Sized>: TrustedSizedCollection {
     /// If you can guarantee its validity, you may want to use the [`get_mut_unchecked`](`Self::get_mut_unchecked`)
     /// method instead.
     ///
+    /// With the `track-borrows` feature, the reference is wrapped in a `BorrowGuard` that keeps
+    /// the element mutably borrowed until it is dropped.
+    ///
     /// # Panics
     ///
     /// Panics if `index` is out of bounds of the collection.
+    /// With the `track-borrows` feature, collections that track their borrows also panic if the element
+    /// is borrowed through a guard returned by [`get`](`Self::get`) or by this method.
     ///
     /// # Safety
     ///
@@ -205,12 +251,14 @@ pub unsafe trait UnsafeIndex<T: ?Sized>: TrustedSizedCollection {
     /// # use par_slice::*;
     /// let collection = vec![0; 5].into_par_index();
     /// {
-    ///     let ref_0: &mut usize = unsafe { collection.get_mut(0) };
+    ///     let mut ref_0 = unsafe { collection.get_mut(0) };
     ///     *ref_0 = 42;
     /// }
     /// // ref_0 is no longer in scope: we can create a shared reference to the same element
     /// assert_eq!(unsafe { *collection.get(0) }, 42);
     /// ```
+    #[must_use]
+    #[cfg(not(feature = "track-borrows"))]
     #[allow(clippy::mut_from_ref)]
     #[inline]
     unsafe fn get_mut(&self, index: usize) -> &mut T {
@@ -221,6 +269,42 @@ pub unsafe trait UnsafeIndex<T: ?Sized>: TrustedSizedCollection {
         }
     }
 
+    /// With the `track-borrows` feature, the reference is wrapped in a `BorrowGuard` that keeps
+    /// the element mutably borrowed until it is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of the collection.
+    /// With the `track-borrows` feature, collections that track their borrows also panic if the element
+    /// is borrowed through a guard returned by [`get`](`Self::get`) or by this method.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while a reference of any kind to the same element still exists is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 5].into_par_index();
+    /// {
+    ///     let mut ref_0 = unsafe { collection.get_mut(0) };
+    ///     *ref_0 = 42;
+    /// }
+    /// // ref_0 is no longer in scope: we can create a shared reference to the same element
+    /// assert_eq!(unsafe { *collection.get(0) }, 42);
+    /// ```
+    #[must_use]
+    #[cfg(feature = "track-borrows")]
+    #[inline]
+    unsafe fn get_mut(&self, index: usize) -> BorrowGuard<'_, T> {
+        assert_in_bounds(self.len(), index);
+        BorrowGuard::untracked(unsafe {
+            // Safety: we just checked that index is in bounds
+            self.get_mut_unchecked(index)
+        })
+    }
+
     /// Returns a mutable reference to the element identified by `index` in the collection, without performing
     /// bounds checking.
     ///
@@ -245,11 +329,245 @@ pub unsafe trait UnsafeIndex<T: ?Sized>: TrustedSizedCollection {
     /// // ref_0 is no longer in scope: we can create a shared reference to the same element
     /// assert_eq!(unsafe { *collection.get(0) }, 42);
     /// ```
+    #[must_use]
     #[allow(clippy::mut_from_ref)]
     unsafe fn get_mut_unchecked(&self, index: usize) -> &mut T;
+
+    /// Returns mutable references to the two distinct elements identified by `i` and `j` in the collection.
+    ///
+    /// This is useful for pairwise updates (*e.g.* swap partners or the endpoints of an edge).
+    /// This method performs bounds checking on both indexes and checks that they are different.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` or `j` are out of bounds of the collection or if `i == j`.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while a reference of any kind to either element still exists is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![1, 2, 3].into_par_index();
+    /// {
+    ///     let (a, b) = unsafe { collection.get_two_mut(0, 2) };
+    ///     std::mem::swap(a, b);
+    /// }
+    /// assert_eq!(collection.into(), vec![3, 2, 1]);
+    /// ```
+    #[must_use]
+    #[allow(clippy::mut_from_ref)]
+    #[inline]
+    unsafe fn get_two_mut(&self, i: usize, j: usize) -> (&mut T, &mut T) {
+        assert_in_bounds(self.len(), i);
+        assert_in_bounds(self.len(), j);
+        assert!(
+            i != j,
+            "Indices {i} and {j} should refer to different elements"
+        );
+        unsafe {
+            // Safety: we just checked that i and j are in bounds and refer to different
+            // elements, and the caller guarantees no other reference to them exists
+            (self.get_mut_unchecked(i), self.get_mut_unchecked(j))
+        }
+    }
+
+    /// Returns a mutable reference to the element identified by `index` in the collection as an array of
+    /// length 1.
+    ///
+    /// This is useful for generic code written over `&mut [T; N]` that also needs to handle single elements.
+    /// This method performs bounds checking on `index` to ensure its validity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of the collection.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while a reference of any kind to the same element still exists is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![1, 2, 3].into_par_index();
+    /// {
+    ///     let array: &mut [i32; 1] = unsafe { collection.get_mut_single_array(1) };
+    ///     array[0] = 42;
+    /// }
+    /// assert_eq!(collection.into(), vec![1, 42, 3]);
+    /// ```
+    #[must_use]
+    #[allow(clippy::mut_from_ref)]
+    #[inline]
+    unsafe fn get_mut_single_array(&self, index: usize) -> &mut [T; 1]
+    where
+        T: Sized,
+    {
+        assert_in_bounds(self.len(), index);
+        std::array::from_mut(unsafe {
+            // Safety: we just checked that index is in bounds and the caller guarantees
+            // no other reference to the element exists
+            self.get_mut_unchecked(index)
+        })
+    }
+
+    /// Calls `f` on a mutable reference to each element of the collection and a shared reference
+    /// to the element with the same index in `other`.
+    ///
+    /// This is useful for elementwise kernels (*e.g.* `out[i] += other[i]`).
+    /// This method checks that `other` has the same length as the collection.
+    /// If you can guarantee it, you may want to use the [`zip_apply_unchecked`](`Self::zip_apply_unchecked`)
+    /// method instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other.len() != self.len()`.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while a reference of any kind to any element of the collection still exists
+    /// is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![1, 2, 3].into_par_index();
+    ///
+    /// unsafe { collection.zip_apply(&[10, 20, 30], |x, y| *x += y) };
+    ///
+    /// assert_eq!(collection.into(), vec![11, 22, 33]);
+    /// ```
+    #[inline]
+    unsafe fn zip_apply<U>(&self, other: &[U], f: impl Fn(&mut T, &U)) {
+        assert!(
+            other.len() == self.len(),
+            "other should have the same length as the collection. Got other of length {} for a collection of length {}",
+            other.len(),
+            self.len()
+        );
+        unsafe {
+            // Safety: we just checked that the lengths match
+            self.zip_apply_unchecked(other, f)
+        }
+    }
+
+    /// Calls `f` on a mutable reference to each element of the collection and a shared reference
+    /// to the element with the same index in `other`, without checking their lengths.
+    ///
+    /// This method does not check that `other` has the same length as the collection.
+    /// If you can't guarantee it, you may want to use the [`zip_apply`](`Self::zip_apply`) method instead.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while a reference of any kind to any element of the collection still exists
+    /// is undefined behavior.
+    /// Calling this method with an `other` that would panic [`zip_apply`](`Self::zip_apply`) is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![1, 2, 3].into_par_index();
+    ///
+    /// // We know both have length 3
+    /// unsafe { collection.zip_apply_unchecked(&[10, 20, 30], |x, y| *x *= y) };
+    ///
+    /// assert_eq!(collection.into(), vec![10, 40, 90]);
+    /// ```
+    #[inline]
+    unsafe fn zip_apply_unchecked<U>(&self, other: &[U], f: impl Fn(&mut T, &U)) {
+        debug_assert!(other.len() == self.len());
+
+        for (index, y) in other.iter().enumerate() {
+            unsafe {
+                // Safety: index is in bounds of other, which has the same length as the collection,
+                // and the caller guarantees Rust's aliasing rules are respected
+                f(self.get_mut_unchecked(index), y);
+            }
+        }
+    }
+
+    /// Splits the indices of the collection in two halves and calls `f` on each of them in
+    /// parallel, using two threads from [`std::thread::scope`].
+    ///
+    /// `f` is called with the collection and either `0..mid` or `mid..len`, where
+    /// `mid = len / 2` and `len` is the [`len`](`TrustedSizedCollection::len`) of the collection.
+    ///
+    /// The two ranges are disjoint, so `f` may create mutable references to any of the elements
+    /// in the range it receives without causing data races. It is up to `f` to respect this
+    /// contract: accessing an index outside of its range while the other call may access it is
+    /// [undefined behavior] like with any other unsynchronized access.
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 5].into_par_index();
+    ///
+    /// collection.par_update_halves(|collection, range| {
+    ///     for i in range.clone() {
+    ///         // Each call only accesses the indices in its range
+    ///         unsafe { *collection.get_mut(i) = range.start };
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(collection.into(), vec![0, 0, 2, 2, 2]);
+    /// ```
+    #[inline]
+    fn par_update_halves(&self, f: impl Fn(&Self, Range<usize>) + Sync)
+    where
+        Self: Sized + Sync,
+    {
+        par_update_halves(self, f)
+    }
+
+    /// Calls `f` on every index of the collection in parallel, using `num_threads` threads
+    /// from [`std::thread::scope`].
+    ///
+    /// Indices are distributed round-robin: thread `tid` calls `f` with indices
+    /// `tid, tid + num_threads, tid + 2 * num_threads, ...`, so each index is visited by exactly
+    /// one thread and exactly once.
+    ///
+    /// `f` may therefore create a mutable reference to the element identified by the index it
+    /// receives without causing data races. It is up to `f` to respect this contract: accessing
+    /// any other index while another thread may access it is [undefined behavior] like with any
+    /// other unsynchronized access.
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_threads` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 5].into_par_index();
+    ///
+    /// collection.scope_for_each(2, |index, collection| {
+    ///     // Each index is visited exactly once
+    ///     unsafe { *collection.get_mut(index) = 2 * index };
+    /// });
+    ///
+    /// assert_eq!(collection.into(), vec![0, 2, 4, 6, 8]);
+    /// ```
+    #[inline]
+    fn scope_for_each(&self, num_threads: usize, f: impl Fn(usize, &Self) + Sync)
+    where
+        Self: Sized + Sync,
+    {
+        scope_for_each(self, num_threads, f)
+    }
 }
 
-/// Marker trait for collections that allow unsynchronized access to non-overlapping chunks of their elements through references.
+/// Unsynchronized access to non-overlapping chunks of elements of a collection through references.
 ///
 /// The trait allows *unsynchronized* access to chunks of elements of a collection by
 /// allowing the creation of *mutable references* to chunks of elements from a *shared reference* to the
@@ -271,4 +589,339 @@ pub unsafe trait UnsafeIndex<T: ?Sized>: TrustedSizedCollection {
 pub unsafe trait UnsafeChunkIndex<T>:
     UnsafeIndex<[T]> + TrustedChunkSizedCollection
 {
+    /// Returns a mutable reference to the element at position `offset` inside the chunk identified by `chunk`
+    /// in the collection.
+    ///
+    /// The provided implementation borrows the whole chunk through
+    /// [`get_mut_unchecked`](`UnsafeIndex::get_mut_unchecked`). The collections of this crate override it
+    /// so that, unlike [`get_mut`](`UnsafeIndex::get_mut`), only the element is borrowed and the other elements
+    /// of the chunk may still be accessed (*e.g.* from other threads).
+    /// This method performs bounds checking on `chunk` and `offset` to ensure their validity.
+    /// If you can guarantee their validity, you may want to use the
+    /// [`get_mut_at_unchecked`](`Self::get_mut_at_unchecked`) method instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk` is out of bounds of the collection or if `offset` is out of bounds of the chunk.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while a reference of any kind to the same element (or to its chunk)
+    /// still exists is undefined behavior. With the provided implementation of
+    /// [`get_mut_at_unchecked`](`Self::get_mut_at_unchecked`), the same holds for references to the other
+    /// elements of the chunk.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 6].into_par_chunk_index(3);
+    /// {
+    ///     let element: &mut usize = unsafe { collection.get_mut_at(1, 2) };
+    ///     *element = 42;
+    /// }
+    /// assert_eq!(collection.into(), vec![0, 0, 0, 0, 0, 42]);
+    /// ```
+    #[must_use]
+    #[allow(clippy::mut_from_ref)]
+    #[inline]
+    unsafe fn get_mut_at(&self, chunk: usize, offset: usize) -> &mut T {
+        assert_in_bounds(self.num_chunks(), chunk);
+        assert_in_bounds(self.chunk_size(), offset);
+        unsafe {
+            // Safety: we just checked that chunk and offset are in bounds
+            self.get_mut_at_unchecked(chunk, offset)
+        }
+    }
+
+    /// Returns a mutable reference to the element at position `offset` inside the chunk identified by `chunk`
+    /// in the collection, without performing bounds checking.
+    ///
+    /// This method does not perform bounds checking on `chunk` and `offset` to ensure their validity.
+    /// If you can't guarantee their validity, you may want to use the [`get_mut_at`](`Self::get_mut_at`)
+    /// method instead.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while a reference of any kind to the same element (or to its chunk)
+    /// still exists is undefined behavior. With the provided implementation of
+    /// [`get_mut_at_unchecked`](`Self::get_mut_at_unchecked`), the same holds for references to the other
+    /// elements of the chunk.
+    /// Calling this method with a `chunk` or an `offset` that would panic [`get_mut_at`](`Self::get_mut_at`)
+    /// is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 6].into_par_chunk_index(3);
+    /// {
+    ///     // We know (0, 1) is valid for a collection of 2 chunks of 3 elements
+    ///     let element: &mut usize = unsafe { collection.get_mut_at_unchecked(0, 1) };
+    ///     *element = 42;
+    /// }
+    /// assert_eq!(collection.into(), vec![0, 42, 0, 0, 0, 0]);
+    /// ```
+    #[must_use]
+    #[allow(clippy::mut_from_ref)]
+    #[inline]
+    unsafe fn get_mut_at_unchecked(&self, chunk: usize, offset: usize) -> &mut T {
+        #[cfg(feature = "checked")]
+        assert_in_bounds(self.chunk_size(), offset);
+        debug_assert!(offset < self.chunk_size());
+
+        unsafe {
+            // Safety: the caller guarantees that chunk and offset are in bounds and
+            // that no other reference to the chunk exists
+            self.get_mut_unchecked(chunk).get_unchecked_mut(offset)
+        }
+    }
+
+    /// Returns a mutable reference to the chunk identified by `index` in the collection as an array of `N` elements.
+    ///
+    /// This is useful when the chunk size is known at compile time (*e.g.* for SIMD code).
+    /// This method performs bounds checking on `index` and checks that `N` is equal to the chunk size.
+    /// If you can guarantee both, you may want to use the [`get_array_mut_unchecked`](`Self::get_array_mut_unchecked`)
+    /// method instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of the collection or if `N` is not equal to
+    /// [`chunk_size`](`TrustedChunkSizedCollection::chunk_size`).
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while a reference of any kind to the chunk identified by `index` still exists
+    /// is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 8].into_par_chunk_index(4);
+    /// {
+    ///     let chunk: &mut [usize; 4] = unsafe { collection.get_array_mut(1) };
+    ///     *chunk = [1, 2, 3, 4];
+    /// }
+    /// assert_eq!(collection.into(), vec![0, 0, 0, 0, 1, 2, 3, 4]);
+    /// ```
+    #[must_use]
+    #[allow(clippy::mut_from_ref)]
+    #[inline]
+    unsafe fn get_array_mut<const N: usize>(&self, index: usize) -> &mut [T; N] {
+        assert_in_bounds(self.len(), index);
+        assert_array_compatible(self.chunk_size(), N);
+        unsafe {
+            // Safety: we just checked that index is in bounds and that N is the chunk size
+            self.get_array_mut_unchecked(index)
+        }
+    }
+
+    /// Returns a mutable reference to the chunk identified by `index` in the collection as an array of `N` elements,
+    /// without performing bounds checking.
+    ///
+    /// This method does not perform bounds checking on `index` and does not check that `N` is equal to the chunk size.
+    /// If you can't guarantee both, you may want to use the [`get_array_mut`](`Self::get_array_mut`) method instead.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while a reference of any kind to the chunk identified by `index` still exists
+    /// is undefined behavior.
+    /// Calling this method with an `index` or an `N` that would panic [`get_array_mut`](`Self::get_array_mut`)
+    /// is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0_i32; 8].into_par_chunk_index(4);
+    /// // We know 0 is a valid index and that chunks have 4 elements
+    /// let chunk: &mut [i32; 4] = unsafe { collection.get_array_mut_unchecked(0) };
+    /// chunk[3] = 42;
+    /// assert_eq!(collection.into(), vec![0, 0, 0, 42, 0, 0, 0, 0]);
+    /// ```
+    #[must_use]
+    #[allow(clippy::mut_from_ref)]
+    #[inline]
+    unsafe fn get_array_mut_unchecked<const N: usize>(&self, index: usize) -> &mut [T; N] {
+        #[cfg(feature = "checked")]
+        assert_array_compatible(self.chunk_size(), N);
+        debug_assert_eq!(N, self.chunk_size());
+
+        unsafe {
+            // Safety: the caller guarantees Rust's aliasing rules are respected, that index
+            // is valid and that the chunk has exactly N elements
+            let chunk = self.get_mut_unchecked(index);
+            &mut *(chunk.as_mut_ptr() as *mut [T; N])
+        }
+    }
+
+    /// Returns a view over the same elements of the collection, indexed one element at a time.
+    ///
+    /// The view has [`num_elements`](`TrustedChunkSizedCollection::num_elements`) elements and its
+    /// element `i` is element `i % chunk_size` of chunk `i / chunk_size` of the collection.
+    /// No element is copied: the view and the collection share the same memory, so a reference
+    /// obtained from the view counts as a reference to the chunk containing the element for Rust's
+    /// *aliasing rules* (see [`FlatView`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0, 1, 2, 3, 4, 5].into_par_chunk_index(2);
+    /// let flat = collection.as_flat();
+    ///
+    /// assert_eq!(flat.len(), 6);
+    /// unsafe { *flat.get_mut(3) = 42 };
+    /// assert_eq!(unsafe { collection.get(1) }, &[2, 42]);
+    /// ```
+    #[inline]
+    fn as_flat(&self) -> FlatView<'_, T>
+    where
+        Self: Sized + Sync,
+    {
+        FlatView::new(self)
+    }
+
+    /// Returns a [`ParityView`] over the chunks with even indices of the collection and one over
+    /// the chunks with odd indices.
+    ///
+    /// Chunk `k` of the first view refers to chunk `2k` of the collection, while chunk `k` of the second
+    /// view refers to chunk `2k + 1`. As the two views never share a chunk, they may be handed to different
+    /// threads (*e.g.* for red-black iterations).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// # use std::thread::scope;
+    /// let collection = vec![0; 10].into_par_chunk_index(2);
+    /// let (even, odd) = collection.split_parity();
+    ///
+    /// scope(|s| {
+    ///     s.spawn(|| (0..even.len()).for_each(|k| unsafe { even.get_mut(k).fill(1) }));
+    ///     s.spawn(|| (0..odd.len()).for_each(|k| unsafe { odd.get_mut(k).fill(2) }));
+    /// });
+    ///
+    /// assert_eq!(collection.into(), vec![1, 1, 2, 2, 1, 1, 2, 2, 1, 1]);
+    /// ```
+    #[inline]
+    fn split_parity(&self) -> (ParityView<'_, T>, ParityView<'_, T>)
+    where
+        Self: Sized + Sync,
+    {
+        ParityView::split(self)
+    }
+
+    /// Sorts the elements inside each chunk of the collection, sorting the chunks in parallel
+    /// using [`rayon`]'s global thread pool.
+    ///
+    /// This method is only available with the `rayon` feature.
+    ///
+    /// Each chunk is sorted independently with [`slice::sort_unstable`]: elements are never
+    /// moved between chunks.
+    ///
+    /// # Safety
+    ///
+    /// No reference of any kind to any of the chunks may exist while this method runs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![3, 1, 2, 6, 5, 4].into_par_chunk_index(3);
+    ///
+    /// // No other reference to the chunks exists
+    /// unsafe { collection.par_sort_chunks() };
+    ///
+    /// assert_eq!(collection.into(), vec![1, 2, 3, 4, 5, 6]);
+    /// ```
+    #[cfg(feature = "rayon")]
+    #[inline]
+    unsafe fn par_sort_chunks(&self)
+    where
+        Self: Sized + Sync,
+        T: Ord + Send,
+    {
+        par_sort_chunks(self)
+    }
+}
+
+/// Extension of [`UnsafeChunkIndex`] for collections whose consecutive chunks are stored in
+/// contiguous memory, so that they can be borrowed as a single slice.
+///
+/// # Safety
+///
+/// Implementors of this trait must guarantee, in addition to the invariants of [`UnsafeChunkIndex`],
+/// that [`get_mut_chunks_unchecked`](`Self::get_mut_chunks_unchecked`) returns a slice of
+/// `count * chunk_size` elements made of exactly the elements of the chunks from index `start`
+/// included to `start + count` excluded.
+pub unsafe trait ContiguousUnsafeChunkIndex<T>: UnsafeChunkIndex<T> {
+    /// Returns a mutable reference to the `count` consecutive chunks starting from the chunk identified by `start`
+    /// in the collection, as a single slice of `count * chunk_size` elements.
+    ///
+    /// This method performs bounds checking on `start` and `count` to ensure their validity.
+    /// If you can guarantee their validity, you may want to use the
+    /// [`get_mut_chunks_unchecked`](`Self::get_mut_chunks_unchecked`) method instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start + count` is greater than the number of chunks in the collection.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while a reference of any kind to any of the chunks still exists is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 6].into_par_chunk_index(2);
+    /// {
+    ///     let chunks: &mut [usize] = unsafe { collection.get_mut_chunks(1, 2) };
+    ///     chunks.copy_from_slice(&[1, 2, 3, 4]);
+    /// }
+    /// // chunks is no longer in scope: we can create a shared reference to the same chunks
+    /// assert_eq!(unsafe { collection.get(2) }, &[3, 4]);
+    /// ```
+    #[must_use]
+    #[allow(clippy::mut_from_ref)]
+    #[inline]
+    unsafe fn get_mut_chunks(&self, start: usize, count: usize) -> &mut [T] {
+        assert_chunks_in_bounds(self.num_chunks(), start, count);
+        unsafe {
+            // Safety: we just checked that start and count are in bounds
+            self.get_mut_chunks_unchecked(start, count)
+        }
+    }
+
+    /// Returns a mutable reference to the `count` consecutive chunks starting from the chunk identified by `start`
+    /// in the collection, as a single slice of `count * chunk_size` elements, without performing bounds checking.
+    ///
+    /// This method does not perform bounds checking on `start` and `count` to ensure their validity.
+    /// If you can't guarantee their validity, you may want to use the [`get_mut_chunks`](`Self::get_mut_chunks`)
+    /// method instead.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method while a reference of any kind to any of the chunks still exists is undefined behavior.
+    /// Calling this method with a `start` and a `count` that would panic [`get_mut_chunks`](`Self::get_mut_chunks`)
+    /// is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use par_slice::*;
+    /// let collection = vec![0; 6].into_par_chunk_index(2);
+    /// {
+    ///     // We know chunks 0 and 1 are valid for a collection of 3 chunks
+    ///     let chunks: &mut [usize] = unsafe { collection.get_mut_chunks_unchecked(0, 2) };
+    ///     chunks.copy_from_slice(&[1, 2, 3, 4]);
+    /// }
+    /// // chunks is no longer in scope: we can create a shared reference to the same chunks
+    /// assert_eq!(unsafe { collection.get(1) }, &[3, 4]);
+    /// ```
+    #[must_use]
+    #[allow(clippy::mut_from_ref)]
+    unsafe fn get_mut_chunks_unchecked(&self, start: usize, count: usize) -> &mut [T];
 }
@@ -0,0 +1,86 @@
+use par_slice::*;
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread::scope,
+};
+
+#[test]
+fn base_pointer_aligned() {
+    let slice = ParSlice::new_aligned::<64, u32>(100);
+
+    assert_eq!(slice.base_align(), 64);
+    assert_eq!(slice.as_slice().as_ptr() as usize % 64, 0);
+    assert_eq!(
+        &mut *unsafe { slice.get_mut(0) } as *mut u32 as usize % 64,
+        0
+    );
+}
+
+#[test]
+fn alignment_of_type_preserved() {
+    let slice = ParSlice::with_value_aligned::<1, u64>(0, 3);
+
+    assert_eq!(slice.base_align(), 8);
+    assert_eq!(slice.as_slice().as_ptr() as usize % 8, 0);
+}
+
+#[test]
+fn empty() {
+    let slice = ParSlice::new_aligned::<32, u8>(0);
+
+    assert_eq!(slice.base_align(), 32);
+    assert!(slice.is_empty());
+    assert_eq!(slice.into().len(), 0);
+}
+
+#[test]
+fn parallel_fill() {
+    let slice = ParSlice::with_closure_aligned::<64, _>(|i| i, 8);
+
+    scope(|s| {
+        s.spawn(|| {
+            for i in 0..4 {
+                unsafe { *slice.get_mut(i) *= 2 };
+            }
+        });
+        s.spawn(|| {
+            for i in 4..8 {
+                unsafe { *slice.get_mut(i) *= 2 };
+            }
+        });
+    });
+
+    assert_eq!(slice.into().as_ref(), &[0, 2, 4, 6, 8, 10, 12, 14]);
+}
+
+#[test]
+fn elements_dropped_once() {
+    let counter = Arc::new(AtomicUsize::new(0));
+
+    struct Droppable(Arc<AtomicUsize>);
+
+    impl Drop for Droppable {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let slice = ParSlice::with_closure_aligned::<64, _>(|_| Droppable(counter.clone()), 4);
+    let boxed = slice.into();
+    assert_eq!(counter.load(Ordering::Relaxed), 0);
+    drop(boxed);
+    assert_eq!(counter.load(Ordering::Relaxed), 4);
+
+    let slice = ParSlice::with_closure_aligned::<64, _>(|_| Droppable(counter.clone()), 4);
+    drop(slice);
+    assert_eq!(counter.load(Ordering::Relaxed), 8);
+}
+
+#[test]
+#[should_panic(expected = "alignment should be a power of two. Got an alignment of 48")]
+fn invalid_alignment() {
+    ParSlice::new_aligned::<48, u8>(4);
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "allocator_api")]
+#![feature(allocator_api)]
+
+use par_slice::*;
+use std::{
+    alloc::{AllocError, Allocator, Global, Layout},
+    ptr::NonNull,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread::scope,
+};
+
+#[derive(Debug, Default)]
+struct Counts {
+    allocations: AtomicUsize,
+    deallocations: AtomicUsize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TrackingAllocator<'a>(&'a Counts);
+
+unsafe impl Allocator for TrackingAllocator<'_> {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        self.0.allocations.fetch_add(1, Ordering::Relaxed);
+        Global.allocate(layout)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        self.0.deallocations.fetch_add(1, Ordering::Relaxed);
+        unsafe { Global.deallocate(ptr, layout) }
+    }
+}
+
+impl Counts {
+    fn get(&self) -> (usize, usize) {
+        (
+            self.allocations.load(Ordering::Relaxed),
+            self.deallocations.load(Ordering::Relaxed),
+        )
+    }
+}
+
+#[test]
+fn allocation_kept_into_vec() {
+    let counts = Counts::default();
+
+    let slice: AllocatorParIndex<u32, _> = ParSlice::new_in(8, TrackingAllocator(&counts));
+    assert_eq!(counts.get(), (1, 0));
+
+    scope(|s| {
+        for i in 0..8 {
+            let slice = &slice;
+            s.spawn(move || unsafe { *slice.get_mut(i) = i as u32 });
+        }
+    });
+
+    let vec: Vec<u32, TrackingAllocator<'_>> = slice.into();
+    let boxed = vec.into_boxed_slice();
+    assert_eq!(counts.get(), (1, 0));
+    assert_eq!(boxed.as_ref(), &[0, 1, 2, 3, 4, 5, 6, 7]);
+
+    drop(boxed);
+    assert_eq!(counts.get(), (1, 1));
+}
+
+#[test]
+fn deallocated_on_drop() {
+    let counts = Counts::default();
+
+    let slice = ParSlice::with_value_in(String::from("value"), 4, TrackingAllocator(&counts));
+    assert_eq!(slice.len(), 4);
+
+    drop(slice);
+    assert_eq!(counts.get(), (1, 1));
+}
+
+#[test]
+fn into_boxed_slice_moves_to_global() {
+    let counts = Counts::default();
+
+    let slice = ParSlice::with_closure_in(|i| i * 2, 4, TrackingAllocator(&counts));
+
+    assert_eq!(slice.into_boxed_slice().as_ref(), &[0, 2, 4, 6]);
+    assert_eq!(counts.get(), (1, 1));
+}
@@ -0,0 +1,121 @@
+use par_slice::*;
+use std::thread::scope;
+
+#[test]
+fn histogram_disjoint_bins() {
+    let values: Vec<usize> = (0..1000).map(|i| (i * 7) % 10).collect();
+    let bins = vec![0_u32; 10].into_par_index_no_ref();
+
+    scope(|s| {
+        // Each thread only updates the bins in its own half
+        for half in 0..2 {
+            let bins = &bins;
+            let values = &values;
+            s.spawn(move || {
+                for &value in values.iter().filter(|&&v| v / 5 == half) {
+                    unsafe { bins.add(value, 1) };
+                }
+            });
+        }
+    });
+
+    assert_eq!(bins.into(), vec![100; 10]);
+}
+
+#[test]
+fn sub_disjoint_bins() {
+    let bins = vec![10_i64; 4].into_par_index_no_ref();
+
+    scope(|s| {
+        s.spawn(|| unsafe {
+            bins.sub(0, 3);
+            bins.sub_unchecked(1, 20);
+        });
+        s.spawn(|| unsafe {
+            bins.add_unchecked(2, -1);
+            bins.sub(3, 10);
+        });
+    });
+
+    assert_eq!(bins.into(), vec![7, -10, 9, 0]);
+}
+
+#[test]
+fn bitwise_disjoint_flags() {
+    let flags = IndexWrapper::new::<u8>(vec![0_u8; 2].into_par_index_no_ref());
+
+    scope(|s| {
+        s.spawn(|| unsafe {
+            flags.or(0, 0b1111);
+            flags.and(0, 0b0110);
+        });
+        s.spawn(|| unsafe {
+            flags.or_unchecked(1, 0b1000);
+            flags.and_unchecked(1, 0b1001);
+        });
+    });
+
+    assert_eq!(flags.into_inner().into(), vec![0b0110, 0b1000]);
+}
+
+#[test]
+#[should_panic(expected = "Index 3 invalid for slice of len 3")]
+fn add_panic() {
+    let bins = vec![0; 3].into_par_index_no_ref();
+    unsafe { bins.add(3, 1) };
+}
+
+#[test]
+fn relax_disjoint_distances() {
+    // (node, candidate distance) pairs, each node relaxed by a single thread
+    let relaxations = [
+        (0, 7),
+        (1, 3),
+        (0, 4),
+        (1, 9),
+        (2, 5),
+        (3, 2),
+        (2, 8),
+        (3, 1),
+    ];
+    let dists = vec![u32::MAX; 4].into_par_index_no_ref();
+
+    scope(|s| {
+        for half in 0..2 {
+            let dists = &dists;
+            s.spawn(move || {
+                for &(node, dist) in relaxations.iter().filter(|(node, _)| node / 2 == half) {
+                    let previous = unsafe { dists.fetch_min(node, dist) };
+                    assert!(previous >= unsafe { dists.get_value(node) });
+                }
+            });
+        }
+    });
+
+    assert_eq!(dists.into(), vec![4, 3, 5, 1]);
+}
+
+#[test]
+fn fetch_max_returns_previous() {
+    let maxima = vec![5_i32; 2].into_par_index_no_ref();
+
+    scope(|s| {
+        s.spawn(|| unsafe {
+            assert_eq!(maxima.fetch_max(0, 3), 5);
+            assert_eq!(maxima.fetch_max_unchecked(0, 8), 5);
+        });
+        s.spawn(|| unsafe {
+            assert_eq!(maxima.fetch_min_unchecked(1, -2), 5);
+            assert_eq!(maxima.fetch_max(1, -1), -2);
+        });
+    });
+
+    assert_eq!(maxima.into(), vec![8, -1]);
+}
+
+#[test]
+#[should_panic(expected = "Index 2 invalid for slice of len 2")]
+fn fetch_min_panic() {
+    let dists = vec![0; 2].into_par_index_no_ref();
+    unsafe { dists.fetch_min(2, 1) };
+}
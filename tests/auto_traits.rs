@@ -0,0 +1,71 @@
+//! Compile-time checks that the auto traits of the parallel collections are not broader than needed.
+//!
+//! The negative cases (*e.g.* collections of elements that are not [`Send`] not being [`Sync`])
+//! are `compile_fail` doctests on [`OwnedParIndex`] and [`UnsafeView`].
+
+use par_slice::*;
+use std::cell::Cell;
+
+fn assert_send<T: Send>() {}
+
+fn assert_sync<T: Sync>() {}
+
+fn owned_is_send_and_sync<T: Send + Sync>() {
+    assert_send::<OwnedPointerParIndex<T>>();
+    assert_sync::<OwnedPointerParIndex<T>>();
+    assert_send::<VecNoRefParIndex<T>>();
+    assert_sync::<VecNoRefParIndex<T>>();
+    assert_send::<OwnedParIndex<T>>();
+    assert_sync::<OwnedParIndex<T>>();
+    assert_send::<OwnedPointerChunkParIndex<T>>();
+    assert_sync::<OwnedPointerChunkParIndex<T>>();
+    assert_send::<OwnedNoRefChunkParIndex<T>>();
+    assert_sync::<OwnedNoRefChunkParIndex<T>>();
+    assert_send::<VecChunkParIndex<T>>();
+    assert_sync::<VecChunkParIndex<T>>();
+}
+
+fn views_are_send_and_sync<'a, T: Send + Sync + 'a>() {
+    assert_send::<PointerView<'a, T>>();
+    assert_sync::<PointerView<'a, T>>();
+    assert_send::<NoRefView<'a, T>>();
+    assert_sync::<NoRefView<'a, T>>();
+    assert_send::<UnsafeView<'a, T>>();
+    assert_sync::<UnsafeView<'a, T>>();
+    assert_send::<PointerChunkView<'a, T>>();
+    assert_sync::<PointerChunkView<'a, T>>();
+    assert_send::<NoRefChunkView<'a, T>>();
+    assert_sync::<NoRefChunkView<'a, T>>();
+    assert_send::<UnsafeChunkView<'a, T>>();
+    assert_sync::<UnsafeChunkView<'a, T>>();
+    assert_send::<RevChunkView<'a, T>>();
+    assert_sync::<RevChunkView<'a, T>>();
+}
+
+fn owned_is_send<T: Send>() {
+    assert_send::<OwnedParIndex<T>>();
+    assert_send::<OwnedChunkParIndex<T>>();
+}
+
+#[test]
+fn send_and_sync_elements() {
+    owned_is_send_and_sync::<u32>();
+    views_are_send_and_sync::<u32>();
+    assert_sync::<SubView<'_, u32>>();
+    assert_sync::<ParityView<'_, u32>>();
+    assert_sync::<FlatView<'_, u32>>();
+}
+
+#[test]
+fn send_only_elements() {
+    // Moving an owned collection only moves its elements, so `Sync` is not needed
+    owned_is_send::<Cell<u32>>();
+}
+
+#[test]
+fn zip_follows_collections() {
+    // The element types are only markers, so they don't restrict the auto traits
+    type Zip<'a> = ZipParIndex<*const u32, *const u32, UnsafeView<'a, u32>, UnsafeView<'a, u32>>;
+    assert_send::<Zip<'_>>();
+    assert_sync::<Zip<'_>>();
+}
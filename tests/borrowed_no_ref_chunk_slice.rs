@@ -4,7 +4,7 @@ use std::thread::scope;
 #[test]
 #[should_panic(expected = "chunk_size should be a divisor of len. 7 / 2 = 3 with a remainder of 1")]
 fn invalid_chunk_size() {
-    let mut v = vec![1, 2, 3, 4, 5, 6, 7];
+    let mut v = [1, 2, 3, 4, 5, 6, 7];
 
     {
         v.as_par_chunk_index_no_ref(2);
@@ -56,7 +56,7 @@ fn no_thread_checked() {
 #[test]
 #[should_panic(expected = "Index 42 invalid for slice of len 2")]
 fn no_thread_checked_panic_get() {
-    let mut v = vec![1, 2, 3, 4];
+    let mut v = [1, 2, 3, 4];
     let mut buf = vec![0; 2];
 
     {
@@ -70,7 +70,7 @@ fn no_thread_checked_panic_get() {
 #[test]
 #[should_panic(expected = "Index 69 invalid for slice of len 2")]
 fn no_thread_checked_panic_set() {
-    let mut v = vec![1, 2, 3, 4];
+    let mut v = [1, 2, 3, 4];
 
     {
         let slice = v.as_par_chunk_index_no_ref(2);
@@ -85,7 +85,7 @@ fn no_thread_checked_panic_set() {
     expected = "value should have the same length as the chunk. Got a value of length 1 for a chunk of length 2"
 )]
 fn no_thread_checked_panic_set_chunk_size() {
-    let mut v = vec![1, 2, 3, 4];
+    let mut v = [1, 2, 3, 4];
 
     {
         let slice = v.as_par_chunk_index_no_ref(2);
@@ -38,12 +38,12 @@ fn no_thread_checked() {
 #[test]
 #[should_panic(expected = "Index 42 invalid for slice of len 3")]
 fn no_thread_checked_panic_get() {
-    let mut v = vec![1, 2, 3];
+    let mut v = [1, 2, 3];
 
     {
         let slice = v.as_par_index_no_ref();
         unsafe {
-            slice.get_value(42);
+            let _ = slice.get_value(42);
         }
     }
 }
@@ -51,7 +51,7 @@ fn no_thread_checked_panic_get() {
 #[test]
 #[should_panic(expected = "Index 69 invalid for slice of len 3")]
 fn no_thread_checked_panic_set() {
-    let mut v = vec![1, 2, 3];
+    let mut v = [1, 2, 3];
 
     {
         let slice = v.as_par_index_no_ref();
@@ -119,7 +119,7 @@ fn single_thread_checked_panic_get() {
         let slice = v.as_par_index_no_ref();
         scope(|s| {
             s.spawn(|| {
-                unsafe { slice.get_value(42) };
+                let _ = unsafe { slice.get_value(42) };
             })
             .join()
             .unwrap_err();
@@ -210,7 +210,7 @@ fn multithread_checked_panic_get() {
                 unsafe { slice.set_value(2, 42) };
             });
             s.spawn(|| {
-                unsafe { slice.get_value(42) };
+                let _ = unsafe { slice.get_value(42) };
             })
             .join()
             .unwrap_err();
@@ -240,3 +240,34 @@ fn multithread_checked_panic_mut() {
 
     assert_eq!(v, vec![1, 2, 3]);
 }
+
+//
+// Test stack arrays
+//
+
+fn fill_with_index<C: ParIndexView<usize> + ?Sized>(collection: &mut C) {
+    let view = collection.as_par_index_no_ref();
+    scope(|s| {
+        for i in 0..view.len() {
+            let view = &view;
+            s.spawn(move || unsafe { view.set_value(i, i) });
+        }
+    });
+}
+
+#[test]
+fn stack_array() {
+    let mut array = [0; 4];
+    fill_with_index(&mut array);
+    assert_eq!(array, [0, 1, 2, 3]);
+
+    {
+        let view = array.as_par_chunk_index_no_ref(2);
+        unsafe { view.set_values(1, &[42, 69]) };
+    }
+    assert_eq!(array, [0, 1, 42, 69]);
+
+    let mut slice = vec![0; 3];
+    fill_with_index(slice.as_mut_slice());
+    assert_eq!(slice, vec![0, 1, 2]);
+}
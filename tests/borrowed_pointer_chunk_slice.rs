@@ -4,7 +4,7 @@ use std::thread::scope;
 #[test]
 #[should_panic(expected = "chunk_size should be a divisor of len. 7 / 2 = 3 with a remainder of 1")]
 fn invalid_chunk_size() {
-    let mut v = vec![1, 2, 3, 4, 5, 6, 7];
+    let mut v = [1, 2, 3, 4, 5, 6, 7];
 
     {
         v.as_pointer_par_chunk_index(2);
@@ -48,22 +48,22 @@ fn no_thread_checked() {
 #[test]
 #[should_panic(expected = "Index 42 invalid for slice of len 2")]
 fn no_thread_checked_panic() {
-    let mut v = vec![1, 2, 3, 4];
+    let mut v = [1, 2, 3, 4];
 
     {
         let slice = v.as_pointer_par_chunk_index(2);
-        slice.get_ptr(42);
+        let _ = slice.get_ptr(42);
     }
 }
 
 #[test]
 #[should_panic(expected = "Index 69 invalid for slice of len 2")]
 fn no_thread_checked_panic_mut() {
-    let mut v = vec![1, 2, 3, 4];
+    let mut v = [1, 2, 3, 4];
 
     {
         let slice = v.as_pointer_par_chunk_index(2);
-        slice.get_mut_ptr(69);
+        let _ = slice.get_mut_ptr(69);
     }
 }
 
@@ -125,7 +125,7 @@ fn single_thread_checked_panic() {
         let slice = v.as_pointer_par_chunk_index(2);
         scope(|s| {
             s.spawn(|| {
-                slice.get_ptr(42);
+                let _ = slice.get_ptr(42);
             })
             .join()
             .unwrap_err();
@@ -153,7 +153,7 @@ fn single_thread_checked_panic_mut() {
             .join()
             .unwrap();
             s.spawn(|| {
-                slice.get_mut_ptr(42);
+                let _ = slice.get_mut_ptr(42);
             })
             .join()
             .unwrap_err();
@@ -216,7 +216,7 @@ fn multithread_checked_panic() {
                 unsafe { (*slice.get_mut_ptr(1)).copy_from_slice(&[42, 69]) };
             });
             s.spawn(|| {
-                slice.get_ptr(42);
+                let _ = slice.get_ptr(42);
             })
             .join()
             .unwrap_err();
@@ -237,7 +237,7 @@ fn multithread_checked_panic_mut() {
                 assert_eq!(unsafe { &*slice.get_ptr(0) }, &[1, 2]);
             });
             s.spawn(|| {
-                slice.get_mut_ptr(42);
+                let _ = slice.get_mut_ptr(42);
             })
             .join()
             .unwrap_err();
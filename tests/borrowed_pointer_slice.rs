@@ -38,22 +38,22 @@ fn no_thread_checked() {
 #[test]
 #[should_panic(expected = "Index 42 invalid for slice of len 3")]
 fn no_thread_checked_panic() {
-    let mut v = vec![1, 2, 3];
+    let mut v = [1, 2, 3];
 
     {
         let slice = v.as_pointer_par_index();
-        slice.get_ptr(42);
+        let _ = slice.get_ptr(42);
     }
 }
 
 #[test]
 #[should_panic(expected = "Index 69 invalid for slice of len 3")]
 fn no_thread_checked_panic_mut() {
-    let mut v = vec![1, 2, 3];
+    let mut v = [1, 2, 3];
 
     {
         let slice = v.as_pointer_par_index();
-        slice.get_mut_ptr(69);
+        let _ = slice.get_mut_ptr(69);
     }
 }
 
@@ -115,7 +115,7 @@ fn single_thread_checked_panic() {
         let slice = v.as_pointer_par_index();
         scope(|s| {
             s.spawn(|| {
-                slice.get_ptr(42);
+                let _ = slice.get_ptr(42);
             })
             .join()
             .unwrap_err();
@@ -206,7 +206,7 @@ fn multithread_checked_panic() {
                 unsafe { *slice.get_mut_ptr(2) = 42 };
             });
             s.spawn(|| {
-                slice.get_ptr(42);
+                let _ = slice.get_ptr(42);
             })
             .join()
             .unwrap_err();
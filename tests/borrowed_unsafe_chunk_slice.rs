@@ -4,7 +4,7 @@ use std::thread::scope;
 #[test]
 #[should_panic(expected = "chunk_size should be a divisor of len. 7 / 2 = 3 with a remainder of 1")]
 fn invalid_chunk_size() {
-    let mut v = vec![1, 2, 3, 4, 5, 6, 7];
+    let mut v = [1, 2, 3, 4, 5, 6, 7];
 
     {
         v.as_par_chunk_index(2);
@@ -48,12 +48,12 @@ fn no_thread_checked() {
 #[test]
 #[should_panic(expected = "Index 42 invalid for slice of len 2")]
 fn no_thread_checked_panic() {
-    let mut v = vec![1, 2, 3, 4];
+    let mut v = [1, 2, 3, 4];
 
     {
         let slice = v.as_par_chunk_index(2);
         unsafe {
-            slice.get(42);
+            let _ = slice.get(42);
         }
     }
 }
@@ -61,12 +61,12 @@ fn no_thread_checked_panic() {
 #[test]
 #[should_panic(expected = "Index 69 invalid for slice of len 2")]
 fn no_thread_checked_panic_mut() {
-    let mut v = vec![1, 2, 3, 4];
+    let mut v = [1, 2, 3, 4];
 
     {
         let slice = v.as_par_chunk_index(2);
         unsafe {
-            slice.get_mut(69);
+            let _ = slice.get_mut(69);
         }
     }
 }
@@ -129,7 +129,7 @@ fn single_thread_checked_panic() {
         let slice = v.as_par_chunk_index(2);
         scope(|s| {
             s.spawn(|| {
-                unsafe { slice.get(42) };
+                let _ = unsafe { slice.get(42) };
             })
             .join()
             .unwrap_err();
@@ -157,7 +157,7 @@ fn single_thread_checked_panic_mut() {
             .join()
             .unwrap();
             s.spawn(|| {
-                unsafe { slice.get_mut(42) };
+                let _ = unsafe { slice.get_mut(42) };
             })
             .join()
             .unwrap_err();
@@ -220,7 +220,7 @@ fn multithread_checked_panic() {
                 unsafe { slice.get_mut(1).copy_from_slice(&[42, 69]) };
             });
             s.spawn(|| {
-                unsafe { slice.get(42) };
+                let _ = unsafe { slice.get(42) };
             })
             .join()
             .unwrap_err();
@@ -241,7 +241,7 @@ fn multithread_checked_panic_mut() {
                 assert_eq!(unsafe { slice.get(0) }, &[1, 2]);
             });
             s.spawn(|| {
-                unsafe { slice.get_mut(42) };
+                let _ = unsafe { slice.get_mut(42) };
             })
             .join()
             .unwrap_err();
@@ -250,3 +250,122 @@ fn multithread_checked_panic_mut() {
 
     assert_eq!(v, vec![1, 2, 3, 4]);
 }
+
+//
+// Test reversed chunks
+//
+
+#[test]
+fn rchunk_index() {
+    let mut slice = [1, 2, 3, 4];
+
+    {
+        let view = slice.as_rchunk_index(2);
+        assert_eq!(view.num_chunks(), 2);
+        assert_eq!(unsafe { view.get(0) }, &[3, 4]);
+        assert_eq!(unsafe { view.get_unchecked(1) }, &[1, 2]);
+    }
+}
+
+#[test]
+fn rchunk_index_multithread() {
+    let mut slice = vec![0; 6];
+
+    {
+        let view = slice.as_rchunk_index(2);
+        scope(|s| {
+            for chunk in 0..3 {
+                let view = &view;
+                s.spawn(move || unsafe { view.get_mut(chunk).fill(chunk) });
+            }
+        });
+        unsafe { *view.get_mut_at(1, 0) = 42 };
+    }
+
+    assert_eq!(slice, vec![2, 2, 42, 1, 0, 0]);
+}
+
+#[test]
+#[should_panic(expected = "Index 2 invalid for slice of len 2")]
+fn rchunk_index_panic() {
+    let mut slice = [1, 2, 3, 4];
+    let view = slice.as_rchunk_index(2);
+    let _ = unsafe { view.get(2) };
+}
+
+#[test]
+#[should_panic(expected = "chunk_size should be a divisor of len. 4 / 3 = 1 with a remainder of 1")]
+fn rchunk_index_chunk_size_panic() {
+    let mut slice = [1, 2, 3, 4];
+    slice.as_rchunk_index(3);
+}
+
+#[test]
+fn rchunk_index_get_mut_at() {
+    let mut slice = [1, 2, 3, 4, 5, 6];
+
+    {
+        let view = slice.as_rchunk_index(2);
+        unsafe { *view.get_mut_at(0, 1) = 42 };
+        unsafe { *view.get_mut_at(2, 0) = 69 };
+    }
+
+    assert_eq!(slice, [69, 2, 3, 4, 5, 42]);
+}
+
+#[test]
+fn rchunk_index_as_flat() {
+    let mut slice = [1, 2, 3, 4, 5, 6];
+
+    {
+        let view = slice.as_rchunk_index(2);
+        let flat = view.as_flat();
+        assert_eq!(flat.len(), 6);
+        assert_eq!(
+            (0..6).map(|i| unsafe { *flat.get(i) }).collect::<Vec<_>>(),
+            vec![5, 6, 3, 4, 1, 2]
+        );
+        unsafe { *flat.get_mut(1) = 42 };
+    }
+
+    assert_eq!(slice, [1, 2, 3, 4, 5, 42]);
+}
+
+#[test]
+fn chunk_index_remainder() {
+    let mut slice = [1, 2, 3, 4, 5, 6, 7];
+
+    {
+        let (view, remainder) = slice.as_par_chunk_index_remainder(2);
+        assert_eq!(view.num_chunks(), 3);
+        assert_eq!(view.chunk_size(), 2);
+        assert_eq!(remainder, &mut [7]);
+
+        scope(|s| {
+            for chunk in 0..3 {
+                let view = &view;
+                s.spawn(move || unsafe { view.get_mut(chunk).swap(0, 1) });
+            }
+        });
+        remainder[0] = 42;
+    }
+
+    assert_eq!(slice, [2, 1, 4, 3, 6, 5, 42]);
+}
+
+#[test]
+fn chunk_index_remainder_divisible() {
+    let mut slice = [1, 2, 3, 4];
+
+    let (view, remainder) = slice.as_par_chunk_index_remainder(2);
+    assert_eq!(view.num_chunks(), 2);
+    assert!(remainder.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "chunk_size should be greater than 0")]
+fn chunk_index_remainder_zero_chunk_size() {
+    let mut slice = [1, 2, 3, 4];
+
+    let _ = slice.as_par_chunk_index_remainder(0);
+}
@@ -38,12 +38,12 @@ fn no_thread_checked() {
 #[test]
 #[should_panic(expected = "Index 42 invalid for slice of len 3")]
 fn no_thread_checked_panic() {
-    let mut v = vec![1, 2, 3];
+    let mut v = [1, 2, 3];
 
     {
         let slice = v.as_par_index();
         unsafe {
-            slice.get(42);
+            let _ = slice.get(42);
         }
     }
 }
@@ -51,12 +51,12 @@ fn no_thread_checked_panic() {
 #[test]
 #[should_panic(expected = "Index 69 invalid for slice of len 3")]
 fn no_thread_checked_panic_mut() {
-    let mut v = vec![1, 2, 3];
+    let mut v = [1, 2, 3];
 
     {
         let slice = v.as_par_index();
         unsafe {
-            slice.get_mut(69);
+            let _ = slice.get_mut(69);
         }
     }
 }
@@ -119,7 +119,7 @@ fn single_thread_checked_panic() {
         let slice = v.as_par_index();
         scope(|s| {
             s.spawn(|| {
-                unsafe { slice.get(42) };
+                let _ = unsafe { slice.get(42) };
             })
             .join()
             .unwrap_err();
@@ -210,7 +210,7 @@ fn multithread_checked_panic() {
                 unsafe { *slice.get_mut(2) = 42 };
             });
             s.spawn(|| {
-                unsafe { slice.get(42) };
+                let _ = unsafe { slice.get(42) };
             })
             .join()
             .unwrap_err();
@@ -240,3 +240,141 @@ fn multithread_checked_panic_mut() {
 
     assert_eq!(v, vec![1, 2, 3]);
 }
+
+//
+// Test views on arrays
+//
+
+#[test]
+fn array_views() {
+    let mut array = [1, 2, 3, 4];
+
+    {
+        let view = array.as_par_index();
+        scope(|s| {
+            s.spawn(|| unsafe { *view.get_mut(0) = 42 });
+            s.spawn(|| unsafe { *view.get_mut(3) = 69 });
+        });
+    }
+    {
+        let array_ref = &mut array;
+        let view = array_ref.as_par_chunk_index(2);
+        unsafe { view.get_mut(0)[1] = 0 };
+    }
+
+    assert_eq!(array, [42, 0, 3, 69]);
+}
+
+#[test]
+fn array_reference_views() {
+    fn double(mut collection: impl ParIndexView<u32>) {
+        let view = collection.as_par_index();
+        scope(|s| {
+            for i in 0..view.len() {
+                let view = &view;
+                s.spawn(move || unsafe { *view.get_mut(i) *= 2 });
+            }
+        });
+    }
+
+    let mut array = [1, 2, 3, 4];
+    double(&mut array);
+
+    assert_eq!(array, [2, 4, 6, 8]);
+}
+
+//
+// Test views on custom buffers
+//
+
+struct Buf(Vec<u32>);
+
+impl AsMut<[u32]> for Buf {
+    fn as_mut(&mut self) -> &mut [u32] {
+        &mut self.0
+    }
+}
+
+#[test]
+fn as_mut_buffer_view() {
+    let mut buf = Buf(vec![0; 4]);
+
+    {
+        let view = buf.as_par_index_via_asmut();
+        scope(|s| {
+            for i in 0..4 {
+                let view = &view;
+                s.spawn(move || unsafe { *view.get_mut(i) = i as u32 * 10 });
+            }
+        });
+    }
+
+    assert_eq!(buf.0, vec![0, 10, 20, 30]);
+}
+
+#[test]
+#[should_panic(expected = "Index 4 invalid for slice of len 4")]
+fn as_mut_buffer_view_panic() {
+    let mut buf = Buf(vec![0; 4]);
+    let view = buf.as_par_index_via_asmut();
+    let _ = unsafe { view.get(4) };
+}
+
+//
+// Test elementwise updates
+//
+
+#[test]
+fn zip_apply_disjoint_ranges() {
+    let delta = [1_u64; 8];
+    let mut values: Vec<u64> = (0..8).collect();
+
+    {
+        let (left, right) = values.split_at_mut(4);
+        let left = left.as_par_index();
+        let right = right.as_par_index();
+        scope(|s| {
+            s.spawn(|| unsafe { left.zip_apply(&delta[..4], |x, d| *x += d) });
+            s.spawn(|| unsafe { right.zip_apply_unchecked(&delta[4..], |x, d| *x += 10 * d) });
+        });
+    }
+
+    assert_eq!(values, vec![1, 2, 3, 4, 14, 15, 16, 17]);
+}
+
+#[test]
+#[should_panic(
+    expected = "other should have the same length as the collection. Got other of length 2 for a collection of length 3"
+)]
+fn zip_apply_len_mismatch() {
+    let mut values = [1, 2, 3];
+    let view = values.as_par_index();
+    unsafe { view.zip_apply(&[1, 2], |x, y| *x += y) };
+}
+
+#[test]
+fn view_in_struct_field() {
+    struct Rows<'a> {
+        view: UnsafeChunkView<'a, u32>,
+        offsets: Vec<usize>,
+    }
+
+    let mut v = vec![0; 6];
+
+    {
+        let rows = Rows {
+            view: v.as_par_chunk_index(3),
+            offsets: vec![0, 2],
+        };
+        scope(|s| {
+            for (i, &offset) in rows.offsets.iter().enumerate() {
+                let rows = &rows;
+                s.spawn(move || unsafe {
+                    rows.view.get_mut(i)[offset] = 42;
+                });
+            }
+        });
+    }
+
+    assert_eq!(v, vec![42, 0, 0, 0, 0, 42]);
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "bytemuck")]
+
+use par_slice::*;
+use std::thread::scope;
+
+#[test]
+fn as_bytes_after_parallel_fill() {
+    let slice = vec![0_u32; 2].into_par_index_no_ref();
+
+    scope(|s| {
+        s.spawn(|| unsafe { slice.set_value(0, 0x0403_0201) });
+        s.spawn(|| unsafe { slice.set_value(1, 0x0807_0605) });
+    });
+
+    let bytes = slice.as_bytes();
+    assert_eq!(bytes.len(), 2 * size_of::<u32>());
+    assert_eq!(
+        u32::from_le_bytes(bytes[..4].try_into().unwrap()),
+        u32::from_le(0x0403_0201)
+    );
+    assert_eq!(
+        bytes,
+        &[0x0403_0201_u32.to_ne_bytes(), 0x0807_0605_u32.to_ne_bytes()].concat()
+    );
+}
+
+#[test]
+fn as_bytes_mut() {
+    let mut slice = ParSlice::new_aligned::<64, u32>(2);
+
+    slice.as_bytes_mut()[4..].copy_from_slice(&42_u32.to_le_bytes());
+
+    assert_eq!(slice.into().as_ref(), &[0, u32::from_le(42)]);
+}
+
+#[test]
+fn new_zeroed_large() {
+    let len = 1 << 20;
+    let slice = ParSlice::new_zeroed::<u32>(len);
+
+    assert_eq!(slice.len(), len);
+    assert!(slice.as_slice().iter().all(|&x| x == 0));
+
+    scope(|s| {
+        s.spawn(|| unsafe { *slice.get_mut(0) = 42 });
+        s.spawn(|| unsafe { *slice.get_mut(len - 1) = 69 });
+    });
+
+    let boxed = slice.into_boxed_slice();
+    assert_eq!((boxed[0], boxed[1], boxed[len - 1]), (42, 0, 69));
+}
+
+#[test]
+fn new_zeroed_empty() {
+    let slice = ParSlice::new_zeroed::<u64>(0);
+    assert!(slice.is_empty());
+
+    let slice = ParSlice::new_zeroed::<()>(3);
+    assert_eq!(slice.len(), 3);
+}
@@ -0,0 +1,103 @@
+#![cfg(feature = "checked")]
+
+use par_slice::*;
+
+//
+// Test element access
+//
+
+#[test]
+#[should_panic(expected = "Index 3 invalid for slice of len 3")]
+fn get_mut_ptr_unchecked_out_of_bounds() {
+    let slice = vec![0; 3].into_pointer_par_index();
+    let _ = unsafe { slice.get_mut_ptr_unchecked(3) };
+}
+
+#[test]
+#[should_panic(expected = "Index 4 invalid for slice of len 3")]
+fn get_value_unchecked_out_of_bounds() {
+    let slice = vec![0; 3].into_par_index_no_ref();
+    let _ = unsafe { slice.get_value_unchecked(4) };
+}
+
+#[test]
+#[should_panic(expected = "Index 3 invalid for slice of len 3")]
+fn get_mut_unchecked_out_of_bounds_borrowed() {
+    let mut v = [0; 3];
+    let slice = v.as_par_index();
+    unsafe { *slice.get_mut_unchecked(3) = 42 };
+}
+
+//
+// Test chunk access
+//
+
+#[test]
+#[should_panic(expected = "Index 2 invalid for slice of len 2")]
+fn get_unchecked_chunk_out_of_bounds() {
+    let slice = vec![0; 6].into_par_chunk_index(3);
+    let _ = unsafe { slice.get_unchecked(2) };
+}
+
+#[test]
+#[should_panic(expected = "Index 3 invalid for slice of len 3")]
+fn set_values_unchecked_out_of_bounds() {
+    let slice = vec![0; 6].into_par_chunk_index_no_ref(2);
+    unsafe { slice.set_values_unchecked(3, &[1, 2]) };
+}
+
+#[test]
+#[should_panic(
+    expected = "value should have the same length as the chunk. Got a value of length 3 for a chunk of length 2"
+)]
+fn set_values_unchecked_incompatible_chunk() {
+    let slice = vec![0; 6].into_par_chunk_index_no_ref(2);
+    unsafe { slice.set_values_unchecked(0, &[1, 2, 3]) };
+}
+
+#[test]
+#[should_panic(
+    expected = "array should have the same length as the chunk. Got an array of length 3 for a chunk of length 2"
+)]
+fn get_array_mut_unchecked_incompatible_chunk() {
+    let slice = vec![0; 6].into_par_chunk_index(2);
+    let _ = unsafe { slice.get_array_mut_unchecked::<3>(0) };
+}
+
+#[test]
+#[should_panic(expected = "Index 2 invalid for slice of len 2")]
+fn get_mut_element_ptr_unchecked_out_of_bounds() {
+    let slice = vec![0; 6].into_pointer_par_chunk_index(2);
+    let _ = unsafe { slice.get_mut_element_ptr_unchecked(0, 2) };
+}
+
+#[test]
+#[should_panic(expected = "Cell (1, 2) invalid for grid of 2 rows and 2 columns")]
+fn grid_get_mut_ptr_unchecked_out_of_bounds() {
+    let grid = PointerGrid::new(vec![0; 4].into_pointer_par_index(), 2);
+    let _ = unsafe { grid.get_mut_ptr_unchecked(1, 2) };
+}
+
+#[test]
+#[should_panic(expected = "Index 2 invalid for slice of len 2")]
+fn get_chunk_element_unchecked_out_of_bounds() {
+    let slice = vec![0; 4].into_par_chunk_index_no_ref(2);
+    let _ = unsafe { slice.get_chunk_element_unchecked(0, 2) };
+}
+
+#[test]
+#[should_panic(expected = "Index 2 invalid for slice of len 2")]
+fn chunk_writer_past_the_end() {
+    let slice = vec![0; 4].into_pointer_par_chunk_index(2);
+    let mut writer = slice.sequential_chunk_writer();
+    for _ in 0..3 {
+        let _ = unsafe { writer.next_chunk() };
+    }
+}
+
+#[test]
+#[should_panic(expected = "Index 3 invalid for slice of len 3")]
+fn get_mut_at_unchecked_out_of_bounds() {
+    let slice = vec![0; 6].into_par_chunk_index(3);
+    let _ = unsafe { slice.get_mut_at_unchecked(0, 3) };
+}
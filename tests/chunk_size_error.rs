@@ -0,0 +1,143 @@
+use par_slice::*;
+
+//
+// Test borrowed views
+//
+
+#[test]
+fn try_as_par_chunk_index_ok() {
+    let mut v = [0; 6];
+
+    {
+        let view = v.try_as_par_chunk_index(3).unwrap();
+        assert_eq!(view.num_chunks(), 2);
+        unsafe { view.get_mut(1).copy_from_slice(&[1, 2, 3]) };
+    }
+
+    assert_eq!(v, [0, 0, 0, 1, 2, 3]);
+}
+
+#[test]
+fn try_as_par_chunk_index_err() {
+    let mut v = [0; 7];
+
+    let error = v.try_as_par_chunk_index(3).err().unwrap();
+
+    assert_eq!(
+        error,
+        ChunkSizeError {
+            len: 7,
+            chunk_size: 3
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "chunk_size should be a divisor of len. Got a chunk_size of 3 for a collection of len 7"
+    );
+}
+
+#[test]
+fn try_as_par_chunk_index_zero() {
+    let mut v = [0; 4];
+
+    let error = v.try_as_par_chunk_index(0).err().unwrap();
+
+    assert_eq!(
+        error,
+        ChunkSizeError {
+            len: 4,
+            chunk_size: 0
+        }
+    );
+}
+
+//
+// Test owned collections
+//
+
+#[test]
+fn try_into_par_chunk_index_ok() {
+    let slice = vec![0; 6].try_into_par_chunk_index(2).unwrap();
+
+    unsafe { slice.get_mut(2).copy_from_slice(&[1, 2]) };
+
+    assert_eq!(slice.num_chunks(), 3);
+    assert_eq!(slice.into(), vec![0, 0, 0, 0, 1, 2]);
+}
+
+#[test]
+fn try_into_par_chunk_index_err() {
+    let error = vec![0; 5]
+        .into_boxed_slice()
+        .try_into_par_chunk_index(2)
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        error,
+        ChunkSizeError {
+            len: 5,
+            chunk_size: 2
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "chunk_size should be a divisor of len. Got a chunk_size of 2 for a collection of len 5"
+    );
+}
+
+#[test]
+fn try_into_par_chunk_index_question_mark() {
+    fn split(v: Vec<u8>, chunk_size: usize) -> Result<usize, Box<dyn std::error::Error>> {
+        let slice = v.try_into_par_chunk_index(chunk_size)?;
+        Ok(slice.num_chunks())
+    }
+
+    assert_eq!(split(vec![0; 8], 4).unwrap(), 2);
+    assert!(split(vec![0; 8], 3).is_err());
+}
+
+//
+// Test constructors
+//
+
+#[test]
+fn try_chunk_constructors_err() {
+    let expected = ChunkSizeError {
+        len: 7,
+        chunk_size: 2,
+    };
+
+    assert_eq!(
+        PointerParSlice::try_new_chunks::<u8>(7, 2).err().unwrap(),
+        expected
+    );
+    assert_eq!(
+        NoRefParSlice::try_chunks_with_value(0_u8, 7, 2)
+            .err()
+            .unwrap(),
+        expected
+    );
+    assert_eq!(
+        ParSlice::try_chunks_with_closure(|i| i, 7, 2)
+            .err()
+            .unwrap(),
+        expected
+    );
+    assert!(ParSlice::try_new_chunks::<u8>(7, 0).is_err());
+}
+
+#[test]
+fn try_chunk_constructors_ok() {
+    let pointer_slice = PointerParSlice::try_chunks_with_closure(|i| i, 6, 3).unwrap();
+    let no_ref_slice = NoRefParSlice::try_new_chunks(6, 2).unwrap();
+    let unsafe_slice = ParSlice::try_chunks_with_value(1, 6, 3).unwrap();
+
+    unsafe { no_ref_slice.set_values(2, &[4, 2]) };
+    unsafe { unsafe_slice.get_mut(1).fill(2) };
+
+    assert_eq!(pointer_slice.num_chunks(), 2);
+    assert_eq!(pointer_slice.into().as_ref(), &[0, 1, 2, 3, 4, 5]);
+    assert_eq!(no_ref_slice.into().as_ref(), &[0, 0, 0, 0, 4, 2]);
+    assert_eq!(unsafe_slice.into().as_ref(), &[1, 1, 1, 2, 2, 2]);
+}
@@ -0,0 +1,64 @@
+use par_slice::*;
+
+struct PageId(u16);
+impl_as_usize!(PageId => u16);
+
+#[test]
+fn newtype_index() {
+    let slice = IndexWrapper::new::<PageId>(vec![0; 4].into_par_index());
+
+    unsafe {
+        *slice.get_mut(PageId(2)) = 42;
+    }
+
+    assert_eq!(unsafe { *slice.get(PageId(2)) }, 42);
+    assert_eq!(slice.into_inner().into(), vec![0, 0, 42, 0]);
+}
+
+#[test]
+#[should_panic(expected = "Index 4 invalid for slice of len 4")]
+fn newtype_index_panic() {
+    let slice = IndexWrapper::new::<PageId>(vec![0; 4].into_par_index_no_ref());
+
+    unsafe {
+        slice.set_value(PageId(4), 42);
+    }
+}
+
+#[test]
+fn typed_index_without_wrapper() {
+    let slice = vec![0_u32; 4].into_par_index_no_ref();
+
+    unsafe {
+        slice.set_value_at(3_u16, 42);
+        slice.set_value_at(PageId(1), 69);
+    }
+
+    assert_eq!(unsafe { slice.get_value_at(3_u16) }, 42);
+    assert_eq!(unsafe { slice.get_value_at(PageId(1)) }, 69);
+    assert_eq!(slice.into(), vec![0, 69, 0, 42]);
+}
+
+#[test]
+#[should_panic(expected = "Index 4 invalid for slice of len 4")]
+fn typed_index_without_wrapper_panic() {
+    let slice = vec![0_u32; 4].into_par_index_no_ref();
+
+    unsafe {
+        let _ = slice.get_value_at(4_u16);
+    }
+}
+
+#[test]
+fn char_index() {
+    let table = IndexWrapper::new::<char>(vec![false; 0x110000].into_par_index_no_ref());
+
+    unsafe {
+        table.set_value('a', true);
+        table.set_value(char::MAX, true);
+    }
+
+    assert!(unsafe { table.get_value('a') });
+    assert!(unsafe { table.get_value('\u{10FFFF}') });
+    assert!(!unsafe { table.get_value('b') });
+}
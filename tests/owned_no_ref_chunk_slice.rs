@@ -75,6 +75,43 @@ fn no_thread_checked_panic_set_chunk_size() {
     }
 }
 
+#[test]
+fn no_thread_get_values_vec() {
+    let slice = vec![1, 2, 3, 4].into_par_chunk_index_no_ref(2);
+
+    assert_eq!(unsafe { slice.get_values_vec(0) }, vec![1, 2]);
+    assert_eq!(unsafe { slice.get_values_vec_unchecked(1) }, vec![3, 4]);
+}
+
+#[test]
+#[should_panic(expected = "Index 2 invalid for slice of len 2")]
+fn no_thread_checked_panic_get_values_vec() {
+    let slice = vec![1, 2, 3, 4].into_par_chunk_index_no_ref(2);
+    let _ = unsafe { slice.get_values_vec(2) };
+}
+
+#[test]
+fn no_thread_get_values_uninit() {
+    let slice = vec![1, 2, 3, 4].into_par_chunk_index_no_ref(2);
+    let mut buf = [std::mem::MaybeUninit::uninit(); 2];
+
+    let values = unsafe { slice.get_values_uninit(1, &mut buf) };
+    assert_eq!(values, &[3, 4]);
+
+    values[0] = 42;
+    assert_eq!(unsafe { buf[0].assume_init() }, 42);
+}
+
+#[test]
+#[should_panic(
+    expected = "value should have the same length as the chunk. Got a value of length 3 for a chunk of length 2"
+)]
+fn no_thread_checked_panic_get_values_uninit() {
+    let slice = vec![1, 2, 3, 4].into_par_chunk_index_no_ref(2);
+    let mut buf = [std::mem::MaybeUninit::uninit(); 3];
+    let _ = unsafe { slice.get_values_uninit(0, &mut buf) };
+}
+
 //
 // Test with a single thread
 //
@@ -236,3 +273,28 @@ fn multithread_checked_panic_mut() {
 
     assert_eq!(slice.into(), vec![1, 2, 3, 4]);
 }
+
+#[test]
+fn chunk_element() {
+    let slice = vec![0, 1, 2, 3, 4, 5].into_par_chunk_index_no_ref(2);
+
+    assert_eq!(unsafe { slice.get_chunk_element(1, 1) }, 3);
+    unsafe { slice.set_chunk_element(2, 0, 42) };
+    assert_eq!(unsafe { slice.get_chunk_element_unchecked(2, 0) }, 42);
+
+    assert_eq!(slice.into(), vec![0, 1, 2, 3, 42, 5]);
+}
+
+#[test]
+#[should_panic(expected = "Index 3 invalid for slice of len 3")]
+fn get_chunk_element_chunk_panic() {
+    let slice = vec![0; 6].into_par_chunk_index_no_ref(2);
+    let _ = unsafe { slice.get_chunk_element(3, 0) };
+}
+
+#[test]
+#[should_panic(expected = "Index 2 invalid for slice of len 2")]
+fn set_chunk_element_offset_panic() {
+    let slice = vec![0; 6].into_par_chunk_index_no_ref(2);
+    unsafe { slice.set_chunk_element(0, 2, 42) };
+}
@@ -1,5 +1,13 @@
 use par_slice::*;
-use std::thread::scope;
+use std::{
+    mem::MaybeUninit,
+    ptr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread::scope,
+};
 
 //
 // Test without threads
@@ -35,7 +43,7 @@ fn no_thread_checked_panic_get() {
     let slice = vec![1, 2, 3].into_par_index_no_ref();
 
     unsafe {
-        slice.get_value(42);
+        let _ = slice.get_value(42);
     }
 }
 
@@ -99,7 +107,7 @@ fn single_thread_checked_panic_get() {
 
     scope(|s| {
         s.spawn(|| {
-            unsafe { slice.get_value(42) };
+            let _ = unsafe { slice.get_value(42) };
         })
         .join()
         .unwrap_err();
@@ -178,7 +186,7 @@ fn multithread_checked_panic_get() {
             unsafe { slice.set_value(2, 42) };
         });
         s.spawn(|| {
-            unsafe { slice.get_value(42) };
+            let _ = unsafe { slice.get_value(42) };
         })
         .join()
         .unwrap_err();
@@ -204,3 +212,211 @@ fn multithread_checked_panic_mut() {
 
     assert_eq!(slice.into(), vec![1, 2, 3]);
 }
+
+//
+// Test reads through an out-pointer
+//
+
+#[test]
+fn get_value_into() {
+    let slice = vec![1, 2, 3].into_par_index_no_ref();
+    let mut first = 0;
+    let mut last = 0;
+
+    unsafe {
+        slice.get_value_into(0, &mut first);
+        slice.get_value_into_unchecked(2, &mut last as *mut i32);
+    }
+
+    assert_eq!((first, last), (1, 3));
+}
+
+#[test]
+fn get_value_into_multithread() {
+    let slice = vec![1, 2, 3, 4].into_par_index_no_ref();
+
+    let (first, second) = scope(|s| {
+        let first = s.spawn(|| {
+            let mut out = 0;
+            unsafe { slice.get_value_into(1, &mut out) };
+            out
+        });
+        let second = s.spawn(|| {
+            let mut out = 0;
+            unsafe { slice.get_value_into(3, &mut out) };
+            out
+        });
+        (first.join().unwrap(), second.join().unwrap())
+    });
+
+    assert_eq!((first, second), (2, 4));
+}
+
+#[test]
+#[should_panic(expected = "Index 3 invalid for slice of len 3")]
+fn get_value_into_panic() {
+    let slice = vec![1, 2, 3].into_par_index_no_ref();
+    let mut out = 0;
+
+    unsafe {
+        slice.get_value_into(3, &mut out);
+    }
+}
+
+//
+// Test volatile accesses
+//
+
+#[test]
+fn volatile_round_trip() {
+    let slice = vec![0_u32; 4].into_par_index_no_ref();
+
+    unsafe {
+        slice.set_value_volatile(1, 0xDEAD_BEEF);
+        slice.set_value_volatile_unchecked(3, 42);
+    }
+
+    assert_eq!(unsafe { slice.get_value_volatile(1) }, 0xDEAD_BEEF);
+    assert_eq!(unsafe { slice.get_value_volatile_unchecked(3) }, 42);
+    assert_eq!(unsafe { slice.get_value(1) }, 0xDEAD_BEEF);
+    assert_eq!(slice.into(), vec![0, 0xDEAD_BEEF, 0, 42]);
+}
+
+#[test]
+#[should_panic(expected = "Index 4 invalid for slice of len 4")]
+fn get_value_volatile_panic() {
+    let slice = vec![0_u32; 4].into_par_index_no_ref();
+    let _ = unsafe { slice.get_value_volatile(4) };
+}
+
+#[test]
+#[should_panic(expected = "Index 4 invalid for slice of len 4")]
+fn set_value_volatile_panic() {
+    let slice = vec![0_u32; 4].into_par_index_no_ref();
+    unsafe { slice.set_value_volatile(4, 1) };
+}
+
+//
+// Test moving elements out
+//
+
+#[test]
+fn take_string() {
+    let slice = vec![String::from("foo"), String::from("bar")].into_par_index_no_ref();
+
+    assert_eq!(unsafe { slice.take(1) }, "bar");
+    assert_eq!(unsafe { slice.take_unchecked(0) }, "foo");
+
+    assert_eq!(slice.into(), vec![String::new(), String::new()]);
+}
+
+#[test]
+fn take_multithread() {
+    let slice = (0..4)
+        .map(|i| vec![i; i])
+        .collect::<Vec<_>>()
+        .into_par_index_no_ref();
+
+    let lens: Vec<usize> = scope(|s| {
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let slice = &slice;
+                s.spawn(move || unsafe { slice.take(i) }.len())
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+
+    assert_eq!(lens, vec![0, 1, 2, 3]);
+    let values: Vec<Vec<usize>> = slice.into();
+    assert!(values.iter().all(Vec::is_empty));
+}
+
+#[test]
+#[should_panic(expected = "Index 2 invalid for slice of len 2")]
+fn take_panic() {
+    let slice = vec![String::new(); 2].into_par_index_no_ref();
+    let _ = unsafe { slice.take(2) };
+}
+
+//
+// Test uninitialized construction
+//
+
+#[test]
+fn new_uninit_multithread() {
+    let slice = NoRefParSlice::new_uninit(4);
+
+    scope(|s| {
+        for i in 0..4 {
+            let slice = &slice;
+            s.spawn(move || unsafe { slice.set_value(i, MaybeUninit::new(i * 10)) });
+        }
+    });
+
+    // Every element was written by exactly one thread
+    let slice = unsafe { slice.assume_init() };
+    assert_eq!(slice.into_boxed_slice().into_vec(), vec![0, 10, 20, 30]);
+}
+
+//
+// Test writes that do not drop the overwritten element
+//
+
+struct Droppable(Arc<AtomicUsize>);
+
+impl Drop for Droppable {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn write_into_moved_out_slot() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let slice = VecPointerParIndex::new(
+        (0..2)
+            .map(|_| Droppable(counter.clone()))
+            .collect::<Vec<_>>(),
+    );
+
+    // Move the element out, leaving its slot logically uninitialized
+    let moved = unsafe { ptr::read(slice.get_mut_ptr(1)) };
+    drop(moved);
+    assert_eq!(counter.load(Ordering::Relaxed), 1);
+
+    let slice = slice.into_no_ref();
+    unsafe { slice.write(1, Droppable(counter.clone())) };
+    assert_eq!(counter.load(Ordering::Relaxed), 1);
+
+    drop(slice);
+    assert_eq!(counter.load(Ordering::Relaxed), 3);
+}
+
+#[test]
+fn write_does_not_drop_set_value_does() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let slice = (0..2)
+        .map(|_| Droppable(counter.clone()))
+        .collect::<Vec<_>>()
+        .into_par_index_no_ref();
+
+    unsafe { slice.set_value(0, Droppable(counter.clone())) };
+    assert_eq!(counter.load(Ordering::Relaxed), 1);
+
+    // The overwritten element is leaked
+    let leaked = Arc::new(AtomicUsize::new(0));
+    unsafe { slice.write_unchecked(1, Droppable(leaked.clone())) };
+    assert_eq!(counter.load(Ordering::Relaxed), 1);
+
+    drop(slice);
+    assert_eq!(counter.load(Ordering::Relaxed), 2);
+    assert_eq!(leaked.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+#[should_panic(expected = "Index 2 invalid for slice of len 2")]
+fn write_panic() {
+    let slice = vec![0; 2].into_par_index_no_ref();
+    unsafe { slice.write(2, 42) };
+}
@@ -40,7 +40,7 @@ fn no_thread_checked() {
 fn no_thread_checked_panic() {
     let slice = vec![1, 2, 3, 4].into_pointer_par_chunk_index(2);
 
-    slice.get_ptr(42);
+    let _ = slice.get_ptr(42);
 }
 
 #[test]
@@ -48,7 +48,7 @@ fn no_thread_checked_panic() {
 fn no_thread_checked_panic_mut() {
     let slice = vec![1, 2, 3, 4].into_pointer_par_chunk_index(2);
 
-    slice.get_mut_ptr(69);
+    let _ = slice.get_mut_ptr(69);
 }
 
 //
@@ -101,7 +101,7 @@ fn single_thread_checked_panic_get() {
 
     scope(|s| {
         s.spawn(|| {
-            slice.get_ptr(42);
+            let _ = slice.get_ptr(42);
         })
         .join()
         .unwrap_err();
@@ -126,7 +126,7 @@ fn single_thread_checked_panic_set() {
         .join()
         .unwrap();
         s.spawn(|| {
-            slice.get_mut_ptr(42);
+            let _ = slice.get_mut_ptr(42);
         })
         .join()
         .unwrap_err();
@@ -180,7 +180,7 @@ fn multithread_checked_panic_get() {
             unsafe { (*slice.get_mut_ptr(1)).copy_from_slice(&[42, 69]) };
         });
         s.spawn(|| {
-            slice.get_ptr(42);
+            let _ = slice.get_ptr(42);
         })
         .join()
         .unwrap_err();
@@ -198,7 +198,7 @@ fn multithread_checked_panic_mut() {
             assert_eq!(unsafe { &*slice.get_ptr(0) }, &[1, 2]);
         });
         s.spawn(|| {
-            slice.get_mut_ptr(42);
+            let _ = slice.get_mut_ptr(42);
         })
         .join()
         .unwrap_err();
@@ -206,3 +206,108 @@ fn multithread_checked_panic_mut() {
 
     assert_eq!(slice.into(), vec![1, 2, 3, 4]);
 }
+
+//
+// Test element pointers
+//
+
+#[test]
+fn element_ptr() {
+    let slice = vec![1, 2, 3, 4, 5, 6].into_pointer_par_chunk_index(2);
+
+    for chunk in 0..3 {
+        for offset in 0..2 {
+            let expected = unsafe { (slice.get_mut_ptr(chunk) as *mut i32).add(offset) };
+            assert_eq!(slice.get_mut_element_ptr(chunk, offset), expected);
+            assert_eq!(
+                unsafe { slice.get_mut_element_ptr_unchecked(chunk, offset) },
+                expected
+            );
+        }
+    }
+
+    unsafe { *slice.get_mut_element_ptr(1, 1) = 42 };
+
+    assert_eq!(slice.into(), vec![1, 2, 3, 42, 5, 6]);
+}
+
+#[test]
+#[should_panic(expected = "Index 3 invalid for slice of len 3")]
+fn element_ptr_panic_chunk() {
+    let slice = vec![1, 2, 3, 4, 5, 6].into_pointer_par_chunk_index(2);
+
+    let _ = slice.get_mut_element_ptr(3, 0);
+}
+
+#[test]
+#[should_panic(expected = "Index 2 invalid for slice of len 2")]
+fn element_ptr_panic_offset() {
+    let slice = vec![1, 2, 3, 4, 5, 6].into_pointer_par_chunk_index(2);
+
+    let _ = slice.get_mut_element_ptr(0, 2);
+}
+
+#[test]
+fn full_ptr() {
+    let slice = vec![1, 2, 3, 4, 5, 6].into_pointer_par_chunk_index(2);
+    let ptr = slice.full_mut_ptr();
+
+    for chunk in 0..3 {
+        assert_eq!(
+            unsafe { ptr.add(chunk * slice.chunk_size()) },
+            slice.get_mut_ptr(chunk) as *mut i32
+        );
+    }
+
+    let all = unsafe { std::slice::from_raw_parts_mut(ptr, slice.num_elements()) };
+    all.reverse();
+
+    assert_eq!(slice.into(), vec![6, 5, 4, 3, 2, 1]);
+}
+
+#[test]
+fn chunk_of_ptr() {
+    let slice = vec![0_u32; 6].into_pointer_par_chunk_index(3);
+    let other = [0_u32; 6];
+
+    for chunk in 0..2 {
+        for offset in 0..3 {
+            let ptr = slice.get_mut_element_ptr(chunk, offset);
+            assert_eq!(slice.chunk_of_ptr(ptr), Some(chunk));
+        }
+        assert_eq!(
+            slice.chunk_of_ptr(slice.get_ptr(chunk) as *const u32),
+            Some(chunk)
+        );
+    }
+    assert_eq!(slice.chunk_of_ptr(&other[0]), None);
+    assert_eq!(
+        slice.chunk_of_ptr(unsafe { slice.full_mut_ptr().add(6) }),
+        None
+    );
+}
+
+#[test]
+fn sequential_chunk_writer() {
+    let slice = vec![0; 12].into_pointer_par_chunk_index(3);
+    let mut writer = slice.sequential_chunk_writer();
+
+    for chunk in 0..4 {
+        assert_eq!(writer.remaining(), 4 - chunk);
+        let ptr = unsafe { writer.next_chunk() };
+        assert_eq!(ptr, slice.get_mut_ptr(chunk));
+        unsafe { (*ptr).fill(chunk) };
+    }
+    assert_eq!(writer.remaining(), 0);
+
+    assert_eq!(slice.into(), vec![0, 0, 0, 1, 1, 1, 2, 2, 2, 3, 3, 3]);
+}
+
+#[test]
+fn full_ptr_empty() {
+    let slice = Vec::<u32>::new().into_pointer_par_chunk_index(2);
+    let ptr = slice.full_mut_ptr();
+
+    assert!(!ptr.is_null());
+    assert_eq!(slice.chunk_of_ptr(ptr), None);
+}
@@ -34,7 +34,7 @@ fn no_thread_checked() {
 fn no_thread_checked_panic() {
     let slice = vec![1, 2, 3].into_pointer_par_index();
 
-    slice.get_ptr(42);
+    let _ = slice.get_ptr(42);
 }
 
 #[test]
@@ -42,7 +42,7 @@ fn no_thread_checked_panic() {
 fn no_thread_checked_panic_mut() {
     let slice = vec![1, 2, 3].into_pointer_par_index();
 
-    slice.get_ptr(69);
+    let _ = slice.get_ptr(69);
 }
 
 //
@@ -95,7 +95,7 @@ fn single_thread_checked_panic() {
 
     scope(|s| {
         s.spawn(|| {
-            slice.get_ptr(42);
+            let _ = slice.get_ptr(42);
         })
         .join()
         .unwrap_err();
@@ -174,7 +174,7 @@ fn multithread_checked_panic() {
             unsafe { *slice.get_mut_ptr(2) = 42 };
         });
         s.spawn(|| {
-            slice.get_ptr(42);
+            let _ = slice.get_ptr(42);
         })
         .join()
         .unwrap_err();
@@ -200,3 +200,162 @@ fn multithread_checked_panic_mut() {
 
     assert_eq!(slice.into(), vec![1, 2, 3]);
 }
+
+//
+// Test non-null pointers
+//
+
+#[test]
+fn non_null() {
+    let slice = vec![1, 2, 3].into_pointer_par_index();
+
+    for i in 0..3 {
+        assert_eq!(slice.get_non_null(i).as_ptr(), slice.get_mut_ptr(i));
+        assert_eq!(
+            unsafe { slice.get_non_null_unchecked(i) }.as_ptr(),
+            slice.get_mut_ptr(i)
+        );
+    }
+
+    unsafe { *slice.get_non_null(1).as_ptr() = 42 };
+
+    assert_eq!(slice.into(), vec![1, 42, 3]);
+}
+
+#[test]
+#[should_panic(expected = "Index 3 invalid for slice of len 3")]
+fn non_null_panic() {
+    let slice = vec![1, 2, 3].into_pointer_par_index();
+
+    let _ = slice.get_non_null(3);
+}
+
+#[test]
+fn dangling_ptr_for_empty_slice() {
+    let slice = Vec::<u32>::new().into_pointer_par_index();
+
+    let ptr = slice.dangling_ptr();
+    assert!(!ptr.is_null());
+    assert_eq!(ptr as usize % std::mem::align_of::<u32>(), 0);
+
+    let empty = unsafe { std::slice::from_raw_parts_mut(ptr, slice.len()) };
+    assert!(empty.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Index 0 invalid for slice of len 0")]
+fn empty_slice_get_mut_ptr_panic() {
+    let slice = Vec::<u32>::new().into_pointer_par_index();
+
+    let _ = slice.get_mut_ptr(0);
+}
+
+#[test]
+fn byte_offset_of() {
+    let slice = vec![[0_u8; 3]; 4].into_pointer_par_index();
+
+    for i in 0..4 {
+        assert_eq!(slice.byte_offset_of(i), 3 * i);
+        assert_eq!(slice.byte_offset_of_unchecked(i), 3 * i);
+        assert_eq!(
+            slice.get_mut_ptr(i) as usize - slice.get_mut_ptr(0) as usize,
+            slice.byte_offset_of(i)
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "Index 4 invalid for slice of len 4")]
+fn byte_offset_of_panic() {
+    let slice = vec![0_u64; 4].into_pointer_par_index();
+
+    let _ = slice.byte_offset_of(4);
+}
+
+//
+// Test casts
+//
+
+#[test]
+fn cast() {
+    let slice = vec![0x8000_0000_u32, 0xFFFF_FFFE, 7].into_pointer_par_index();
+    let view = unsafe { slice.cast::<i32>() };
+
+    assert_eq!(view.len(), 3);
+    assert_eq!(unsafe { *view.get_ptr(0) }, i32::MIN);
+    assert_eq!(unsafe { *view.get_ptr(1) }, -2);
+    assert_eq!(view.get_mut_ptr(2) as *mut u32, slice.get_mut_ptr(2));
+
+    unsafe { *view.get_mut_ptr(2) = -1 };
+    drop(view);
+
+    assert_eq!(slice.into(), vec![0x8000_0000, 0xFFFF_FFFE, u32::MAX]);
+}
+
+#[test]
+#[should_panic(expected = "Index 3 invalid for slice of len 3")]
+fn cast_panic() {
+    let slice = vec![1_u32, 2, 3].into_pointer_par_index();
+    let view = unsafe { slice.cast::<i32>() };
+
+    let _ = view.get_ptr(3);
+}
+
+//
+// Test reverse pointer lookups
+//
+
+#[test]
+fn index_of_ptr() {
+    let slice = vec![0_u64; 5].into_pointer_par_index();
+    let other = [0_u64; 5];
+
+    for i in 0..5 {
+        assert_eq!(slice.index_of_ptr(slice.get_mut_ptr(i)), Some(i));
+    }
+    assert_eq!(slice.index_of_ptr(&other[3]), None);
+    assert_eq!(
+        slice.index_of_ptr(unsafe { (slice.get_ptr(4) as *const u8).add(8) } as *const u64),
+        None
+    );
+    assert_eq!(
+        slice.index_of_ptr(unsafe { (slice.get_ptr(1) as *const u8).add(3) } as *const u64),
+        None
+    );
+}
+
+#[test]
+fn index_of_ptr_sub_view() {
+    let slice = vec![1, 2, 3, 4].into_pointer_par_index();
+    let (_, tail) = slice.split_first().unwrap();
+
+    assert_eq!(tail.index_of_ptr(slice.get_ptr(3)), Some(2));
+    assert_eq!(tail.index_of_ptr(slice.get_ptr(0)), None);
+}
+
+#[test]
+fn index_of_ptr_empty() {
+    let slice = Vec::<u32>::new().into_pointer_par_index();
+
+    assert_eq!(slice.index_of_ptr(&42), None);
+}
+
+#[test]
+fn constructor_in_struct_field() {
+    struct Solver {
+        dists: OwnedPointerParIndex<i32>,
+        visited: VecPointerParIndex<bool>,
+    }
+
+    let solver = Solver {
+        dists: PointerParSlice::with_value(-1, 4),
+        visited: vec![false; 4].into_pointer_par_index(),
+    };
+    unsafe {
+        *solver.dists.get_mut_ptr(2) = 0;
+        *solver.visited.get_mut_ptr(2) = true;
+    }
+
+    assert_eq!(solver.dists.into().as_ref(), &[-1, -1, 0, -1]);
+    assert_eq!(solver.visited.into(), vec![false, false, true, false]);
+}
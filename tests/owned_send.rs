@@ -0,0 +1,49 @@
+use par_slice::*;
+use std::{
+    sync::mpsc::{channel, Receiver, Sender},
+    thread::spawn,
+};
+
+/// Creates a channel for values of the same (unnameable) type as `value`,
+/// asserting that the type is `Send`.
+fn channel_for<S: Send>(_value: &S) -> (Sender<S>, Receiver<S>) {
+    channel()
+}
+
+#[test]
+fn send_through_channel() {
+    let slice = vec![0; 4].into_par_index();
+
+    let (to_consumer, from_producer) = channel_for(&slice);
+    let (to_producer, from_consumer) = channel_for(&slice);
+
+    let consumer = spawn(move || {
+        let slice = from_producer.recv().unwrap();
+        unsafe { *slice.get_mut(1) = 42 };
+        to_producer.send(slice).unwrap();
+    });
+
+    unsafe { *slice.get_mut(0) = 69 };
+    to_consumer.send(slice).unwrap();
+    let slice = from_consumer.recv().unwrap();
+    consumer.join().unwrap();
+
+    assert_eq!(slice.into(), vec![69, 42, 0, 0]);
+}
+
+#[test]
+fn send_chunks_through_channel() {
+    let slice = vec![0; 4].into_par_chunk_index_no_ref(2);
+
+    let (sender, receiver) = channel_for(&slice);
+
+    let consumer = spawn(move || {
+        let slice = receiver.recv().unwrap();
+        unsafe { slice.set_values(1, &[1, 2]) };
+        slice.into()
+    });
+
+    sender.send(slice).unwrap();
+
+    assert_eq!(consumer.join().unwrap(), vec![0, 0, 1, 2]);
+}
@@ -0,0 +1,449 @@
+use par_slice::*;
+use std::{
+    io::{Cursor, ErrorKind, Read},
+    mem::MaybeUninit,
+    sync::Arc,
+    thread::scope,
+};
+
+//
+// Test read-only access after a parallel section
+//
+
+#[test]
+fn contains_after_parallel_fill() {
+    let dists = vec![-1; 6].into_par_index_no_ref();
+
+    scope(|s| {
+        s.spawn(|| {
+            for i in [0, 2, 4] {
+                unsafe { dists.set_value(i, i as i32) };
+            }
+        });
+        s.spawn(|| {
+            for i in [1, 3, 5] {
+                unsafe { dists.set_value(i, i as i32) };
+            }
+        });
+    });
+
+    assert!(!dists.contains(&-1));
+    assert!(dists.contains(&5));
+    assert_eq!(dists.into(), vec![0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn position_after_parallel_fill() {
+    let dists = vec![-1; 6].into_par_index();
+
+    scope(|s| {
+        s.spawn(|| unsafe { *dists.get_mut(0) = 0 });
+        s.spawn(|| unsafe { *dists.get_mut(1) = 1 });
+    });
+
+    assert_eq!(dists.position(|&d| d == -1), Some(2));
+    assert_eq!(dists.position(|&d| d == 1), Some(1));
+    assert_eq!(dists.position(|&d| d == 42), None);
+}
+
+#[test]
+fn fold_after_parallel_fill() {
+    let slice = vec![0_u32; 6].into_par_index_no_ref();
+
+    scope(|s| {
+        s.spawn(|| {
+            for i in 0..3 {
+                unsafe { slice.set_value(i, i as u32) };
+            }
+        });
+        s.spawn(|| {
+            for i in 3..6 {
+                unsafe { slice.set_value(i, i as u32) };
+            }
+        });
+    });
+
+    assert_eq!(slice.fold(0_usize, |acc, &x| acc + x as usize), 15);
+    assert_eq!(slice.fold(0, |acc, &x| acc.max(x)), 5);
+}
+
+#[test]
+fn windows_after_parallel_fill() {
+    let slice = ParSlice::new(4);
+
+    scope(|s| {
+        for i in 0..4 {
+            let slice = &slice;
+            s.spawn(move || unsafe { *slice.get_mut(i) = i + 1 });
+        }
+    });
+
+    let windows: Vec<&[usize]> = slice.windows(3).collect();
+    assert_eq!(windows, vec![&[1, 2, 3], &[2, 3, 4]]);
+    assert_eq!(slice.windows(5).count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "window size must be non-zero")]
+fn windows_zero_size() {
+    let slice = vec![1, 2, 3, 4].into_par_index();
+    let _ = slice.windows(0);
+}
+
+//
+// Test conversions
+//
+
+#[test]
+fn try_into_array() {
+    let slice = vec![1, 2, 3].into_par_index();
+
+    unsafe { *slice.get_mut(1) = 42 };
+
+    assert_eq!(slice.try_into_array::<3>().ok(), Some([1, 42, 3]));
+}
+
+#[test]
+fn try_into_array_len_mismatch() {
+    let slice = vec![1, 2, 3].into_pointer_par_index();
+
+    let slice = slice.try_into_array::<2>().unwrap_err();
+    let slice = slice.try_into_array::<4>().unwrap_err();
+
+    assert_eq!(slice.into(), vec![1, 2, 3]);
+}
+
+#[test]
+fn assume_init() {
+    let slice = Box::new_uninit_slice(4).into_par_index_no_ref();
+
+    scope(|s| {
+        s.spawn(|| {
+            for i in [0, 1] {
+                unsafe { slice.set_value(i, MaybeUninit::new(i * 2)) };
+            }
+        });
+        s.spawn(|| {
+            for i in [2, 3] {
+                unsafe { slice.set_value(i, MaybeUninit::new(i * 2)) };
+            }
+        });
+    });
+
+    let slice = unsafe { slice.assume_init() };
+    unsafe { *slice.get_mut(0) = 42 };
+
+    assert_eq!(slice.into().into_vec(), vec![42, 2, 4, 6]);
+}
+
+#[test]
+fn into_arc_after_parallel_fill() {
+    let slice = ParSlice::new(4);
+
+    scope(|s| {
+        for i in 0..4 {
+            let slice = &slice;
+            s.spawn(move || unsafe { *slice.get_mut(i) = i * i });
+        }
+    });
+    let shared: Arc<[usize]> = slice.into_arc();
+
+    scope(|s| {
+        for _ in 0..2 {
+            let shared = Arc::clone(&shared);
+            s.spawn(move || assert_eq!(shared.as_ref(), &[0, 1, 4, 9]));
+        }
+    });
+    assert_eq!(shared.as_ref(), &[0, 1, 4, 9]);
+}
+
+#[test]
+fn into_boxed_arrays() {
+    let slice = vec![0; 6].into_par_chunk_index(2);
+
+    scope(|s| {
+        for chunk in 0..3 {
+            let slice = &slice;
+            s.spawn(move || unsafe { slice.get_mut(chunk).fill(chunk as i32) });
+        }
+    });
+    let arrays: Box<[[i32; 2]]> = slice.into_boxed_arrays();
+
+    assert_eq!(arrays.as_ref(), &[[0, 0], [1, 1], [2, 2]]);
+}
+
+#[test]
+#[should_panic(
+    expected = "array should have the same length as the chunk. Got an array of length 3 for a chunk of length 2"
+)]
+fn into_boxed_arrays_len_mismatch() {
+    let slice = vec![0; 6].into_par_chunk_index(2);
+    let _: Box<[[i32; 3]]> = slice.into_boxed_arrays();
+}
+
+/// A collection whose safe [`SerialAccess`] implementation returns fewer elements than its chunks hold.
+struct LyingChunks;
+
+unsafe impl TrustedSizedCollection for LyingChunks {
+    fn len(&self) -> usize {
+        2
+    }
+}
+
+unsafe impl TrustedChunkSizedCollection for LyingChunks {
+    fn chunk_size(&self) -> usize {
+        2
+    }
+}
+
+impl SerialAccess<i32> for LyingChunks {
+    fn as_slice(&self) -> &[i32] {
+        &[]
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [i32] {
+        &mut []
+    }
+
+    fn into_boxed_slice(self) -> Box<[i32]> {
+        Box::new([1, 2, 3])
+    }
+}
+
+#[test]
+#[should_panic(expected = "chunk_size should be a divisor of len. 3 / 2 = 1 with a remainder of 1")]
+fn into_boxed_arrays_lying_implementor() {
+    let _: Box<[[i32; 2]]> = LyingChunks.into_boxed_arrays();
+}
+
+#[test]
+fn map_into_after_parallel_count() {
+    let counts = vec![0_u32; 4].into_par_index_no_ref();
+
+    scope(|s| {
+        for i in 0..4 {
+            let counts = &counts;
+            s.spawn(move || unsafe { counts.set_value(i, 1 << i) });
+        }
+    });
+    let reciprocals = counts.map_into(|count| 1.0 / count as f64);
+
+    unsafe { *reciprocals.get_mut(0) *= 2.0 };
+
+    assert_eq!(reciprocals.as_slice(), &[2.0, 0.5, 0.25, 0.125]);
+}
+
+//
+// Test copies into the collection
+//
+
+#[test]
+fn copy_from_slice() {
+    let mut slice = ParSlice::new(4);
+    slice.copy_from_slice(&[1, 2, 3, 4]);
+
+    scope(|s| {
+        s.spawn(|| unsafe { *slice.get_mut(0) *= 10 });
+        s.spawn(|| unsafe { *slice.get_mut(3) *= 10 });
+    });
+
+    assert_eq!(slice.into().as_ref(), &[10, 2, 3, 40]);
+}
+
+#[test]
+fn copy_from_slice_chunks() {
+    let mut slice = vec![0; 6].into_par_chunk_index_no_ref(3);
+    slice.copy_from_slice(&[1, 2, 3, 4, 5, 6]);
+
+    assert_eq!(unsafe { slice.get_values_vec(1) }, vec![4, 5, 6]);
+    assert!(slice.contains(&6));
+    assert_eq!(slice.into(), vec![1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+#[should_panic(
+    expected = "src should have the same length as the collection. Got a src of length 2 for a collection of length 3"
+)]
+fn copy_from_slice_len_mismatch() {
+    let mut slice = vec![0; 3].into_pointer_par_index();
+    slice.copy_from_slice(&[1, 2]);
+}
+
+#[test]
+fn swap_with_slice() {
+    let mut slice = vec![1, 2, 3, 4].into_par_index();
+    let mut buffer = vec![0; 4];
+
+    scope(|s| {
+        let slice = &slice;
+        s.spawn(move || unsafe { *slice.get_mut(0) = 10 });
+        s.spawn(move || unsafe { *slice.get_mut(3) = 40 });
+    });
+    slice.swap_with_slice(&mut buffer);
+
+    assert_eq!(buffer, vec![10, 2, 3, 40]);
+    assert_eq!(slice.into(), vec![0, 0, 0, 0]);
+}
+
+#[test]
+fn swap_with_slice_non_copy() {
+    let mut slice = vec![String::from("a"), String::from("b")].into_par_index_no_ref();
+    let mut buffer = vec![String::from("c"), String::from("d")];
+
+    slice.swap_with_slice(&mut buffer);
+
+    assert_eq!(buffer, vec!["a", "b"]);
+    assert_eq!(slice.into(), vec!["c", "d"]);
+}
+
+#[test]
+#[should_panic(
+    expected = "other should have the same length as the collection. Got an other of length 2 for a collection of length 3"
+)]
+fn swap_with_slice_len_mismatch() {
+    let mut slice = vec![0; 3].into_pointer_par_index();
+    slice.swap_with_slice(&mut [1, 2]);
+}
+
+//
+// Test serial transforms
+//
+
+#[test]
+fn prefix_sum_in_place() {
+    let mut slice = vec![1, 1, 1, 1].into_par_index();
+
+    slice.prefix_sum_in_place();
+
+    assert_eq!(slice.into(), vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn prefix_sum_in_place_after_parallel_fill() {
+    let mut slice = ParSlice::new(4);
+
+    scope(|s| {
+        for i in 0..4 {
+            let slice = &slice;
+            s.spawn(move || unsafe { *slice.get_mut(i) = i as f64 });
+        }
+    });
+    slice.prefix_sum_in_place();
+
+    assert_eq!(slice.as_slice(), &[0.0, 1.0, 3.0, 6.0]);
+}
+
+#[test]
+fn prefix_sum_in_place_empty() {
+    let mut slice = Vec::<u32>::new().into_par_index();
+
+    slice.prefix_sum_in_place();
+
+    assert!(slice.as_slice().is_empty());
+}
+
+#[test]
+fn apply() {
+    let mut slice = vec![1, 2, 3, 4, 5].into_par_index_no_ref();
+
+    slice.apply(|i, x| {
+        if i % 2 == 1 {
+            *x *= 2
+        }
+    });
+
+    assert_eq!(slice.into(), vec![1, 4, 3, 8, 5]);
+}
+
+#[test]
+fn apply_stateful() {
+    let mut slice = ParSlice::new(4);
+    let mut calls = Vec::new();
+
+    slice.apply(|i, x| {
+        calls.push(i);
+        *x = calls.len();
+    });
+
+    assert_eq!(calls, vec![0, 1, 2, 3]);
+    assert_eq!(slice.as_slice(), &[1, 2, 3, 4]);
+}
+
+#[test]
+fn assume_init_chunks() {
+    let slice = ParSlice::new_uninit_chunks(6, 3);
+
+    scope(|s| {
+        for chunk in 0..2 {
+            let slice = &slice;
+            s.spawn(move || {
+                for (offset, elem) in unsafe { slice.get_mut(chunk) }.iter_mut().enumerate() {
+                    elem.write(chunk * 10 + offset);
+                }
+            });
+        }
+    });
+
+    let slice = unsafe { slice.assume_init_chunks() };
+
+    assert_eq!(slice.chunk_size(), 3);
+    assert_eq!(unsafe { slice.get(1) }, &[10, 11, 12]);
+    assert_eq!(slice.into().as_ref(), &[0, 1, 2, 10, 11, 12]);
+}
+
+#[test]
+fn into_vec_exact_capacity() {
+    let mut v = Vec::with_capacity(100);
+    v.extend(0..10);
+
+    let collection = v.into_par_chunk_index(5);
+    unsafe { collection.get_mut(1)[0] = 42 };
+
+    let v = collection.into_vec_exact();
+    assert_eq!(v, vec![0, 1, 2, 3, 4, 42, 6, 7, 8, 9]);
+    assert_eq!(v.capacity(), v.len());
+}
+
+//
+// Test filling from a reader
+//
+
+#[test]
+fn fill_from_reader() {
+    let mut slice = vec![0_u8; 5].into_par_index();
+    let mut reader = Cursor::new(b"hello world".to_vec());
+
+    assert_eq!(slice.fill_from_reader(&mut reader).unwrap(), 5);
+    assert_eq!(slice.as_slice(), b"hello");
+
+    assert_eq!(slice.fill_from_reader(&mut reader).unwrap(), 5);
+    assert_eq!(slice.as_slice(), b" worl");
+
+    assert_eq!(slice.fill_from_reader(&mut reader).unwrap(), 1);
+    assert_eq!(slice.as_slice(), b"dworl");
+}
+
+#[test]
+fn fill_from_reader_chained() {
+    let mut slice = vec![0_u8; 6].into_par_chunk_index(3);
+    let mut reader = Cursor::new(b"abc").chain(Cursor::new(b"def"));
+
+    assert_eq!(slice.fill_from_reader(&mut reader).unwrap(), 6);
+    assert_eq!(slice.into(), b"abcdef");
+}
+
+#[test]
+fn fill_from_reader_error() {
+    struct Failing;
+
+    impl Read for Failing {
+        fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+            Err(ErrorKind::BrokenPipe.into())
+        }
+    }
+
+    let mut slice = vec![0_u8; 2].into_par_index_no_ref();
+    let err = slice.fill_from_reader(&mut Failing).unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::BrokenPipe);
+}
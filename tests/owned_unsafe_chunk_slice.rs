@@ -41,7 +41,7 @@ fn no_thread_checked_panic() {
     let slice = vec![1, 2, 3, 4].into_par_chunk_index(2);
 
     unsafe {
-        slice.get(42);
+        let _ = slice.get(42);
     }
 }
 
@@ -51,7 +51,7 @@ fn no_thread_checked_panic_mut() {
     let slice = vec![1, 2, 3, 4].into_par_chunk_index(2);
 
     unsafe {
-        slice.get_mut(69);
+        let _ = slice.get_mut(69);
     }
 }
 
@@ -105,7 +105,7 @@ fn single_thread_checked_panic_get() {
 
     scope(|s| {
         s.spawn(|| {
-            unsafe { slice.get(42) };
+            let _ = unsafe { slice.get(42) };
         })
         .join()
         .unwrap_err();
@@ -130,7 +130,7 @@ fn single_thread_checked_panic_set() {
         .join()
         .unwrap();
         s.spawn(|| {
-            unsafe { slice.get_mut(42) };
+            let _ = unsafe { slice.get_mut(42) };
         })
         .join()
         .unwrap_err();
@@ -184,7 +184,7 @@ fn multithread_checked_panic_get() {
             unsafe { slice.get_mut(1).copy_from_slice(&[42, 69]) };
         });
         s.spawn(|| {
-            unsafe { slice.get(42) };
+            let _ = unsafe { slice.get(42) };
         })
         .join()
         .unwrap_err();
@@ -202,7 +202,7 @@ fn multithread_checked_panic_mut() {
             assert_eq!(unsafe { slice.get(0) }, &[1, 2]);
         });
         s.spawn(|| {
-            unsafe { slice.get_mut(42) };
+            let _ = unsafe { slice.get_mut(42) };
         })
         .join()
         .unwrap_err();
@@ -210,3 +210,319 @@ fn multithread_checked_panic_mut() {
 
     assert_eq!(slice.into(), vec![1, 2, 3, 4]);
 }
+
+//
+// Test access to multiple chunks
+//
+
+#[test]
+fn get_mut_chunks() {
+    let slice = vec![0, 1, 2, 3, 4, 5, 6, 7].into_par_chunk_index(2);
+
+    {
+        let chunks = unsafe { slice.get_mut_chunks(1, 2) };
+        assert_eq!(chunks, &[2, 3, 4, 5]);
+        chunks[0] = 42;
+        chunks[3] = 69;
+    }
+
+    assert_eq!(unsafe { slice.get(1) }, &[42, 3]);
+    assert_eq!(unsafe { slice.get(2) }, &[4, 69]);
+    assert!(unsafe { slice.get_mut_chunks(4, 0) }.is_empty());
+    assert_eq!(slice.into(), vec![0, 1, 42, 3, 4, 69, 6, 7]);
+}
+
+#[test]
+fn multithread_get_mut_chunks() {
+    let slice = vec![0; 8].into_par_chunk_index(2);
+
+    scope(|s| {
+        s.spawn(|| unsafe { slice.get_mut_chunks_unchecked(0, 2).fill(1) });
+        s.spawn(|| unsafe { slice.get_mut_chunks_unchecked(2, 2).fill(2) });
+    });
+
+    assert_eq!(slice.into(), vec![1, 1, 1, 1, 2, 2, 2, 2]);
+}
+
+#[test]
+#[should_panic(expected = "Chunks 3..3+2 invalid for slice of 4 chunks")]
+fn get_mut_chunks_panic() {
+    let slice = vec![0; 8].into_par_chunk_index(2);
+    let _ = unsafe { slice.get_mut_chunks(3, 2) };
+}
+
+//
+// Test element to chunk index conversions
+//
+
+#[test]
+fn chunk_index_of_element() {
+    let slice = vec![0; 12].into_par_chunk_index(4);
+
+    for element in [0, 5, 6, 11] {
+        let chunk = slice.chunk_index_of(element);
+        let offset = slice.offset_in_chunk(element);
+        unsafe { slice.get_mut(chunk)[offset] = element };
+    }
+
+    assert_eq!(slice.chunk_index_of_unchecked(4), 1);
+    assert_eq!(slice.offset_in_chunk_unchecked(4), 0);
+    assert_eq!(slice.into(), vec![0, 0, 0, 0, 0, 5, 6, 0, 0, 0, 0, 11]);
+}
+
+#[test]
+#[should_panic(expected = "Index 12 invalid for slice of len 12")]
+fn chunk_index_of_panic() {
+    let slice = vec![0; 12].into_par_chunk_index(4);
+    slice.chunk_index_of(12);
+}
+
+#[test]
+#[should_panic(expected = "Index 13 invalid for slice of len 12")]
+fn offset_in_chunk_panic() {
+    let slice = vec![0; 12].into_par_chunk_index(4);
+    slice.offset_in_chunk(13);
+}
+
+//
+// Test even and odd chunk views
+//
+
+#[test]
+fn split_parity() {
+    let slice = vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9].into_par_chunk_index(2);
+    let (even, odd) = slice.split_parity();
+
+    assert_eq!((even.len(), odd.len()), (3, 2));
+    assert_eq!(even.chunk_size(), 2);
+    assert_eq!(unsafe { even.get(1) }, unsafe { slice.get(2) });
+    assert_eq!(unsafe { odd.get(1) }, unsafe { slice.get(3) });
+}
+
+#[test]
+fn split_parity_red_black() {
+    let slice = vec![0; 8].into_par_chunk_index(2);
+
+    for pass in 1..=2 {
+        let (even, odd) = slice.split_parity();
+        let view = if pass % 2 == 1 { even } else { odd };
+        scope(|s| {
+            for k in 0..view.len() {
+                s.spawn(move || unsafe { view.get_mut(k).fill(pass) });
+            }
+        });
+    }
+
+    assert_eq!(slice.into(), vec![1, 1, 2, 2, 1, 1, 2, 2]);
+}
+
+#[test]
+#[should_panic(expected = "Index 2 invalid for slice of len 2")]
+fn split_parity_panic() {
+    let slice = vec![0; 8].into_par_chunk_index(2);
+    let (_, odd) = slice.split_parity();
+    let _ = unsafe { odd.get(2) };
+}
+
+//
+// Test flat views
+//
+
+#[test]
+fn as_flat() {
+    let slice = vec![0, 1, 2, 3, 4, 5].into_par_chunk_index(2);
+
+    {
+        let flat = slice.as_flat();
+        assert_eq!(flat.len(), 6);
+        for i in 0..6 {
+            let element: *const usize = {
+                let element = unsafe { flat.get_mut(i) };
+                &*element
+            };
+            assert_eq!(element, &unsafe { slice.get(i / 2) }[i % 2] as *const usize);
+        }
+        unsafe { *flat.get_mut(3) = 42 };
+    }
+
+    assert_eq!(slice.into(), vec![0, 1, 2, 42, 4, 5]);
+}
+
+#[test]
+fn as_flat_of_parity_view() {
+    let slice = vec![0; 8].into_par_chunk_index(2);
+
+    {
+        let (_, odd) = slice.split_parity();
+        let flat = odd.as_flat();
+        assert_eq!(flat.len(), 4);
+        scope(|s| {
+            for i in 0..4 {
+                s.spawn(move || unsafe { *flat.get_mut(i) = i + 1 });
+            }
+        });
+        unsafe { *odd.get_mut_at(1, 0) *= 10 };
+    }
+
+    assert_eq!(slice.into(), vec![0, 0, 1, 2, 0, 0, 30, 4]);
+}
+
+#[test]
+#[should_panic(expected = "Index 6 invalid for slice of len 6")]
+fn as_flat_panic() {
+    let slice = vec![0; 6].into_par_chunk_index(3);
+    let flat = slice.as_flat();
+    let _ = unsafe { flat.get(6) };
+}
+
+//
+// Test array access
+//
+
+#[test]
+fn get_array_mut() {
+    let slice = vec![0_i32, 1, 2, 3, 4, 5, 6, 7].into_par_chunk_index(4);
+
+    {
+        let chunk: &mut [i32; 4] = unsafe { slice.get_array_mut(1) };
+        assert_eq!(chunk, &[4, 5, 6, 7]);
+        chunk.iter_mut().for_each(|x| *x *= 10);
+    }
+    {
+        let chunk: &mut [i32; 4] = unsafe { slice.get_array_mut_unchecked(0) };
+        chunk[0] = 42;
+    }
+
+    assert_eq!(slice.into(), vec![42, 1, 2, 3, 40, 50, 60, 70]);
+}
+
+#[test]
+#[should_panic(expected = "Index 2 invalid for slice of len 2")]
+fn get_array_mut_panic() {
+    let slice = vec![0; 8].into_par_chunk_index(4);
+    let _ = unsafe { slice.get_array_mut::<4>(2) };
+}
+
+#[test]
+#[should_panic(
+    expected = "array should have the same length as the chunk. Got an array of length 2 for a chunk of length 4"
+)]
+fn get_array_mut_len_panic() {
+    let slice = vec![0; 8].into_par_chunk_index(4);
+    let _ = unsafe { slice.get_array_mut::<2>(0) };
+}
+
+//
+// Test element access inside chunks
+//
+
+#[test]
+fn get_mut_at() {
+    let slice = vec![0, 1, 2, 3, 4, 5].into_par_chunk_index(2);
+
+    unsafe { *slice.get_mut_at(1, 0) = 42 };
+    assert_eq!(unsafe { slice.get(1) }, &[42, 3]);
+    assert_eq!(unsafe { *slice.get_mut_at_unchecked(1, 0) }, 42);
+
+    assert_eq!(slice.into(), vec![0, 1, 42, 3, 4, 5]);
+}
+
+#[test]
+fn get_mut_at_same_chunk_multithread() {
+    let slice = vec![0; 8].into_par_chunk_index(4);
+
+    scope(|s| {
+        for offset in 0..4 {
+            let slice = &slice;
+            s.spawn(move || unsafe { *slice.get_mut_at(1, offset) = offset + 1 });
+        }
+    });
+
+    assert_eq!(slice.into(), vec![0, 0, 0, 0, 1, 2, 3, 4]);
+}
+
+#[test]
+#[should_panic(expected = "Index 2 invalid for slice of len 2")]
+fn get_mut_at_offset_panic() {
+    let slice = vec![0; 4].into_par_chunk_index(2);
+    let _ = unsafe { slice.get_mut_at(0, 2) };
+}
+
+//
+// Test merging sorted chunks
+//
+
+#[test]
+fn reverse() {
+    let mut slice = ParSlice::chunks_with_closure(|i| i + 1, 6, 2);
+
+    slice.reverse();
+
+    assert_eq!(unsafe { slice.get(0) }, &[5, 6]);
+    assert_eq!(slice.num_chunks(), 3);
+    assert_eq!(slice.into().as_ref(), &[5, 6, 3, 4, 1, 2]);
+}
+
+#[test]
+fn into_sorted_vec() {
+    let slice = vec![1, 4, 6, 9, 2, 3, 7, 8].into_par_chunk_index(4);
+
+    assert_eq!(slice.into_sorted_vec(), vec![1, 2, 3, 4, 6, 7, 8, 9]);
+}
+
+#[test]
+fn into_sorted_vec_after_sorting_chunks() {
+    let slice = VecChunkParIndex::new(vec![5, 3, 9, 0, 7, 2, 8, 8, 1], 3);
+
+    scope(|s| {
+        for chunk in 0..3 {
+            let slice = &slice;
+            s.spawn(move || unsafe { slice.get_mut(chunk) }.sort());
+        }
+    });
+
+    assert_eq!(slice.into_sorted_vec(), vec![0, 1, 2, 3, 5, 7, 8, 8, 9]);
+}
+
+#[test]
+fn into_sorted_vec_empty() {
+    let slice = VecPointerChunkParIndex::new(Vec::<u8>::new(), 4);
+
+    assert!(slice.into_sorted_vec().is_empty());
+}
+
+//
+// Test padded construction from jagged rows
+//
+
+#[test]
+fn padded_chunks() {
+    let (slice, row_lens) = ParSlice::padded_chunks(vec![vec![1], vec![2, 3, 4]], -1);
+
+    assert_eq!(slice.num_chunks(), 2);
+    assert_eq!(slice.chunk_size(), 3);
+    assert_eq!(row_lens, vec![1, 3]);
+
+    scope(|s| {
+        for (row, &len) in row_lens.iter().enumerate() {
+            let slice = &slice;
+            s.spawn(move || unsafe { slice.get_mut(row)[..len].iter_mut().for_each(|x| *x *= 10) });
+        }
+    });
+
+    assert_eq!(slice.into().as_ref(), &[10, -1, -1, 20, 30, 40]);
+}
+
+#[test]
+fn padded_chunks_empty_rows() {
+    let (slice, row_lens) = ParSlice::padded_chunks(vec![vec![], vec![]], 0);
+
+    assert_eq!(slice.chunk_size(), 1);
+    assert_eq!(row_lens, vec![0, 0]);
+    assert_eq!(slice.into().as_ref(), &[0, 0]);
+
+    let (slice, row_lens) = ParSlice::padded_chunks(Vec::<Vec<u8>>::new(), 0);
+
+    assert!(slice.is_empty());
+    assert!(row_lens.is_empty());
+}
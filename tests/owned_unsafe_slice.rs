@@ -1,5 +1,8 @@
 use par_slice::*;
-use std::thread::scope;
+use std::{
+    collections::{BTreeMap, HashSet},
+    thread::scope,
+};
 
 //
 // Test without threads
@@ -35,7 +38,7 @@ fn no_thread_checked_panic() {
     let slice = vec![1, 2, 3].into_par_index();
 
     unsafe {
-        slice.get(42);
+        let _ = slice.get(42);
     }
 }
 
@@ -45,7 +48,7 @@ fn no_thread_checked_panic_mut() {
     let slice = vec![1, 2, 3].into_par_index();
 
     unsafe {
-        slice.get_mut(69);
+        let _ = slice.get_mut(69);
     }
 }
 
@@ -99,7 +102,7 @@ fn single_thread_checked_panic() {
 
     scope(|s| {
         s.spawn(|| {
-            unsafe { slice.get(42) };
+            let _ = unsafe { slice.get(42) };
         })
         .join()
         .unwrap_err();
@@ -178,7 +181,7 @@ fn multithread_checked_panic() {
             unsafe { *slice.get_mut(2) = 42 };
         });
         s.spawn(|| {
-            unsafe { slice.get(42) };
+            let _ = unsafe { slice.get(42) };
         })
         .join()
         .unwrap_err();
@@ -204,3 +207,449 @@ fn multithread_checked_panic_mut() {
 
     assert_eq!(slice.into(), vec![1, 2, 3]);
 }
+
+//
+// Test construction from iterators
+//
+
+#[test]
+fn from_iter_exact() {
+    let slice = ParSlice::from_iter_exact((0..10).map(|x| x * 2));
+
+    scope(|s| {
+        s.spawn(|| unsafe { *slice.get_mut(0) += 1 });
+        s.spawn(|| unsafe { *slice.get_mut(9) += 1 });
+    });
+
+    assert_eq!(slice.len(), 10);
+    assert_eq!(slice.into().as_ref(), &[1, 2, 4, 6, 8, 10, 12, 14, 16, 19]);
+}
+
+#[test]
+fn concat() {
+    let parts: Vec<Box<[i32]>> = vec![Box::new([1, 2]), Box::new([]), Box::new([3, 4, 5])];
+    let slice = ParSlice::concat(parts);
+
+    assert_eq!(slice.len(), 5);
+    assert_eq!(unsafe { *slice.get(3) }, 4);
+
+    unsafe { *slice.get_mut(2) = 42 };
+
+    assert_eq!(slice.into().as_ref(), &[1, 2, 42, 4, 5]);
+}
+
+#[test]
+fn repeat_pattern() {
+    let slice = ParSlice::repeat_pattern(&[1, 2], 3);
+
+    assert_eq!(slice.len(), 6);
+    assert_eq!(slice.into().as_ref(), &[1, 2, 1, 2, 1, 2]);
+}
+
+#[test]
+fn repeat_empty_pattern() {
+    let slice = ParSlice::repeat_pattern::<u8>(&[], 5);
+
+    assert!(slice.is_empty());
+}
+
+#[test]
+fn values_into_par_index() {
+    let map = BTreeMap::from([("c", 3), ("a", 1), ("b", 2)]);
+    let (keys, slice) = ParSlice::values_into_par_index(map);
+
+    assert_eq!(keys, vec!["a", "b", "c"]);
+    assert_eq!(slice.len(), 3);
+
+    scope(|s| {
+        for (i, _) in keys.iter().enumerate() {
+            let slice = &slice;
+            s.spawn(move || unsafe { *slice.get_mut(i) *= 10 });
+        }
+    });
+
+    let map: BTreeMap<_, _> = keys.into_iter().zip(slice.into().into_vec()).collect();
+    assert_eq!(map, BTreeMap::from([("a", 10), ("b", 20), ("c", 30)]));
+}
+
+#[test]
+fn append() {
+    let first = vec![1, 2].into_par_index();
+    let second = vec![3, 4].into_par_index();
+
+    let slice = first.append(second);
+
+    assert_eq!(slice.len(), 4);
+    assert_eq!(unsafe { *slice.get(2) }, 3);
+    unsafe { *slice.get_mut(3) = 42 };
+    assert_eq!(Vec::from(slice), vec![1, 2, 3, 42]);
+}
+
+#[test]
+fn eq_vec() {
+    let slice = VecParIndex::new(vec![1, 2, 3]);
+
+    assert_eq!(slice, vec![1, 2, 3]);
+    assert_eq!(vec![1, 2, 3], slice);
+    assert_eq!(slice, &[1, 2, 3][..]);
+}
+
+#[test]
+fn ne_vec() {
+    let slice = VecParIndex::new(vec![1, 2, 3]);
+    unsafe { *slice.get_mut(1) = 42 };
+
+    assert_ne!(slice, vec![1, 2, 3]);
+    assert_ne!(vec![1, 2, 3], slice);
+    assert_ne!(slice, &[1, 2, 3][..]);
+    assert_ne!(slice, vec![1, 42]);
+}
+
+#[test]
+fn concat_per_thread_results() {
+    let parts: Vec<Box<[usize]>> = scope(|s| {
+        let handles: Vec<_> = (0..3)
+            .map(|t| s.spawn(move || (0..t + 1).map(|i| 10 * t + i).collect()))
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+    let slice = ParSlice::concat(parts);
+
+    assert_eq!(slice.into().as_ref(), &[0, 10, 11, 20, 21, 22]);
+}
+
+/// An iterator that reports a length larger than the number of items it yields.
+struct ShortIter(std::ops::Range<u32>);
+
+impl Iterator for ShortIter {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len() + 1, Some(self.0.len() + 1))
+    }
+}
+
+impl ExactSizeIterator for ShortIter {}
+
+#[test]
+#[should_panic(expected = "iterator should yield 4 items. Got 3 items")]
+fn from_iter_exact_short_iterator() {
+    ParSlice::from_iter_exact(ShortIter(0..3));
+}
+
+#[test]
+fn from_exact_len() {
+    let slice = ParSlice::from_exact_len(4, "a b c d".split(' ').map(String::from));
+
+    assert_eq!(slice.len(), 4);
+    assert_eq!(slice.into().as_ref(), &["a", "b", "c", "d"]);
+}
+
+#[test]
+#[should_panic(expected = "iterator should yield 4 items. Got 3 items")]
+fn from_exact_len_short_iterator() {
+    ParSlice::from_exact_len(4, [1, 2, 3].into_iter());
+}
+
+#[test]
+#[should_panic(expected = "iterator should yield 2 items. Got more items")]
+fn from_exact_len_long_iterator() {
+    ParSlice::from_exact_len(2, 0..3);
+}
+
+//
+// Test pairwise access
+//
+
+#[test]
+fn get_two_mut_edges() {
+    let weights = vec![0; 4].into_par_index();
+    let edges = [(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)];
+
+    for (u, v) in edges {
+        let (a, b) = unsafe { weights.get_two_mut(u, v) };
+        *a += 1;
+        *b += 10;
+    }
+
+    assert_eq!(weights.into(), vec![12, 11, 21, 11]);
+}
+
+#[test]
+fn get_two_mut_multithread() {
+    let slice = vec![0; 4].into_par_index();
+
+    scope(|s| {
+        for (i, j) in [(0, 3), (2, 1)] {
+            let slice = &slice;
+            s.spawn(move || {
+                let (a, b) = unsafe { slice.get_two_mut(i, j) };
+                *a = i;
+                *b = j;
+            });
+        }
+    });
+
+    assert_eq!(slice.into(), vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn get_mut_single_array() {
+    fn double_all<const N: usize>(values: &mut [i32; N]) {
+        values.iter_mut().for_each(|v| *v *= 2);
+    }
+
+    let slice = vec![1, 2, 3].into_par_index();
+
+    let array: &mut [i32; 1] = unsafe { slice.get_mut_single_array(2) };
+    assert_eq!(array, &[3]);
+    double_all(array);
+
+    assert_eq!(slice.into(), vec![1, 2, 6]);
+}
+
+#[test]
+#[should_panic(expected = "Indices 1 and 1 should refer to different elements")]
+fn get_two_mut_same_index_panic() {
+    let slice = vec![0; 4].into_par_index();
+    let _ = unsafe { slice.get_two_mut(1, 1) };
+}
+
+#[test]
+#[should_panic(expected = "Index 4 invalid for slice of len 4")]
+fn get_two_mut_out_of_bounds_panic() {
+    let slice = vec![0; 4].into_par_index();
+    let _ = unsafe { slice.get_two_mut(0, 4) };
+}
+
+#[test]
+fn owned_in_struct_field() {
+    struct State {
+        values: VecParIndex<u32>,
+    }
+
+    let mut state = State {
+        values: vec![3, 1, 2].into_par_index(),
+    };
+
+    unsafe {
+        *state.values.get_mut(0) = 0;
+    }
+    state.values.resize(4, 42);
+    state.values[1] = 69;
+    state.values.reverse();
+
+    assert_eq!(state.values.clone().into_iter().sum::<u32>(), 113);
+    assert_eq!(Vec::from(state.values), vec![42, 2, 69, 0]);
+}
+
+#[test]
+fn par_update_halves_distinct_values() {
+    let slice = vec![0; 7].into_par_index();
+
+    slice.par_update_halves(|slice, range| {
+        let value = if range.start == 0 { 42 } else { 69 };
+        for i in range {
+            unsafe { *slice.get_mut(i) = value };
+        }
+    });
+
+    assert_eq!(slice.into(), vec![42, 42, 42, 69, 69, 69, 69]);
+}
+
+#[test]
+fn par_update_halves_empty() {
+    let slice = Vec::<i32>::new().into_par_index();
+
+    slice.par_update_halves(|_, range| assert!(range.is_empty()));
+}
+
+#[test]
+fn scope_for_each_identity() {
+    let slice = vec![0; 100].into_par_index();
+
+    slice.scope_for_each(4, |index, slice| unsafe { *slice.get_mut(index) = index });
+
+    assert_eq!(slice.into(), (0..100).collect::<Vec<_>>());
+}
+
+#[test]
+fn scope_for_each_more_threads_than_elements() {
+    let slice = vec![0; 3].into_par_index();
+
+    slice.scope_for_each(8, |index, slice| unsafe { *slice.get_mut(index) += 1 });
+
+    assert_eq!(slice.into(), vec![1, 1, 1]);
+}
+
+#[test]
+#[should_panic(expected = "num_threads should be greater than 0")]
+fn scope_for_each_no_threads() {
+    let slice = vec![0; 3].into_par_index();
+
+    slice.scope_for_each(0, |_, _| ());
+}
+
+#[test]
+fn switch_paradigms() {
+    let slice = VecPointerParIndex::new(vec![0; 4]);
+    let base = slice.get_mut_ptr(0);
+    unsafe { *slice.get_mut_ptr(0) = 1 };
+
+    let slice = slice.into_no_ref();
+    unsafe { slice.set_value(1, 2) };
+
+    let slice = slice.into_unsafe_index();
+    unsafe { *slice.get_mut(2) = 3 };
+
+    let slice = slice.into_pointer();
+    assert_eq!(slice.get_mut_ptr(0), base);
+    assert_eq!(Vec::from(slice), vec![1, 2, 3, 0]);
+}
+
+//
+// Test serial operations on the returned collections
+//
+
+#[test]
+fn truncate() {
+    let mut slice = ParSlice::with_closure(|i| i as u32, 4);
+
+    slice.truncate(5);
+    assert_eq!(slice.len(), 4);
+
+    slice.truncate(2);
+    unsafe { *slice.get_mut(1) = 42 };
+
+    assert_eq!(slice.len(), 2);
+    assert_eq!(slice.into().as_ref(), &[0, 42]);
+}
+
+#[test]
+fn resize() {
+    let mut slice = vec![1, 2, 3].into_par_index();
+
+    slice.resize(5, 0);
+    unsafe { *slice.get_mut(4) = 42 };
+    assert_eq!(slice.as_slice(), &[1, 2, 3, 0, 42]);
+
+    slice.resize(1, 0);
+    assert_eq!(slice.into(), vec![1]);
+}
+
+#[test]
+fn reverse() {
+    let mut slice = ParSlice::with_closure(|i| i + 1, 5);
+
+    slice.reverse();
+    unsafe { *slice.get_mut(0) *= 10 };
+
+    assert_eq!(slice, vec![50, 4, 3, 2, 1]);
+}
+
+#[test]
+fn replace_inner() {
+    let mut slice = vec![1, 2, 3].into_par_index();
+    let mut back = vec![0; 3].into_boxed_slice();
+
+    for _ in 0..2 {
+        for (i, value) in back.iter_mut().enumerate() {
+            *value = unsafe { *slice.get(i) } * 10;
+        }
+        back = slice.replace_inner(back);
+    }
+
+    assert_eq!(back.as_ref(), &[10, 20, 30]);
+    assert_eq!(slice, vec![100, 200, 300]);
+}
+
+#[test]
+#[should_panic(
+    expected = "new should have the same length as the slice. Got new of length 2 for a slice of length 3"
+)]
+fn replace_inner_panic() {
+    let mut slice = ParSlice::new::<u32>(3);
+    slice.replace_inner(vec![1, 2].into_boxed_slice());
+}
+
+#[test]
+fn index() {
+    let mut slice = vec![1, 2, 3].into_par_index();
+
+    slice[2] = 9;
+    slice[0] += slice[1];
+
+    assert_eq!(slice[0], 3);
+    assert_eq!(slice[2], 9);
+    assert_eq!(slice, vec![3, 2, 9]);
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 3 but the index is 3")]
+fn index_panic() {
+    let slice = ParSlice::new::<u32>(3);
+    let _ = slice[3];
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 3 but the index is 42")]
+fn index_mut_panic() {
+    let mut slice = vec![1, 2, 3].into_par_index();
+    slice[42] = 0;
+}
+
+#[test]
+fn eq() {
+    let slice = vec![1, 2, 3].into_par_index();
+    let other = slice.clone();
+
+    assert_eq!(slice, other);
+    assert_eq!(slice, &[1, 2, 3][..]);
+    unsafe { *other.get_mut(0) = 42 };
+    assert_ne!(slice, other);
+    assert_ne!(other, &[1, 2, 3][..]);
+}
+
+#[test]
+fn sort() {
+    let mut slices = vec![
+        vec![2, 1].into_par_index(),
+        vec![1, 2, 3].into_par_index(),
+        vec![1, 2].into_par_index(),
+    ];
+
+    slices.sort();
+
+    assert_eq!(
+        slices.into_iter().map(Vec::from).collect::<Vec<_>>(),
+        vec![vec![1, 2], vec![1, 2, 3], vec![2, 1]]
+    );
+}
+
+#[test]
+#[allow(clippy::mutable_key_type)]
+fn hash_set() {
+    let mut set = HashSet::new();
+
+    set.insert(ParSlice::with_closure(|i| i + 1, 3));
+    set.insert(ParSlice::with_closure(|i| i + 1, 3));
+    set.insert(ParSlice::with_closure(|i| 3 - i, 3));
+
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&ParSlice::with_closure(|i| 3 - i, 3)));
+}
+
+#[test]
+fn into_iter() {
+    let slice = ParSlice::with_closure(|i| i + 1, 3);
+
+    unsafe { *slice.get_mut(1) = 42 };
+    let iter = slice.into_iter();
+
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![1, 42, 3]);
+}
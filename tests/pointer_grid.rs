@@ -0,0 +1,54 @@
+use par_slice::*;
+use std::thread::scope;
+
+#[test]
+fn cell_maps_to_flat_index() {
+    let ncols = 3;
+    let grid = PointerGrid::new(vec![0; 9].into_pointer_par_index(), ncols);
+    let flat = vec![0; 9].into_pointer_par_index();
+
+    assert_eq!(grid.nrows(), 3);
+    assert_eq!(grid.ncols(), 3);
+    assert_eq!(
+        grid.get_mut_ptr(2, 1).addr() - grid.get_mut_ptr(0, 0).addr(),
+        flat.get_mut_ptr(2 * ncols + 1).addr() - flat.get_mut_ptr(0).addr()
+    );
+    assert_eq!(grid.get_mut_ptr(2, 1), unsafe {
+        grid.get_mut_ptr_unchecked(0, 0).add(2 * ncols + 1)
+    });
+}
+
+#[test]
+fn rows_in_parallel() {
+    let grid = PointerGrid::new(vec![0; 6].into_pointer_par_index(), 2);
+
+    scope(|s| {
+        for row in 0..grid.nrows() {
+            let grid = &grid;
+            s.spawn(move || {
+                for col in 0..grid.ncols() {
+                    unsafe {
+                        *grid.get_mut_ptr(row, col) = row * 10 + col;
+                    }
+                }
+            });
+        }
+    });
+
+    assert_eq!(grid.into_inner().into(), vec![0, 1, 10, 11, 20, 21]);
+}
+
+#[test]
+#[should_panic(
+    expected = "ncols should be a divisor of len. Got 4 columns for a collection of len 6"
+)]
+fn new_panic() {
+    let _ = PointerGrid::new(vec![0; 6].into_pointer_par_index(), 4);
+}
+
+#[test]
+#[should_panic(expected = "Cell (0, 2) invalid for grid of 3 rows and 2 columns")]
+fn get_mut_ptr_col_out_of_bounds() {
+    let grid = PointerGrid::new(vec![0; 6].into_pointer_par_index(), 2);
+    let _ = grid.get_mut_ptr(0, 2);
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "rand")]
+
+use par_slice::*;
+use rand::{rngs::SmallRng, SeedableRng};
+
+#[test]
+fn shuffle_is_permutation() {
+    let mut slice = ParSlice::with_closure(|i| i, 1000);
+    slice.shuffle(&mut SmallRng::seed_from_u64(42));
+
+    let mut elements = slice.into_boxed_slice().into_vec();
+    assert_ne!(elements, (0..1000).collect::<Vec<_>>());
+
+    elements.sort_unstable();
+    assert_eq!(elements, (0..1000).collect::<Vec<_>>());
+}
+
+#[test]
+fn shuffle_is_deterministic_with_seed() {
+    let mut a = vec![1, 2, 3, 4, 5, 6, 7, 8].into_par_index_no_ref();
+    let mut b = vec![1, 2, 3, 4, 5, 6, 7, 8].into_par_index_no_ref();
+
+    a.shuffle(&mut SmallRng::seed_from_u64(7));
+    b.shuffle(&mut SmallRng::seed_from_u64(7));
+
+    assert_eq!(a.as_slice(), b.as_slice());
+}
@@ -0,0 +1,147 @@
+#![cfg(feature = "rayon")]
+
+use par_slice::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn for_each_chunk() {
+    let slice = vec![0; 8].into_par_chunk_index_no_ref(2);
+
+    slice.for_each_chunk(|i| unsafe { slice.set_values(i, &[i, i]) });
+
+    assert_eq!(slice.into(), vec![0, 0, 1, 1, 2, 2, 3, 3]);
+}
+
+#[test]
+fn for_each_chunk_borrowed() {
+    let mut v = vec![0; 9];
+
+    {
+        let slice = v.as_par_chunk_index_no_ref(3);
+        slice.for_each_chunk(|i| unsafe { slice.set_values(i, &[i; 3]) });
+    }
+
+    assert_eq!(v, vec![0, 0, 0, 1, 1, 1, 2, 2, 2]);
+}
+
+#[test]
+fn for_each_chunk_non_send_closure() {
+    let offset = std::sync::Mutex::new(10);
+    let slice = vec![0; 6].into_par_chunk_index_no_ref(2);
+
+    // The guard is Sync but not Send, so the closure capturing it is too
+    let guard = offset.lock().unwrap();
+    slice.for_each_chunk(|i| unsafe { slice.set_values(i, &[i + *guard; 2]) });
+    drop(guard);
+
+    assert_eq!(slice.into(), vec![10, 10, 11, 11, 12, 12]);
+}
+
+#[test]
+fn par_sort_chunks() {
+    let slice = vec![3, 1, 2, 9, 7, 8, 5, 6, 4].into_par_chunk_index(3);
+
+    unsafe { slice.par_sort_chunks() };
+
+    for chunk in 0..3 {
+        assert!(unsafe { slice.get(chunk) }.is_sorted());
+    }
+    assert_eq!(slice.into(), vec![1, 2, 3, 7, 8, 9, 4, 5, 6]);
+}
+
+#[test]
+fn par_sort_chunks_borrowed() {
+    let mut v = vec![2, 1, 4, 3];
+
+    {
+        let slice = v.as_par_chunk_index(2);
+        unsafe { slice.par_sort_chunks() };
+    }
+
+    assert_eq!(v, vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn par_count_reached_nodes() {
+    let dists = ParSlice::with_value(-1_i64, 1000);
+    for i in (0..1000).step_by(3) {
+        unsafe { *dists.get_mut(i) = (i / 3) as i64 };
+    }
+
+    assert_eq!(dists.par_count(|&d| d >= 0), 334);
+    assert_eq!(dists.par_count(|&d| d > 1000), 0);
+}
+
+#[test]
+fn par_with_state_matches_serial() {
+    let slice = ParSlice::par_with_state(
+        10_000,
+        || 0_usize,
+        |calls, i| {
+            *calls += 1;
+            i * 2
+        },
+    );
+
+    assert_eq!(
+        slice.into().into_vec(),
+        (0..10_000).map(|i| i * 2).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn par_with_state_reuses_state() {
+    let states = AtomicUsize::new(0);
+    let slice =
+        ParSlice::par_with_state(10_000, || states.fetch_add(1, Ordering::Relaxed), |_, i| i);
+
+    assert_eq!(slice.len(), 10_000);
+    assert!(states.load(Ordering::Relaxed) < 10_000);
+}
+
+#[test]
+fn par_with_state_non_send_closures() {
+    let offset = std::sync::Mutex::new(10);
+
+    // The guard is Sync but not Send, so the closures capturing it are too
+    let guard = offset.lock().unwrap();
+    let slice = ParSlice::par_with_state(4, || *guard, |offset, i| *offset + i);
+    drop(guard);
+
+    assert_eq!(slice.into().as_ref(), &[10, 11, 12, 13]);
+}
+
+#[test]
+fn par_map_reduce_chunks_sum() {
+    let slice = ParSlice::chunks_with_closure(|i| i as u64, 10_000, 10);
+
+    let total = slice.par_map_reduce_chunks(|chunk| chunk.iter().sum::<u64>(), |a, b| a + b, 0);
+
+    assert_eq!(total, (0..10_000).sum::<u64>());
+}
+
+#[test]
+fn par_map_reduce_chunks_empty() {
+    let slice = Vec::<u64>::new().into_par_chunk_index(4);
+
+    assert_eq!(
+        slice.par_map_reduce_chunks(|chunk| chunk.len(), |a, b| a + b, 42),
+        42
+    );
+}
+
+#[test]
+fn par_map_reduce_chunks_non_send_closure() {
+    let scale = std::sync::Mutex::new(2_u64);
+    let slice = ParSlice::chunks_with_value(1_u64, 100, 10);
+
+    // The guard is Sync but not Send, so the closure capturing it is too
+    let guard = scale.lock().unwrap();
+    let total = slice.par_map_reduce_chunks(
+        move |chunk| chunk.iter().sum::<u64>() * *guard,
+        |a, b| a + b,
+        0,
+    );
+
+    assert_eq!(total, 200);
+}
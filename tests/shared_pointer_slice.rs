@@ -0,0 +1,61 @@
+use par_slice::*;
+use std::{sync::Arc, thread::scope};
+
+#[test]
+fn concurrent_reads() {
+    let table: Arc<[u64]> = (0..100).collect();
+    let shared = table.into_shared_pointer_par_index();
+
+    let sums: Vec<u64> = scope(|s| {
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let shared = shared.clone();
+                s.spawn(move || {
+                    (0..shared.len())
+                        .map(|i| unsafe { *shared.get_ptr(i) })
+                        .sum()
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+
+    assert_eq!(sums, vec![4950; 4]);
+}
+
+#[test]
+fn clones_share_elements() {
+    let table: Arc<[u32]> = Arc::from([1, 2, 3]);
+    let shared = table.clone().into_shared_pointer_par_index();
+    let cloned = shared.clone();
+
+    assert_eq!(shared.get_ptr(1), cloned.get_ptr(1));
+    assert_eq!(
+        unsafe { shared.get_ptr_unchecked(2) },
+        &table[2] as *const u32
+    );
+
+    drop(shared);
+    let back: Arc<[u32]> = cloned.into();
+    assert!(Arc::ptr_eq(&back, &table));
+}
+
+#[test]
+fn concurrent_references() {
+    let table: Arc<[String]> = Arc::from([String::from("a"), String::from("b")]);
+    let shared = table.into_shared_pointer_par_index();
+
+    scope(|s| {
+        for i in 0..2 {
+            let shared = shared.clone();
+            s.spawn(move || assert_eq!(shared.get(i), ["a", "b"][i]));
+        }
+    });
+}
+
+#[test]
+#[should_panic(expected = "Index 3 invalid for slice of len 3")]
+fn checked_panic() {
+    let table: Arc<[u32]> = Arc::from([1, 2, 3]);
+    let _ = table.into_shared_pointer_par_index().get_ptr(3);
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "smallvec")]
+
+use par_slice::*;
+use smallvec::{smallvec, SmallVec};
+use std::thread::scope;
+
+#[test]
+fn round_trip_inline() {
+    let v: SmallVec<[u32; 8]> = smallvec![0; 4];
+    assert!(!v.spilled());
+
+    let slice = v.into_par_index();
+
+    scope(|s| {
+        s.spawn(|| unsafe { *slice.get_mut(0) = 42 });
+        s.spawn(|| unsafe { *slice.get_mut(3) = 69 });
+    });
+
+    let v: SmallVec<[u32; 8]> = slice.into();
+    assert!(!v.spilled());
+    assert_eq!(v.as_slice(), &[42, 0, 0, 69]);
+}
+
+#[test]
+fn round_trip_spilled() {
+    let v: SmallVec<[u32; 2]> = smallvec![0; 4];
+    assert!(v.spilled());
+
+    let slice = v.into_par_index_no_ref();
+    unsafe { slice.set_value(1, 42) };
+
+    let v: SmallVec<[u32; 2]> = slice.into();
+    assert_eq!(v.as_slice(), &[0, 42, 0, 0]);
+}
+
+#[test]
+fn round_trip_chunks() {
+    let v: SmallVec<[u32; 8]> = smallvec![0; 6];
+
+    let slice = v.into_par_chunk_index(3);
+    unsafe { slice.get_mut(1).copy_from_slice(&[1, 2, 3]) };
+
+    let v: SmallVec<[u32; 8]> = slice.into();
+    assert!(!v.spilled());
+    assert_eq!(v.as_slice(), &[0, 0, 0, 1, 2, 3]);
+}
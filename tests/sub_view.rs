@@ -0,0 +1,87 @@
+use par_slice::*;
+use std::thread::scope;
+
+#[test]
+fn split_first() {
+    let slice = vec![1, 2, 3, 4].into_pointer_par_index();
+
+    let (head, tail) = slice.split_first().unwrap();
+
+    assert_eq!(head, slice.get_mut_ptr(0));
+    assert_eq!(tail.len(), 3);
+    for i in 0..3 {
+        assert_eq!(tail.get_mut_ptr(i), slice.get_mut_ptr(i + 1));
+    }
+}
+
+#[test]
+fn split_last() {
+    let slice = vec![1, 2, 3, 4].into_pointer_par_index();
+
+    let (last, init) = slice.split_last().unwrap();
+
+    assert_eq!(last, slice.get_mut_ptr(3));
+    assert_eq!(init.len(), 3);
+    for i in 0..3 {
+        assert_eq!(init.get_mut_ptr(i), slice.get_mut_ptr(i));
+    }
+}
+
+#[test]
+fn split_empty() {
+    let slice = Vec::<u8>::new().into_pointer_par_index();
+
+    assert!(slice.split_first().is_none());
+    assert!(slice.split_last().is_none());
+
+    let slice = vec![1].into_pointer_par_index();
+    let (_, tail) = slice.split_first().unwrap();
+
+    assert!(tail.is_empty());
+    assert!(tail.split_first().is_none());
+}
+
+#[test]
+fn recursive_prefix_sum() {
+    fn prefix_sum(view: &(impl PointerIndex<u64> + Sync), acc: u64) {
+        if let Some((head, tail)) = view.split_first() {
+            let acc = acc + unsafe { *head };
+            unsafe { *head = acc };
+            prefix_sum(&tail, acc);
+        }
+    }
+
+    let slice = vec![1, 2, 3, 4, 5].into_pointer_par_index();
+    prefix_sum(&slice, 0);
+
+    assert_eq!(slice.into(), vec![1, 3, 6, 10, 15]);
+}
+
+#[test]
+fn parallel_split() {
+    let slice = vec![0; 4].into_pointer_par_index();
+    let (first, rest) = slice.split_first().unwrap();
+    let (last, middle) = rest.split_last().unwrap();
+
+    // Dereference the pointers through their addresses, as raw pointers can't be sent between threads
+    let (first, last) = (first as usize, last as usize);
+    scope(|s| {
+        s.spawn(|| unsafe { *(first as *mut i32) = 1 });
+        s.spawn(|| unsafe { *(last as *mut i32) = 4 });
+        s.spawn(|| unsafe {
+            *middle.get_mut_ptr(0) = 2;
+            *middle.get_mut_ptr(1) = 3;
+        });
+    });
+
+    assert_eq!(slice.into(), vec![1, 2, 3, 4]);
+}
+
+#[test]
+#[should_panic(expected = "Index 2 invalid for slice of len 2")]
+fn sub_view_checked_panic() {
+    let slice = vec![1, 2, 3].into_pointer_par_index();
+    let (_, tail) = slice.split_first().unwrap();
+
+    let _ = tail.get_mut_ptr(2);
+}
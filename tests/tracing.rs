@@ -0,0 +1,92 @@
+#![cfg(feature = "tracing")]
+
+use par_slice::*;
+use std::sync::{Arc, Mutex};
+use tracing::{
+    field::{Field, Visit},
+    span, Event, Metadata, Subscriber,
+};
+
+/// Subscriber collecting the message and index of each event.
+#[derive(Clone, Default)]
+struct Recorder(Arc<Mutex<Vec<(String, u64)>>>);
+
+#[derive(Default)]
+struct Fields {
+    message: String,
+    index: u64,
+}
+
+impl Visit for Fields {
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        if field.name() == "index" {
+            self.index = value;
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{value:?}");
+        }
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _: &span::Attributes<'_>) -> span::Id {
+        span::Id::from_u64(1)
+    }
+
+    fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+
+    fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = Fields::default();
+        event.record(&mut fields);
+        self.0.lock().unwrap().push((fields.message, fields.index));
+    }
+
+    fn enter(&self, _: &span::Id) {}
+
+    fn exit(&self, _: &span::Id) {}
+}
+
+#[test]
+fn get_mut_emits_index() {
+    let recorder = Recorder::default();
+    let slice = vec![0; 4].into_par_index();
+
+    tracing::subscriber::with_default(recorder.clone(), || unsafe {
+        *slice.get_mut(2) = 42;
+    });
+
+    assert_eq!(
+        *recorder.0.lock().unwrap(),
+        vec![("get_mut".to_string(), 2)]
+    );
+    assert_eq!(slice.into(), vec![0, 0, 42, 0]);
+}
+
+#[test]
+fn set_value_emits_index() {
+    let recorder = Recorder::default();
+    let mut v = vec![0; 4];
+
+    {
+        let slice = v.as_par_index_no_ref();
+        tracing::subscriber::with_default(recorder.clone(), || unsafe {
+            slice.set_value(1, 42);
+            slice.set_value_unchecked(3, 69);
+        });
+    }
+
+    assert_eq!(
+        *recorder.0.lock().unwrap(),
+        vec![("set_value".to_string(), 1), ("set_value".to_string(), 3)]
+    );
+    assert_eq!(v, vec![0, 42, 0, 69]);
+}
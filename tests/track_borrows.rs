@@ -0,0 +1,216 @@
+#![cfg(feature = "track-borrows")]
+
+use par_slice::*;
+use std::{
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::Barrier,
+    thread::scope,
+};
+
+//
+// Test borrows of elements
+//
+
+#[test]
+fn borrows_different_indexes() {
+    let collection = vec![0; 4].into_par_index();
+
+    scope(|s| {
+        for i in 0..4 {
+            let collection = &collection;
+            s.spawn(move || *unsafe { collection.get_mut(i) } = i);
+        }
+    });
+
+    assert_eq!(collection.into(), vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn shared_borrows_same_index() {
+    let collection = vec![42; 2].into_par_index();
+
+    let first = unsafe { collection.get(0) };
+    let second = unsafe { collection.get(0) };
+
+    assert_eq!(*first, 42);
+    assert_eq!(*second, 42);
+}
+
+#[test]
+fn borrow_released_on_drop() {
+    let mut values = vec![0; 2];
+    let collection = values.as_par_index();
+
+    {
+        let mut ref_0 = unsafe { collection.get_mut(0) };
+        *ref_0 = 42;
+    }
+    {
+        let ref_0 = unsafe { collection.get(0) };
+        assert_eq!(*ref_0, 42);
+    }
+    *unsafe { collection.get_mut(0) } += 1;
+    drop(collection);
+
+    assert_eq!(values, vec![43, 0]);
+}
+
+#[test]
+#[should_panic(expected = "Index 0 is already mutably borrowed: aliasing rules violated")]
+fn get_while_mutably_borrowed() {
+    let collection = vec![0; 2].into_par_index();
+
+    let _mut_ref = unsafe { collection.get_mut(0) };
+    let _ref = unsafe { collection.get(0) };
+}
+
+#[test]
+#[should_panic(expected = "Index 0 is already borrowed: aliasing rules violated")]
+fn get_mut_while_borrowed() {
+    let collection = vec![0; 2].into_par_index();
+
+    let _ref = unsafe { collection.get(0) };
+    let _mut_ref = unsafe { collection.get_mut(0) };
+}
+
+#[test]
+#[should_panic(expected = "Index 2 invalid for slice of len 2")]
+fn get_mut_out_of_bounds() {
+    let collection = vec![0; 2].into_par_index();
+    let _ = unsafe { collection.get_mut(2) };
+}
+
+#[test]
+fn sequential_guard_scopes() {
+    let collection = vec![0; 2].into_par_index();
+
+    {
+        let mut guard = unsafe { collection.get_mut(0) };
+        *guard = 1;
+    }
+    unsafe { *collection.get_mut(0) += 1 };
+    {
+        let guard = unsafe { collection.get(0) };
+        assert_eq!(*guard, 2);
+        assert_eq!(unsafe { *collection.get(0) }, 2);
+    }
+    unsafe { *collection.get_mut(0) += 1 };
+
+    assert_eq!(collection.into(), vec![3, 0]);
+}
+
+#[test]
+#[should_panic(expected = "Index 1 is already borrowed: aliasing rules violated")]
+fn double_get_mut() {
+    let collection = vec![0; 2].into_par_index();
+
+    let _first = unsafe { collection.get_mut(1) };
+    let _second = unsafe { collection.get_mut(1) };
+}
+
+//
+// Test borrows of chunks
+//
+
+#[test]
+fn chunk_borrows_different_chunks() {
+    let collection = vec![0; 6].into_par_chunk_index(2);
+
+    scope(|s| {
+        for chunk in 0..3 {
+            let collection = &collection;
+            s.spawn(move || unsafe { collection.get_mut(chunk) }.fill(chunk));
+        }
+    });
+
+    assert_eq!(collection.into(), vec![0, 0, 1, 1, 2, 2]);
+}
+
+#[test]
+fn chunk_borrow_released_on_drop() {
+    let mut values = vec![0; 4];
+    let collection = values.as_par_chunk_index(2);
+
+    {
+        let mut guard = unsafe { collection.get_mut(1) };
+        guard[0] = 42;
+    }
+    unsafe { collection.get_mut(1)[1] = 69 };
+    drop(collection);
+
+    assert_eq!(values, vec![0, 0, 42, 69]);
+}
+
+#[test]
+fn same_chunk_from_two_threads() {
+    let collection = vec![0; 4].into_par_chunk_index(2);
+    let barrier = Barrier::new(2);
+
+    let results: Vec<_> = scope(|s| {
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let (collection, barrier) = (&collection, &barrier);
+                s.spawn(move || {
+                    // Only one of the two threads can borrow chunk 1, as the guard
+                    // is only dropped after both threads tried to borrow it
+                    let guard = catch_unwind(AssertUnwindSafe(|| unsafe { collection.get_mut(1) }));
+                    barrier.wait();
+                    guard.is_ok()
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+
+    assert_eq!(results.iter().filter(|&&ok| ok).count(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Index 1 is already borrowed: aliasing rules violated")]
+fn flat_borrow_of_borrowed_chunk() {
+    let collection = vec![0; 4].into_par_chunk_index(2);
+    let _chunk = unsafe { collection.get(1) };
+
+    let flat = collection.as_flat();
+    let _ = unsafe { flat.get_mut(3) };
+}
+
+#[test]
+fn flat_borrow_released_on_drop() {
+    let collection = vec![0; 4].into_par_chunk_index(2);
+    let flat = collection.as_flat();
+
+    *unsafe { flat.get_mut(2) } = 42;
+
+    assert_eq!(*unsafe { collection.get_mut(1) }, [42, 0]);
+}
+
+#[test]
+#[should_panic(expected = "Index 3 is already mutably borrowed: aliasing rules violated")]
+fn parity_borrow_of_borrowed_chunk() {
+    let collection = vec![0; 8].into_par_chunk_index(2);
+    let _chunk = unsafe { collection.get_mut(3) };
+
+    let (_, odd) = collection.split_parity();
+    let _ = unsafe { odd.get(1) };
+}
+
+#[test]
+fn parity_borrow_released_on_drop() {
+    let collection = vec![0; 8].into_par_chunk_index(2);
+    let (even, _) = collection.split_parity();
+
+    unsafe { even.get_mut(1)[0] = 42 };
+
+    assert_eq!(*unsafe { collection.get_mut(2) }, [42, 0]);
+}
+
+#[test]
+#[should_panic(expected = "Index 0 is already mutably borrowed: aliasing rules violated")]
+fn rchunk_borrow_of_borrowed_chunk() {
+    let mut slice = [0; 4];
+    let view = slice.as_rchunk_index(2);
+    let _chunk = unsafe { view.get_mut(1) };
+
+    let _ = unsafe { view.get(1) };
+}
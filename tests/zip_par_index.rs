@@ -0,0 +1,63 @@
+use par_slice::*;
+use std::thread::scope;
+
+#[test]
+#[should_panic(
+    expected = "collections should have the same length. Got collections of length 3 and 4"
+)]
+fn different_lengths() {
+    ZipParIndex::new(vec![0; 3].into_par_index(), vec![0; 4].into_par_index());
+}
+
+#[test]
+fn update_both() {
+    let zipped = ZipParIndex::new(vec![-1; 8].into_par_index(), vec![0; 8].into_par_index());
+
+    unsafe {
+        let (dist, parent) = zipped.get_mut(5);
+        *dist = 2;
+        *parent = 1;
+    }
+
+    assert_eq!(unsafe { zipped.get(5) }, (&2, &1));
+
+    let (dists, parents) = zipped.into_inner();
+    assert_eq!(dists.into(), vec![-1, -1, -1, -1, -1, 2, -1, -1]);
+    assert_eq!(parents.into(), vec![0, 0, 0, 0, 0, 1, 0, 0]);
+}
+
+#[test]
+#[should_panic(expected = "Index 8 invalid for slice of len 8")]
+fn update_both_panic() {
+    let zipped = ZipParIndex::new(vec![-1; 8].into_par_index(), vec![0; 8].into_par_index());
+
+    unsafe {
+        let _ = zipped.get_mut(8);
+    }
+}
+
+#[test]
+fn multithread_update_both() {
+    let mut dists = vec![-1; 4];
+    let mut parents = vec![0; 4];
+
+    {
+        let zipped = ZipParIndex::new(dists.as_par_index(), parents.as_par_index());
+
+        scope(|s| {
+            s.spawn(|| {
+                let (dist, parent) = unsafe { zipped.get_mut_unchecked(1) };
+                *dist = 1;
+                *parent = 0;
+            });
+            s.spawn(|| {
+                let (dist, parent) = unsafe { zipped.get_mut_unchecked(3) };
+                *dist = 2;
+                *parent = 1;
+            });
+        });
+    }
+
+    assert_eq!(dists, vec![-1, 1, -1, 2]);
+    assert_eq!(parents, vec![0, 0, 0, 1]);
+}